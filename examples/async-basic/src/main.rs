@@ -0,0 +1,54 @@
+use futures_util::StreamExt;
+use simconnect::{AsyncSimConnect, Period, Recv, SimData, OBJECT_ID_USER};
+
+// Struct in the format of the data definition
+#[derive(Debug, SimData)]
+struct ExampleData {
+    #[simvar(name = "PLANE HEADING DEGREES TRUE", units = "Degrees")]
+    heading: f64,
+    #[simvar(name = "TITLE")]
+    title: String,
+}
+
+#[tokio::main]
+async fn main() -> Result<(), simconnect::Error> {
+
+    // Open connection to SimConnect, no dispatch thread or polling needed
+    let sc = AsyncSimConnect::open("Example")?;
+    println!("SimConnect_Open");
+
+    // Wait for a single response
+    let sim = sc.request_system_state("Sim").await?;
+    println!("RECV_SYSTEM_STATE - Sim running: {}", sim.integer != 0);
+
+    // Read the data definition once, then every second
+    let define_id = sc.define::<ExampleData>()?;
+    let data: ExampleData = sc.request_data_once(define_id, OBJECT_ID_USER).await?;
+    println!("RECV_DATA (once) - Title: '{}', Heading: {:.0}°", data.title, data.heading);
+
+    let request_id = sc.with_connection(|sc| {
+        let request_id = sc.new_request_id();
+        sc.request_data_on_sim_object(request_id, define_id, OBJECT_ID_USER, Period::Second)
+            .map(|()| request_id)
+    })?;
+
+    // Handle messages until the sim quits or ctrl-c is pressed
+    let mut messages = sc.messages();
+    loop {
+        tokio::select! {
+            _ = tokio::signal::ctrl_c() => break,
+            recv = messages.next() => match recv {
+                Some(Ok(Recv::SimObjectData(data))) if data.request_id == request_id => {
+                    let data = ExampleData::from_recv(&data)?;
+                    println!("RECV_DATA - Title: '{}', Heading: {:.0}°", data.title, data.heading);
+                }
+                Some(Ok(Recv::Exception(e))) => eprintln!("{e}"),
+                Some(Ok(Recv::Quit)) => println!("RECV_QUIT"),
+                Some(Ok(_)) => {}
+                Some(Err(e)) => return Err(e),
+                None => break,
+            }
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,38 @@
+use simconnect::{DataRequestFlags, Period, Recv, RequestOptions, SimConnect, SimData, OBJECT_ID_USER};
+
+// Struct in the format of the data definition
+#[derive(Debug, SimData)]
+struct ExampleData {
+    #[simvar(name = "PLANE HEADING DEGREES TRUE", units = "Degrees")]
+    heading: f64,
+    #[simvar(name = "PLANE ALTITUDE", units = "Feet", epsilon = 1.0)]
+    altitude: f64,
+}
+
+fn main() -> Result<(), simconnect::Error> {
+
+    // Open connection to SimConnect, closed again when dropped
+    let sc = SimConnect::open("Example")?;
+    println!("SimConnect_Open");
+
+    // Register data definition and request it every frame it changes
+    let define_id = sc.define::<ExampleData>()?;
+    let request_id = sc.new_request_id();
+    sc.request_data_on_sim_object_with(request_id, define_id, OBJECT_ID_USER, Period::SimFrame,
+        RequestOptions { flags: DataRequestFlags::CHANGED, ..Default::default() })?;
+    println!("SimConnect_RequestDataOnSimObject - RequestID {request_id}");
+
+    // Block for each message until the sim quits
+    for recv in sc.iter() {
+        match recv? {
+            Recv::SimObjectData(data) if data.request_id == request_id => {
+                let data = ExampleData::from_recv(&data)?;
+                println!("RECV_DATA - Heading: {:.0}°, Altitude: {:.0}ft", data.heading, data.altitude);
+            },
+            Recv::Exception(e) => eprintln!("{e}"),
+            Recv::Quit => println!("RECV_QUIT"),
+            _ => {}
+        }
+    }
+    Ok(())
+}
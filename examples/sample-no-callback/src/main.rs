@@ -56,8 +56,8 @@ fn main() -> Result<(), &'static str> {
             std::ptr::addr_of_mut!(data),
             std::ptr::addr_of_mut!(cb_data),
         ) } != 0 {
-            // TODO this fails for some reason? brakes still work though!
-            // eprintln!("Error: SimConnect_GetNextDispatch failed!");
+            // E_FAIL just means the queue is empty, see `SimConnect::try_recv`
+            // in the `simconnect` crate for a version that tells them apart
             std::thread::sleep(std::time::Duration::from_secs(1));
         } else {
             unsafe {
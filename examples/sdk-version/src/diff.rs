@@ -0,0 +1,365 @@
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+
+use quote::ToTokens;
+use syn::{ForeignItem, Item};
+
+#[allow(dead_code)]
+mod generate {
+    include!("../../../simconnect-sys/generate.rs");
+
+    // runs bindgen over `header` as build.rs does for 64 bit windows, types
+    // and functions
+    pub fn bindings(wrapper: &str, header: &str) -> String {
+        [BINDINGS_TYPES, BINDINGS_FUNCTIONS].into_iter()
+            .map(|part| builder(wrapper, header, "x86_64-pc-windows-msvc", part)
+                .generate()
+                .expect("Unable to generate SimConnect bindings")
+                .to_string())
+            .collect()
+    }
+}
+
+// kinds of items compared, in report order
+const KINDS: [(&str, &str); 5] = [
+    ("functions", "functions"),
+    ("structs", "structs"),
+    ("enum_values", "enum values"),
+    ("recv_ids", "`SIMCONNECT_RECV_ID_*` codes"),
+    ("constants", "constants"),
+];
+
+/// Items of one SimConnect header, by kind and name, with a normalised
+/// description of each to compare.
+#[derive(Debug, Default)]
+pub struct Api {
+    pub version: String,
+    items: BTreeMap<&'static str, BTreeMap<String, String>>,
+}
+
+impl Api {
+
+    /// Loads a `SimConnect.h`, run through bindgen, or bindings that were
+    /// already generated, either a `.rs` file or a `bindings/<version>`
+    /// directory.
+    pub fn load(path: &Path, wrapper: &Path) -> Self {
+        let source = if path.is_dir() {
+            ["types.rs", "functions.rs"].iter()
+                .map(|part| std::fs::read_to_string(path.join(part)).unwrap())
+                .collect()
+        } else if path.extension().is_some_and(|ext| ext == "rs") {
+            std::fs::read_to_string(path).unwrap()
+        } else {
+            generate::bindings(&wrapper.display().to_string(), &path.display().to_string())
+        };
+        let mut api = Self::parse(&source);
+        api.version = version_of(path);
+        api
+    }
+
+    fn parse(source: &str) -> Self {
+        let file = syn::parse_file(source).expect("Couldn't parse the bindings");
+        let mut api = Self::default();
+        for item in file.items {
+            match item {
+                Item::ForeignMod(foreign) => {
+                    for item in foreign.items {
+                        if let ForeignItem::Fn(f) = item {
+                            let inputs = f.sig.inputs.to_token_stream();
+                            let output = f.sig.output.to_token_stream();
+                            api.insert("functions", f.sig.ident.to_string(), format!("({inputs}) {output}"));
+                        }
+                    }
+                }
+                Item::Struct(s) => {
+                    let fields = s.fields.iter()
+                        .map(|field| match &field.ident {
+                            Some(ident) => format!("{ident}: {}", field.ty.to_token_stream()),
+                            None => field.ty.to_token_stream().to_string(),
+                        })
+                        .collect::<Vec<_>>()
+                        .join(", ");
+                    api.insert("structs", s.ident.to_string(), format!("{{ {fields} }}"));
+                }
+                Item::Const(c) => {
+                    let name = c.ident.to_string();
+                    let ty = c.ty.to_token_stream().to_string();
+                    // enums are bound as constants typed with the enum name
+                    let kind = if name.starts_with("SIMCONNECT_RECV_ID_") {
+                        "recv_ids"
+                    } else if ty.starts_with("SIMCONNECT_") {
+                        "enum_values"
+                    } else {
+                        "constants"
+                    };
+                    api.insert(kind, name, format!("{ty} = {}", c.expr.to_token_stream()));
+                }
+                _ => {}
+            }
+        }
+        api
+    }
+
+    fn insert(&mut self, kind: &'static str, name: String, description: String) {
+        // the ctypes module differs between bindgen and the pre-generated bindings
+        let description = description
+            .replace("crate :: ctypes :: ", "")
+            .replace(":: std :: os :: raw :: ", "");
+        self.items.entry(kind).or_default().insert(name, description);
+    }
+}
+
+// names of the items in one section of the changelog
+type Names = fn(&KindDiff) -> Vec<String>;
+
+/// An item that exists in both headers but differs.
+#[derive(Debug)]
+pub struct Changed {
+    pub name: String,
+    pub old: String,
+    pub new: String,
+}
+
+/// Differences of one kind of item.
+#[derive(Debug, Default)]
+pub struct KindDiff {
+    pub added: Vec<(String, String)>,
+    pub removed: Vec<(String, String)>,
+    pub changed: Vec<Changed>,
+}
+
+impl KindDiff {
+
+    fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+}
+
+/// Differences between two SimConnect headers.
+#[derive(Debug)]
+pub struct Diff {
+    pub old: String,
+    pub new: String,
+    pub kinds: Vec<(&'static str, KindDiff)>,
+}
+
+impl Diff {
+
+    pub fn new(old: &Api, new: &Api) -> Self {
+        let empty = BTreeMap::new();
+        let kinds = KINDS.iter()
+            .map(|(kind, _)| {
+                let old = old.items.get(kind).unwrap_or(&empty);
+                let new = new.items.get(kind).unwrap_or(&empty);
+                let mut diff = KindDiff::default();
+                for (name, description) in new {
+                    match old.get(name) {
+                        None => diff.added.push((name.clone(), description.clone())),
+                        Some(old) if old != description => diff.changed.push(Changed {
+                            name: name.clone(),
+                            old: old.clone(),
+                            new: description.clone(),
+                        }),
+                        Some(_) => {}
+                    }
+                }
+                for (name, description) in old {
+                    if !new.contains_key(name) {
+                        diff.removed.push((name.clone(), description.clone()));
+                    }
+                }
+                (*kind, diff)
+            })
+            .collect();
+        Self { old: old.version.clone(), new: new.version.clone(), kinds }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.kinds.iter().all(|(_, diff)| diff.is_empty())
+    }
+
+    /// Human readable report of every difference.
+    pub fn report(&self) -> String {
+        let mut out = String::new();
+        writeln!(out, "SimConnect {} -> {}", self.old, self.new).unwrap();
+        for ((_, label), (_, diff)) in KINDS.iter().zip(&self.kinds) {
+            if diff.is_empty() {
+                continue;
+            }
+            writeln!(out, "\n{label}:").unwrap();
+            for (name, description) in &diff.added {
+                writeln!(out, "  + {name} {description}").unwrap();
+            }
+            for (name, description) in &diff.removed {
+                writeln!(out, "  - {name} {description}").unwrap();
+            }
+            for changed in &diff.changed {
+                writeln!(out, "  ~ {}\n      {}\n   -> {}", changed.name, changed.old, changed.new).unwrap();
+            }
+        }
+        if self.is_empty() {
+            writeln!(out, "\nno differences").unwrap();
+        }
+        out
+    }
+
+    /// Machine readable diff, as JSON.
+    pub fn json(&self) -> String {
+        let mut out = String::new();
+        write!(out, "{{\n  \"old\": {},\n  \"new\": {}", json_string(&self.old), json_string(&self.new)).unwrap();
+        for (kind, diff) in &self.kinds {
+            let items = |items: &[(String, String)]| items.iter()
+                .map(|(name, description)| format!(
+                    "\n        {{ \"name\": {}, \"description\": {} }}",
+                    json_string(name),
+                    json_string(description),
+                ))
+                .collect::<Vec<_>>()
+                .join(",");
+            let changed = diff.changed.iter()
+                .map(|changed| format!(
+                    "\n        {{ \"name\": {}, \"old\": {}, \"new\": {} }}",
+                    json_string(&changed.name),
+                    json_string(&changed.old),
+                    json_string(&changed.new),
+                ))
+                .collect::<Vec<_>>()
+                .join(",");
+            write!(
+                out,
+                ",\n  \"{kind}\": {{\n    \"added\": [{}],\n    \"removed\": [{}],\n    \"changed\": [{}]\n  }}",
+                list(items(&diff.added)),
+                list(items(&diff.removed)),
+                list(changed),
+            ).unwrap();
+        }
+        out += "\n}\n";
+        out
+    }
+
+    /// Entries for the `[Unreleased]` section of CHANGELOG.md.
+    pub fn changelog(&self) -> String {
+        let mut out = String::new();
+        let sections: [(&str, Names); 3] = [
+            ("Added", |diff| diff.added.iter().map(|(name, _)| name.clone()).collect()),
+            ("Changed", |diff| diff.changed.iter().map(|changed| changed.name.clone()).collect()),
+            ("Removed", |diff| diff.removed.iter().map(|(name, _)| name.clone()).collect()),
+        ];
+        for (heading, names) in sections {
+            let entries: Vec<String> = KINDS.iter().zip(&self.kinds)
+                .filter_map(|((_, label), (_, diff))| {
+                    let names = names(diff);
+                    (!names.is_empty()).then(|| {
+                        let names = names.iter().map(|name| format!("`{name}`")).collect::<Vec<_>>().join(", ");
+                        format!("* {heading} SimConnect SDK {} {label} {names}.", self.new)
+                    })
+                })
+                .collect();
+            if !entries.is_empty() {
+                writeln!(out, "### {heading}").unwrap();
+                for entry in entries {
+                    writeln!(out, "{entry}").unwrap();
+                }
+                writeln!(out).unwrap();
+            }
+        }
+        out
+    }
+}
+
+// the sdk version from a path like `sdk/<version>/include/SimConnect.h` or
+// `bindings/<version>`, falling back to the path itself
+fn version_of(path: &Path) -> String {
+    let is_version = |s: &str| !s.is_empty() && s.split('.').all(|n| !n.is_empty() && n.chars().all(|c| c.is_ascii_digit()));
+    path.ancestors()
+        .filter_map(|p| p.file_name()?.to_str())
+        .find(|name| is_version(name))
+        .map(str::to_string)
+        .unwrap_or_else(|| path.display().to_string())
+}
+
+fn list(items: String) -> String {
+    match items.is_empty() {
+        true => items,
+        false => format!("{items}\n      "),
+    }
+}
+
+fn json_string(s: &str) -> String {
+    let mut out = String::from("\"");
+    for c in s.chars() {
+        match c {
+            '"' => out += "\\\"",
+            '\\' => out += "\\\\",
+            '\n' => out += "\\n",
+            c if (c as u32) < 0x20 => write!(out, "\\u{:04x}", c as u32).unwrap(),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+/// Writes `contents` to `path`, or stdout for `-`.
+pub fn output(path: &PathBuf, contents: &str) {
+    if path.as_os_str() == "-" {
+        print!("{contents}");
+    } else {
+        std::fs::write(path, contents).unwrap();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const OLD: &str = r#"
+        pub const SIMCONNECT_RECV_ID_SIMCONNECT_RECV_ID_EVENT: SIMCONNECT_RECV_ID = 4;
+        pub struct SIMCONNECT_RECV { pub dwSize: DWORD }
+        extern "C" { pub fn SimConnect_Open(phSimConnect: *mut HANDLE) -> HRESULT; }
+    "#;
+
+    const NEW: &str = r#"
+        pub struct SIMCONNECT_RECV { pub dwSize: DWORD, pub dwVersion: DWORD }
+        extern "C" {
+            pub fn SimConnect_Open(phSimConnect: *mut HANDLE) -> HRESULT;
+            pub fn SimConnect_Close(hSimConnect: HANDLE) -> HRESULT;
+        }
+    "#;
+
+    fn diff() -> Diff {
+        let old = Api { version: "0.24.3".into(), ..Api::parse(OLD) };
+        let new = Api { version: "0.25.0".into(), ..Api::parse(NEW) };
+        Diff::new(&old, &new)
+    }
+
+    #[test]
+    fn json() {
+        let json = diff().json();
+        assert!(json.starts_with("{\n  \"old\": \"0.24.3\",\n  \"new\": \"0.25.0\""));
+        assert!(json.contains(
+            "\"functions\": {\n    \"added\": [\n        \
+            { \"name\": \"SimConnect_Close\", \"description\": \"(hSimConnect : HANDLE) -> HRESULT\" }\n      ],"));
+        assert!(json.contains(
+            "\"changed\": [\n        { \"name\": \"SIMCONNECT_RECV\", \"old\": \"{ dwSize: DWORD }\", \
+            \"new\": \"{ dwSize: DWORD, dwVersion: DWORD }\" }\n      ]"));
+        assert!(json.contains("\"removed\": [\n        { \"name\": \"SIMCONNECT_RECV_ID_SIMCONNECT_RECV_ID_EVENT\""));
+        assert!(json.contains("\"constants\": {\n    \"added\": [],\n    \"removed\": [],\n    \"changed\": []\n  }\n}\n"));
+    }
+
+    #[test]
+    fn changelog() {
+        assert_eq!(diff().changelog(), "\
+### Added
+* Added SimConnect SDK 0.25.0 functions `SimConnect_Close`.
+
+### Changed
+* Changed SimConnect SDK 0.25.0 structs `SIMCONNECT_RECV`.
+
+### Removed
+* Removed SimConnect SDK 0.25.0 `SIMCONNECT_RECV_ID_*` codes `SIMCONNECT_RECV_ID_SIMCONNECT_RECV_ID_EVENT`.
+
+");
+    }
+}
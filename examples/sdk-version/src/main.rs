@@ -2,10 +2,79 @@ use std::path::{Path, PathBuf};
 use scraper::{Html, Selector};
 use semver::Version;
 
-const VERSION_TXT: &'static str = "simconnect-sys/sdk/version.txt";
-const RELEASE_URL: &'static str = "https://docs.flightsimulator.com/html/Introduction/Release_Notes.htm";
+mod diff;
+
+const VERSION_TXT: &str = "simconnect-sys/sdk/version.txt";
+const WRAPPER_H: &str = "simconnect-sys/wrapper.h";
+const RELEASE_URL: &str = "https://docs.flightsimulator.com/html/Introduction/Release_Notes.htm";
+
+const USAGE: &str = "\
+usage: sdk-version
+       sdk-version diff <old> <new> [--json <file>] [--changelog <file>]
+
+Without arguments, checks the release notes for a newer SDK than the vendored one.
+
+`diff` compares two SimConnect headers, given as a SimConnect.h, generated
+bindings or a bindings/<version> directory, and reports the added, removed and
+changed functions, structs, enum values and SIMCONNECT_RECV_ID codes. The
+differences can also be written as JSON and as CHANGELOG entries, use - for
+stdout, which moves the report to stderr. Exits with 1 if the headers differ.";
 
 fn main() {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    match args.first().map(String::as_str) {
+        None => check(),
+        Some("diff") => diff(&args[1..]),
+        Some(_) => usage(),
+    }
+}
+
+fn usage() -> ! {
+    eprintln!("{USAGE}");
+    std::process::exit(2)
+}
+
+fn diff(args: &[String]) {
+    let mut paths = Vec::new();
+    let mut json = None;
+    let mut changelog = None;
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--json" => json = Some(args.next().map(PathBuf::from).unwrap_or_else(|| usage())),
+            "--changelog" => changelog = Some(args.next().map(PathBuf::from).unwrap_or_else(|| usage())),
+            _ => paths.push(PathBuf::from(arg)),
+        }
+    }
+    let [old, new] = paths.as_slice() else {
+        usage()
+    };
+
+    // compare both headers as build.rs would bind them
+    let wrapper = workspace_dir().join(WRAPPER_H);
+    let diff = diff::Diff::new(&diff::Api::load(old, &wrapper), &diff::Api::load(new, &wrapper));
+
+    // keep stdout for the json or changelog when either is written there
+    let to_stdout = |path: &Option<PathBuf>| path.as_ref().is_some_and(|path| path.as_os_str() == "-");
+    if to_stdout(&json) || to_stdout(&changelog) {
+        eprint!("{}", diff.report());
+    } else {
+        print!("{}", diff.report());
+    }
+    if let Some(path) = json {
+        diff::output(&path, &diff.json());
+    }
+    if let Some(path) = changelog {
+        diff::output(&path, &diff.changelog());
+    }
+
+    // indicate differences with exit code of 1, as for a new SDK version
+    if !diff.is_empty() {
+        std::process::exit(1)
+    }
+}
+
+fn check() {
 
     // scrape SimConnect release notes
     let html = reqwest::blocking::get(RELEASE_URL).unwrap().text().unwrap();
@@ -25,10 +25,11 @@ impl ExampleData {
         let p = std::ptr::addr_of!((*data).dwData) as *const ExampleData;
         Ok(*p)
     }
-    unsafe fn title(&self) -> String {
-        std::str::from_utf8_unchecked(std::ffi::CStr::from_ptr(
-            &self.title as *const u8 as *const i8).to_bytes()
-        ).to_string()
+    fn title(&self) -> &str {
+        // STRING128 is nul padded, but don't trust it to be nul terminated or UTF-8
+        std::ffi::CStr::from_bytes_until_nul(&self.title).ok()
+            .and_then(|s| s.to_str().ok())
+            .unwrap_or("<invalid>")
     }
 }
 
@@ -140,7 +141,7 @@ async fn main() -> Result<(), &'static str> {
                 match msg {
                     Some(data) =>  
                         println!("RECV_DATA - Title: '{}', Heading: {:.0}°", 
-                            unsafe { data.title() }, data.heading),
+                            data.title(), data.heading),
                     None => break
                 }
             }
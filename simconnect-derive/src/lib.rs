@@ -0,0 +1,63 @@
+//! Derive macros for the [`simconnect`](https://docs.rs/simconnect) crate,
+//! use them through the re-exports in `simconnect`.
+
+use proc_macro::TokenStream;
+use syn::{parse_macro_input, DeriveInput};
+
+mod sim_data;
+mod sim_events;
+
+/// Derives `simconnect::SimData` for a struct with named fields.
+///
+/// Every field is annotated with the simulation variable it holds:
+///
+/// ```ignore
+/// #[derive(SimData)]
+/// struct Aircraft {
+///     #[simvar(name = "PLANE ALTITUDE", units = "feet", epsilon = 1.0)]
+///     altitude: f64,
+///     #[simvar(skip)]
+///     last_update: Option<std::time::Instant>,
+/// }
+/// ```
+///
+/// Every field needs a `#[simvar]` attribute, fields that aren't part of the
+/// data definition are marked `#[simvar(skip)]` and initialised with
+/// `Default`. `units` defaults to no units and `epsilon` to `0.0`.
+///
+/// A `#[repr(C, packed)]` layout of the registered fields is generated and
+/// its size checked against `SimData::SIZE` at compile time, unless the
+/// struct is generic.
+#[proc_macro_derive(SimData, attributes(simvar))]
+pub fn derive_sim_data(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    sim_data::expand(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Derives `simconnect::SimEvents` for a fieldless enum.
+///
+/// Every variant maps to the key event named after it in SCREAMING_SNAKE_CASE,
+/// unless another name is given:
+///
+/// ```ignore
+/// #[derive(Clone, Copy, SimEvents)]
+/// enum Controls {
+///     GearToggle,
+///     #[event(name = "AP_MASTER")]
+///     Autopilot,
+///     #[event(name = "MOBIFLIGHT.CUSTOM_EVENT", unchecked)]
+///     Custom,
+/// }
+/// ```
+///
+/// Names are checked against `simconnect::key_events` at compile time, with a
+/// warning for events missing from the catalog that `unchecked` silences.
+#[proc_macro_derive(SimEvents, attributes(event))]
+pub fn derive_sim_events(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    sim_events::expand(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
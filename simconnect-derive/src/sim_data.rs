@@ -0,0 +1,138 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Data, DeriveInput, Fields, LitFloat, LitStr, Result};
+
+struct SimVar {
+    name: LitStr,
+    units: Option<LitStr>,
+    epsilon: Option<LitFloat>,
+}
+
+// parses `#[simvar(...)]`, returning `None` for skipped fields
+fn parse_simvar(field: &syn::Field) -> Result<Option<SimVar>> {
+    let Some(attr) = field.attrs.iter().find(|a| a.path().is_ident("simvar")) else {
+        return Err(syn::Error::new_spanned(field, "missing #[simvar(name = \"...\")] attribute"));
+    };
+
+    let mut name = None;
+    let mut units = None;
+    let mut epsilon = None;
+    let mut skip = false;
+    attr.parse_nested_meta(|meta| {
+        if meta.path.is_ident("name") {
+            name = Some(meta.value()?.parse()?);
+        } else if meta.path.is_ident("units") {
+            units = Some(meta.value()?.parse()?);
+        } else if meta.path.is_ident("epsilon") {
+            epsilon = Some(meta.value()?.parse()?);
+        } else if meta.path.is_ident("skip") {
+            skip = true;
+        } else {
+            return Err(meta.error("expected `name`, `units`, `epsilon` or `skip`"));
+        }
+        Ok(())
+    })?;
+
+    if skip {
+        return Ok(None);
+    }
+    let name = name.ok_or_else(|| syn::Error::new_spanned(attr, "missing `name`"))?;
+    Ok(Some(SimVar { name, units, epsilon }))
+}
+
+pub(crate) fn expand(input: DeriveInput) -> Result<TokenStream> {
+    let Data::Struct(data) = &input.data else {
+        return Err(syn::Error::new_spanned(&input, "SimData can only be derived for structs"));
+    };
+    let Fields::Named(fields) = &data.fields else {
+        return Err(syn::Error::new_spanned(&input, "SimData requires named fields"));
+    };
+
+    let mut defs = Vec::new();
+    let mut reads = Vec::new();
+    let mut writes = Vec::new();
+    let mut patches = Vec::new();
+    let mut layout = Vec::new();
+    for field in &fields.named {
+        let ident = &field.ident;
+        let ty = &field.ty;
+        let Some(var) = parse_simvar(field)? else {
+            reads.push(quote! { #ident: ::core::default::Default::default() });
+            continue;
+        };
+
+        let name = &var.name;
+        let units = var.units.map_or_else(|| quote!(""), |u| quote!(#u));
+        let epsilon = var.epsilon.map_or_else(|| quote!(0.0), |e| quote!(#e));
+        defs.push(quote! {
+            ::simconnect::DataField {
+                name: #name,
+                units: #units,
+                data_type: <#ty as ::simconnect::DataValue>::DATA_TYPE,
+                epsilon: #epsilon,
+            }
+        });
+        reads.push(quote! { #ident: <#ty as ::simconnect::DataValue>::read(r)? });
+
+        // datum ids are the index of the field within the definition
+        let datum_id = defs.len() as u32 - 1;
+        patches.push(quote! {
+            #datum_id => self.#ident = <#ty as ::simconnect::DataValue>::read(r)?,
+        });
+        writes.push(quote! { <#ty as ::simconnect::DataValue>::write(&self.#ident, out); });
+
+        // variable length strings take no space, the size isn't checked with them
+        layout.push(quote! {
+            [u8; match <#ty as ::simconnect::DataValue>::DATA_TYPE.size() {
+                ::core::option::Option::Some(size) => size,
+                ::core::option::Option::None => 0,
+            }]
+        });
+    }
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    // the packed layout of the untagged data, checked against the definition.
+    // items can't use the generics of the struct, so generic ones go unchecked
+    let check = input.generics.params.is_empty().then(|| quote! {
+        const _: () = {
+            #[repr(C, packed)]
+            struct Layout(#(#layout),*);
+
+            if let ::core::option::Option::Some(size) = <#name as ::simconnect::SimData>::SIZE {
+                ::core::assert!(
+                    size == ::core::mem::size_of::<Layout>(),
+                    "the layout of the struct doesn't match its data definition",
+                );
+            }
+        };
+    });
+    Ok(quote! {
+        #check
+
+        impl #impl_generics ::simconnect::SimData for #name #ty_generics #where_clause {
+            const FIELDS: &'static [::simconnect::DataField] = &[#(#defs),*];
+
+            fn read(r: &mut ::simconnect::Reader<'_>) -> ::core::result::Result<Self, ::simconnect::DecodeError> {
+                ::core::result::Result::Ok(Self { #(#reads),* })
+            }
+
+            fn write(&self, out: &mut ::std::vec::Vec<u8>) {
+                #(#writes)*
+            }
+
+            fn read_field(
+                &mut self,
+                datum_id: u32,
+                r: &mut ::simconnect::Reader<'_>,
+            ) -> ::core::result::Result<(), ::simconnect::DecodeError> {
+                match datum_id {
+                    #(#patches)*
+                    _ => return ::core::result::Result::Err(r.invalid("datum id", datum_id)),
+                }
+                ::core::result::Result::Ok(())
+            }
+        }
+    })
+}
@@ -0,0 +1,117 @@
+use proc_macro2::TokenStream;
+use quote::{quote, quote_spanned};
+use syn::spanned::Spanned;
+use syn::{Data, DeriveInput, Fields, LitStr, Result, Variant};
+
+struct Event {
+    name: LitStr,
+    unchecked: bool,
+}
+
+// parses `#[event(...)]`, defaulting the name to the variant in SCREAMING_SNAKE_CASE
+fn parse_event(variant: &Variant) -> Result<Event> {
+    let mut name = None;
+    let mut unchecked = false;
+    for attr in variant.attrs.iter().filter(|a| a.path().is_ident("event")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("name") {
+                name = Some(meta.value()?.parse()?);
+            } else if meta.path.is_ident("unchecked") {
+                unchecked = true;
+            } else {
+                return Err(meta.error("expected `name` or `unchecked`"));
+            }
+            Ok(())
+        })?;
+    }
+
+    let name = name.unwrap_or_else(|| LitStr::new(&screaming_snake_case(&variant.ident.to_string()), variant.ident.span()));
+    Ok(Event { name, unchecked })
+}
+
+fn screaming_snake_case(ident: &str) -> String {
+    let mut out = String::new();
+    let chars: Vec<char> = ident.chars().collect();
+    for (i, &c) in chars.iter().enumerate() {
+        // a word starts at an uppercase letter after a lowercase one or a
+        // digit, or before a lowercase one ending an acronym
+        let starts_word = i > 0 && c.is_uppercase() && (
+            !chars[i - 1].is_uppercase() || chars.get(i + 1).is_some_and(|n| n.is_lowercase())
+        );
+        if starts_word && !out.ends_with('_') {
+            out.push('_');
+        }
+        out.extend(c.to_uppercase());
+    }
+    out
+}
+
+pub(crate) fn expand(input: DeriveInput) -> Result<TokenStream> {
+    let Data::Enum(data) = &input.data else {
+        return Err(syn::Error::new_spanned(&input, "SimEvents can only be derived for enums"));
+    };
+
+    let mut variants = Vec::new();
+    let mut names = Vec::new();
+    let mut indices = Vec::new();
+    let mut checks = Vec::new();
+    for (index, variant) in data.variants.iter().enumerate() {
+        if !matches!(variant.fields, Fields::Unit) {
+            return Err(syn::Error::new_spanned(variant, "SimEvents variants can't have fields"));
+        }
+        let ident = &variant.ident;
+        let event = parse_event(variant)?;
+        let name = &event.name;
+
+        variants.push(quote! { Self::#ident });
+        names.push(quote! { Self::#ident => #name });
+        indices.push(quote! { Self::#ident => #index });
+
+        // warns on a name missing from the catalog, which picks the deprecated
+        // impl to call. the catalog isn't exhaustive so it isn't an error
+        if !event.unchecked {
+            let message = format!("`{}` is not a known key event, add #[event(unchecked)] if it is intended", name.value());
+            checks.push(quote_spanned! {variant.span()=>
+                const _: () = {
+                    struct Known<const KNOWN: bool>;
+
+                    #[allow(dead_code)]
+                    impl Known<true> {
+                        const fn check() {}
+                    }
+
+                    #[allow(dead_code)]
+                    impl Known<false> {
+                        #[deprecated(note = #message)]
+                        const fn check() {}
+                    }
+
+                    Known::<{ ::simconnect::key_events::is_known(#name) }>::check();
+                };
+            });
+        }
+    }
+
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        #(#checks)*
+
+        impl #impl_generics ::simconnect::SimEvents for #ident #ty_generics #where_clause {
+            const EVENTS: &'static [Self] = &[#(#variants),*];
+
+            fn name(self) -> &'static str {
+                match self {
+                    #(#names,)*
+                }
+            }
+
+            fn index(self) -> usize {
+                match self {
+                    #(#indices,)*
+                }
+            }
+        }
+    })
+}
+
@@ -0,0 +1,2126 @@
+// generated from SimConnect.h of SDK 0.24.3, regenerate with SIMCONNECT_UPDATE_BINDINGS=1
+
+pub struct SimConnectLibrary {
+    __library: ::libloading::Library,
+    pub SimConnect_MapClientEventToSimEvent: Result<
+        unsafe extern "C" fn(
+            hSimConnect: HANDLE,
+            EventID: SIMCONNECT_CLIENT_EVENT_ID,
+            EventName: *const crate::ctypes::c_char,
+        ) -> HRESULT,
+        ::libloading::Error,
+    >,
+    pub SimConnect_TransmitClientEvent: Result<
+        unsafe extern "C" fn(
+            hSimConnect: HANDLE,
+            ObjectID: SIMCONNECT_OBJECT_ID,
+            EventID: SIMCONNECT_CLIENT_EVENT_ID,
+            dwData: DWORD,
+            GroupID: SIMCONNECT_NOTIFICATION_GROUP_ID,
+            Flags: SIMCONNECT_EVENT_FLAG,
+        ) -> HRESULT,
+        ::libloading::Error,
+    >,
+    pub SimConnect_SetSystemEventState: Result<
+        unsafe extern "C" fn(
+            hSimConnect: HANDLE,
+            EventID: SIMCONNECT_CLIENT_EVENT_ID,
+            dwState: SIMCONNECT_STATE,
+        ) -> HRESULT,
+        ::libloading::Error,
+    >,
+    pub SimConnect_AddClientEventToNotificationGroup: Result<
+        unsafe extern "C" fn(
+            hSimConnect: HANDLE,
+            GroupID: SIMCONNECT_NOTIFICATION_GROUP_ID,
+            EventID: SIMCONNECT_CLIENT_EVENT_ID,
+            bMaskable: BOOL,
+        ) -> HRESULT,
+        ::libloading::Error,
+    >,
+    pub SimConnect_RemoveClientEvent: Result<
+        unsafe extern "C" fn(
+            hSimConnect: HANDLE,
+            GroupID: SIMCONNECT_NOTIFICATION_GROUP_ID,
+            EventID: SIMCONNECT_CLIENT_EVENT_ID,
+        ) -> HRESULT,
+        ::libloading::Error,
+    >,
+    pub SimConnect_SetNotificationGroupPriority: Result<
+        unsafe extern "C" fn(
+            hSimConnect: HANDLE,
+            GroupID: SIMCONNECT_NOTIFICATION_GROUP_ID,
+            uPriority: DWORD,
+        ) -> HRESULT,
+        ::libloading::Error,
+    >,
+    pub SimConnect_ClearNotificationGroup: Result<
+        unsafe extern "C" fn(
+            hSimConnect: HANDLE,
+            GroupID: SIMCONNECT_NOTIFICATION_GROUP_ID,
+        ) -> HRESULT,
+        ::libloading::Error,
+    >,
+    pub SimConnect_RequestNotificationGroup: Result<
+        unsafe extern "C" fn(
+            hSimConnect: HANDLE,
+            GroupID: SIMCONNECT_NOTIFICATION_GROUP_ID,
+            dwReserved: DWORD,
+            Flags: DWORD,
+        ) -> HRESULT,
+        ::libloading::Error,
+    >,
+    pub SimConnect_AddToDataDefinition: Result<
+        unsafe extern "C" fn(
+            hSimConnect: HANDLE,
+            DefineID: SIMCONNECT_DATA_DEFINITION_ID,
+            DatumName: *const crate::ctypes::c_char,
+            UnitsName: *const crate::ctypes::c_char,
+            DatumType: SIMCONNECT_DATATYPE,
+            fEpsilon: f32,
+            DatumID: DWORD,
+        ) -> HRESULT,
+        ::libloading::Error,
+    >,
+    pub SimConnect_ClearDataDefinition: Result<
+        unsafe extern "C" fn(
+            hSimConnect: HANDLE,
+            DefineID: SIMCONNECT_DATA_DEFINITION_ID,
+        ) -> HRESULT,
+        ::libloading::Error,
+    >,
+    pub SimConnect_RequestDataOnSimObject: Result<
+        unsafe extern "C" fn(
+            hSimConnect: HANDLE,
+            RequestID: SIMCONNECT_DATA_REQUEST_ID,
+            DefineID: SIMCONNECT_DATA_DEFINITION_ID,
+            ObjectID: SIMCONNECT_OBJECT_ID,
+            Period: SIMCONNECT_PERIOD,
+            Flags: SIMCONNECT_DATA_REQUEST_FLAG,
+            origin: DWORD,
+            interval: DWORD,
+            limit: DWORD,
+        ) -> HRESULT,
+        ::libloading::Error,
+    >,
+    pub SimConnect_RequestDataOnSimObjectType: Result<
+        unsafe extern "C" fn(
+            hSimConnect: HANDLE,
+            RequestID: SIMCONNECT_DATA_REQUEST_ID,
+            DefineID: SIMCONNECT_DATA_DEFINITION_ID,
+            dwRadiusMeters: DWORD,
+            type_: SIMCONNECT_SIMOBJECT_TYPE,
+        ) -> HRESULT,
+        ::libloading::Error,
+    >,
+    pub SimConnect_SetDataOnSimObject: Result<
+        unsafe extern "C" fn(
+            hSimConnect: HANDLE,
+            DefineID: SIMCONNECT_DATA_DEFINITION_ID,
+            ObjectID: SIMCONNECT_OBJECT_ID,
+            Flags: SIMCONNECT_DATA_SET_FLAG,
+            ArrayCount: DWORD,
+            cbUnitSize: DWORD,
+            pDataSet: *mut crate::ctypes::c_void,
+        ) -> HRESULT,
+        ::libloading::Error,
+    >,
+    pub SimConnect_MapInputEventToClientEvent: Result<
+        unsafe extern "C" fn(
+            hSimConnect: HANDLE,
+            GroupID: SIMCONNECT_INPUT_GROUP_ID,
+            szInputDefinition: *const crate::ctypes::c_char,
+            DownEventID: SIMCONNECT_CLIENT_EVENT_ID,
+            DownValue: DWORD,
+            UpEventID: SIMCONNECT_CLIENT_EVENT_ID,
+            UpValue: DWORD,
+            bMaskable: BOOL,
+        ) -> HRESULT,
+        ::libloading::Error,
+    >,
+    pub SimConnect_SetInputGroupPriority: Result<
+        unsafe extern "C" fn(
+            hSimConnect: HANDLE,
+            GroupID: SIMCONNECT_INPUT_GROUP_ID,
+            uPriority: DWORD,
+        ) -> HRESULT,
+        ::libloading::Error,
+    >,
+    pub SimConnect_RemoveInputEvent: Result<
+        unsafe extern "C" fn(
+            hSimConnect: HANDLE,
+            GroupID: SIMCONNECT_INPUT_GROUP_ID,
+            szInputDefinition: *const crate::ctypes::c_char,
+        ) -> HRESULT,
+        ::libloading::Error,
+    >,
+    pub SimConnect_ClearInputGroup: Result<
+        unsafe extern "C" fn(
+            hSimConnect: HANDLE,
+            GroupID: SIMCONNECT_INPUT_GROUP_ID,
+        ) -> HRESULT,
+        ::libloading::Error,
+    >,
+    pub SimConnect_SetInputGroupState: Result<
+        unsafe extern "C" fn(
+            hSimConnect: HANDLE,
+            GroupID: SIMCONNECT_INPUT_GROUP_ID,
+            dwState: DWORD,
+        ) -> HRESULT,
+        ::libloading::Error,
+    >,
+    pub SimConnect_RequestReservedKey: Result<
+        unsafe extern "C" fn(
+            hSimConnect: HANDLE,
+            EventID: SIMCONNECT_CLIENT_EVENT_ID,
+            szKeyChoice1: *const crate::ctypes::c_char,
+            szKeyChoice2: *const crate::ctypes::c_char,
+            szKeyChoice3: *const crate::ctypes::c_char,
+        ) -> HRESULT,
+        ::libloading::Error,
+    >,
+    pub SimConnect_SubscribeToSystemEvent: Result<
+        unsafe extern "C" fn(
+            hSimConnect: HANDLE,
+            EventID: SIMCONNECT_CLIENT_EVENT_ID,
+            SystemEventName: *const crate::ctypes::c_char,
+        ) -> HRESULT,
+        ::libloading::Error,
+    >,
+    pub SimConnect_UnsubscribeFromSystemEvent: Result<
+        unsafe extern "C" fn(
+            hSimConnect: HANDLE,
+            EventID: SIMCONNECT_CLIENT_EVENT_ID,
+        ) -> HRESULT,
+        ::libloading::Error,
+    >,
+    pub SimConnect_WeatherRequestInterpolatedObservation: Result<
+        unsafe extern "C" fn(
+            hSimConnect: HANDLE,
+            RequestID: SIMCONNECT_DATA_REQUEST_ID,
+            lat: f32,
+            lon: f32,
+            alt: f32,
+        ) -> HRESULT,
+        ::libloading::Error,
+    >,
+    pub SimConnect_WeatherRequestObservationAtStation: Result<
+        unsafe extern "C" fn(
+            hSimConnect: HANDLE,
+            RequestID: SIMCONNECT_DATA_REQUEST_ID,
+            szICAO: *const crate::ctypes::c_char,
+        ) -> HRESULT,
+        ::libloading::Error,
+    >,
+    pub SimConnect_WeatherRequestObservationAtNearestStation: Result<
+        unsafe extern "C" fn(
+            hSimConnect: HANDLE,
+            RequestID: SIMCONNECT_DATA_REQUEST_ID,
+            lat: f32,
+            lon: f32,
+        ) -> HRESULT,
+        ::libloading::Error,
+    >,
+    pub SimConnect_WeatherCreateStation: Result<
+        unsafe extern "C" fn(
+            hSimConnect: HANDLE,
+            RequestID: SIMCONNECT_DATA_REQUEST_ID,
+            szICAO: *const crate::ctypes::c_char,
+            szName: *const crate::ctypes::c_char,
+            lat: f32,
+            lon: f32,
+            alt: f32,
+        ) -> HRESULT,
+        ::libloading::Error,
+    >,
+    pub SimConnect_WeatherRemoveStation: Result<
+        unsafe extern "C" fn(
+            hSimConnect: HANDLE,
+            RequestID: SIMCONNECT_DATA_REQUEST_ID,
+            szICAO: *const crate::ctypes::c_char,
+        ) -> HRESULT,
+        ::libloading::Error,
+    >,
+    pub SimConnect_WeatherSetObservation: Result<
+        unsafe extern "C" fn(
+            hSimConnect: HANDLE,
+            Seconds: DWORD,
+            szMETAR: *const crate::ctypes::c_char,
+        ) -> HRESULT,
+        ::libloading::Error,
+    >,
+    pub SimConnect_WeatherSetModeServer: Result<
+        unsafe extern "C" fn(
+            hSimConnect: HANDLE,
+            dwPort: DWORD,
+            dwSeconds: DWORD,
+        ) -> HRESULT,
+        ::libloading::Error,
+    >,
+    pub SimConnect_WeatherSetModeTheme: Result<
+        unsafe extern "C" fn(
+            hSimConnect: HANDLE,
+            szThemeName: *const crate::ctypes::c_char,
+        ) -> HRESULT,
+        ::libloading::Error,
+    >,
+    pub SimConnect_WeatherSetModeGlobal: Result<
+        unsafe extern "C" fn(
+            hSimConnect: HANDLE,
+        ) -> HRESULT,
+        ::libloading::Error,
+    >,
+    pub SimConnect_WeatherSetModeCustom: Result<
+        unsafe extern "C" fn(
+            hSimConnect: HANDLE,
+        ) -> HRESULT,
+        ::libloading::Error,
+    >,
+    pub SimConnect_WeatherSetDynamicUpdateRate: Result<
+        unsafe extern "C" fn(
+            hSimConnect: HANDLE,
+            dwRate: DWORD,
+        ) -> HRESULT,
+        ::libloading::Error,
+    >,
+    pub SimConnect_WeatherRequestCloudState: Result<
+        unsafe extern "C" fn(
+            hSimConnect: HANDLE,
+            RequestID: SIMCONNECT_DATA_REQUEST_ID,
+            minLat: f32,
+            minLon: f32,
+            minAlt: f32,
+            maxLat: f32,
+            maxLon: f32,
+            maxAlt: f32,
+            dwFlags: DWORD,
+        ) -> HRESULT,
+        ::libloading::Error,
+    >,
+    pub SimConnect_WeatherCreateThermal: Result<
+        unsafe extern "C" fn(
+            hSimConnect: HANDLE,
+            RequestID: SIMCONNECT_DATA_REQUEST_ID,
+            lat: f32,
+            lon: f32,
+            alt: f32,
+            radius: f32,
+            height: f32,
+            coreRate: f32,
+            coreTurbulence: f32,
+            sinkRate: f32,
+            sinkTurbulence: f32,
+            coreSize: f32,
+            coreTransitionSize: f32,
+            sinkLayerSize: f32,
+            sinkTransitionSize: f32,
+        ) -> HRESULT,
+        ::libloading::Error,
+    >,
+    pub SimConnect_WeatherRemoveThermal: Result<
+        unsafe extern "C" fn(
+            hSimConnect: HANDLE,
+            ObjectID: SIMCONNECT_OBJECT_ID,
+        ) -> HRESULT,
+        ::libloading::Error,
+    >,
+    pub SimConnect_AICreateParkedATCAircraft: Result<
+        unsafe extern "C" fn(
+            hSimConnect: HANDLE,
+            szContainerTitle: *const crate::ctypes::c_char,
+            szTailNumber: *const crate::ctypes::c_char,
+            szAirportID: *const crate::ctypes::c_char,
+            RequestID: SIMCONNECT_DATA_REQUEST_ID,
+        ) -> HRESULT,
+        ::libloading::Error,
+    >,
+    pub SimConnect_AICreateEnrouteATCAircraft: Result<
+        unsafe extern "C" fn(
+            hSimConnect: HANDLE,
+            szContainerTitle: *const crate::ctypes::c_char,
+            szTailNumber: *const crate::ctypes::c_char,
+            iFlightNumber: crate::ctypes::c_int,
+            szFlightPlanPath: *const crate::ctypes::c_char,
+            dFlightPlanPosition: f64,
+            bTouchAndGo: BOOL,
+            RequestID: SIMCONNECT_DATA_REQUEST_ID,
+        ) -> HRESULT,
+        ::libloading::Error,
+    >,
+    pub SimConnect_AICreateNonATCAircraft: Result<
+        unsafe extern "C" fn(
+            hSimConnect: HANDLE,
+            szContainerTitle: *const crate::ctypes::c_char,
+            szTailNumber: *const crate::ctypes::c_char,
+            InitPos: SIMCONNECT_DATA_INITPOSITION,
+            RequestID: SIMCONNECT_DATA_REQUEST_ID,
+        ) -> HRESULT,
+        ::libloading::Error,
+    >,
+    pub SimConnect_AICreateSimulatedObject: Result<
+        unsafe extern "C" fn(
+            hSimConnect: HANDLE,
+            szContainerTitle: *const crate::ctypes::c_char,
+            InitPos: SIMCONNECT_DATA_INITPOSITION,
+            RequestID: SIMCONNECT_DATA_REQUEST_ID,
+        ) -> HRESULT,
+        ::libloading::Error,
+    >,
+    pub SimConnect_AIReleaseControl: Result<
+        unsafe extern "C" fn(
+            hSimConnect: HANDLE,
+            ObjectID: SIMCONNECT_OBJECT_ID,
+            RequestID: SIMCONNECT_DATA_REQUEST_ID,
+        ) -> HRESULT,
+        ::libloading::Error,
+    >,
+    pub SimConnect_AIRemoveObject: Result<
+        unsafe extern "C" fn(
+            hSimConnect: HANDLE,
+            ObjectID: SIMCONNECT_OBJECT_ID,
+            RequestID: SIMCONNECT_DATA_REQUEST_ID,
+        ) -> HRESULT,
+        ::libloading::Error,
+    >,
+    pub SimConnect_AISetAircraftFlightPlan: Result<
+        unsafe extern "C" fn(
+            hSimConnect: HANDLE,
+            ObjectID: SIMCONNECT_OBJECT_ID,
+            szFlightPlanPath: *const crate::ctypes::c_char,
+            RequestID: SIMCONNECT_DATA_REQUEST_ID,
+        ) -> HRESULT,
+        ::libloading::Error,
+    >,
+    pub SimConnect_ExecuteMissionAction: Result<
+        unsafe extern "C" fn(
+            hSimConnect: HANDLE,
+            guidInstanceId: GUID,
+        ) -> HRESULT,
+        ::libloading::Error,
+    >,
+    pub SimConnect_CompleteCustomMissionAction: Result<
+        unsafe extern "C" fn(
+            hSimConnect: HANDLE,
+            guidInstanceId: GUID,
+        ) -> HRESULT,
+        ::libloading::Error,
+    >,
+    pub SimConnect_Close: Result<
+        unsafe extern "C" fn(
+            hSimConnect: HANDLE,
+        ) -> HRESULT,
+        ::libloading::Error,
+    >,
+    pub SimConnect_RetrieveString: Result<
+        unsafe extern "C" fn(
+            pData: *mut SIMCONNECT_RECV,
+            cbData: DWORD,
+            pStringV: *mut crate::ctypes::c_void,
+            pszString: *mut *mut crate::ctypes::c_char,
+            pcbString: *mut DWORD,
+        ) -> HRESULT,
+        ::libloading::Error,
+    >,
+    pub SimConnect_GetLastSentPacketID: Result<
+        unsafe extern "C" fn(
+            hSimConnect: HANDLE,
+            pdwError: *mut DWORD,
+        ) -> HRESULT,
+        ::libloading::Error,
+    >,
+    pub SimConnect_Open: Result<
+        unsafe extern "C" fn(
+            phSimConnect: *mut HANDLE,
+            szName: LPCSTR,
+            hWnd: HWND,
+            UserEventWin32: DWORD,
+            hEventHandle: HANDLE,
+            ConfigIndex: DWORD,
+        ) -> HRESULT,
+        ::libloading::Error,
+    >,
+    pub SimConnect_CallDispatch: Result<
+        unsafe extern "C" fn(
+            hSimConnect: HANDLE,
+            pfcnDispatch: DispatchProc,
+            pContext: *mut crate::ctypes::c_void,
+        ) -> HRESULT,
+        ::libloading::Error,
+    >,
+    pub SimConnect_GetNextDispatch: Result<
+        unsafe extern "C" fn(
+            hSimConnect: HANDLE,
+            ppData: *mut *mut SIMCONNECT_RECV,
+            pcbData: *mut DWORD,
+        ) -> HRESULT,
+        ::libloading::Error,
+    >,
+    pub SimConnect_RequestResponseTimes: Result<
+        unsafe extern "C" fn(
+            hSimConnect: HANDLE,
+            nCount: DWORD,
+            fElapsedSeconds: *mut f32,
+        ) -> HRESULT,
+        ::libloading::Error,
+    >,
+    pub SimConnect_InsertString: Result<
+        unsafe extern "C" fn(
+            pDest: *mut crate::ctypes::c_char,
+            cbDest: DWORD,
+            ppEnd: *mut *mut crate::ctypes::c_void,
+            pcbStringV: *mut DWORD,
+            pSource: *const crate::ctypes::c_char,
+        ) -> HRESULT,
+        ::libloading::Error,
+    >,
+    pub SimConnect_CameraSetRelative6DOF: Result<
+        unsafe extern "C" fn(
+            hSimConnect: HANDLE,
+            fDeltaX: f32,
+            fDeltaY: f32,
+            fDeltaZ: f32,
+            fPitchDeg: f32,
+            fBankDeg: f32,
+            fHeadingDeg: f32,
+        ) -> HRESULT,
+        ::libloading::Error,
+    >,
+    pub SimConnect_MenuAddItem: Result<
+        unsafe extern "C" fn(
+            hSimConnect: HANDLE,
+            szMenuItem: *const crate::ctypes::c_char,
+            MenuEventID: SIMCONNECT_CLIENT_EVENT_ID,
+            dwData: DWORD,
+        ) -> HRESULT,
+        ::libloading::Error,
+    >,
+    pub SimConnect_MenuDeleteItem: Result<
+        unsafe extern "C" fn(
+            hSimConnect: HANDLE,
+            MenuEventID: SIMCONNECT_CLIENT_EVENT_ID,
+        ) -> HRESULT,
+        ::libloading::Error,
+    >,
+    pub SimConnect_MenuAddSubItem: Result<
+        unsafe extern "C" fn(
+            hSimConnect: HANDLE,
+            MenuEventID: SIMCONNECT_CLIENT_EVENT_ID,
+            szMenuItem: *const crate::ctypes::c_char,
+            SubMenuEventID: SIMCONNECT_CLIENT_EVENT_ID,
+            dwData: DWORD,
+        ) -> HRESULT,
+        ::libloading::Error,
+    >,
+    pub SimConnect_MenuDeleteSubItem: Result<
+        unsafe extern "C" fn(
+            hSimConnect: HANDLE,
+            MenuEventID: SIMCONNECT_CLIENT_EVENT_ID,
+            SubMenuEventID: SIMCONNECT_CLIENT_EVENT_ID,
+        ) -> HRESULT,
+        ::libloading::Error,
+    >,
+    pub SimConnect_RequestSystemState: Result<
+        unsafe extern "C" fn(
+            hSimConnect: HANDLE,
+            RequestID: SIMCONNECT_DATA_REQUEST_ID,
+            szState: *const crate::ctypes::c_char,
+        ) -> HRESULT,
+        ::libloading::Error,
+    >,
+    pub SimConnect_SetSystemState: Result<
+        unsafe extern "C" fn(
+            hSimConnect: HANDLE,
+            szState: *const crate::ctypes::c_char,
+            dwInteger: DWORD,
+            fFloat: f32,
+            szString: *const crate::ctypes::c_char,
+        ) -> HRESULT,
+        ::libloading::Error,
+    >,
+    pub SimConnect_MapClientDataNameToID: Result<
+        unsafe extern "C" fn(
+            hSimConnect: HANDLE,
+            szClientDataName: *const crate::ctypes::c_char,
+            ClientDataID: SIMCONNECT_CLIENT_DATA_ID,
+        ) -> HRESULT,
+        ::libloading::Error,
+    >,
+    pub SimConnect_CreateClientData: Result<
+        unsafe extern "C" fn(
+            hSimConnect: HANDLE,
+            ClientDataID: SIMCONNECT_CLIENT_DATA_ID,
+            dwSize: DWORD,
+            Flags: SIMCONNECT_CREATE_CLIENT_DATA_FLAG,
+        ) -> HRESULT,
+        ::libloading::Error,
+    >,
+    pub SimConnect_AddToClientDataDefinition: Result<
+        unsafe extern "C" fn(
+            hSimConnect: HANDLE,
+            DefineID: SIMCONNECT_CLIENT_DATA_DEFINITION_ID,
+            dwOffset: DWORD,
+            dwSizeOrType: DWORD,
+            fEpsilon: f32,
+            DatumID: DWORD,
+        ) -> HRESULT,
+        ::libloading::Error,
+    >,
+    pub SimConnect_ClearClientDataDefinition: Result<
+        unsafe extern "C" fn(
+            hSimConnect: HANDLE,
+            DefineID: SIMCONNECT_CLIENT_DATA_DEFINITION_ID,
+        ) -> HRESULT,
+        ::libloading::Error,
+    >,
+    pub SimConnect_RequestClientData: Result<
+        unsafe extern "C" fn(
+            hSimConnect: HANDLE,
+            ClientDataID: SIMCONNECT_CLIENT_DATA_ID,
+            RequestID: SIMCONNECT_DATA_REQUEST_ID,
+            DefineID: SIMCONNECT_CLIENT_DATA_DEFINITION_ID,
+            Period: SIMCONNECT_CLIENT_DATA_PERIOD,
+            Flags: SIMCONNECT_CLIENT_DATA_REQUEST_FLAG,
+            origin: DWORD,
+            interval: DWORD,
+            limit: DWORD,
+        ) -> HRESULT,
+        ::libloading::Error,
+    >,
+    pub SimConnect_SetClientData: Result<
+        unsafe extern "C" fn(
+            hSimConnect: HANDLE,
+            ClientDataID: SIMCONNECT_CLIENT_DATA_ID,
+            DefineID: SIMCONNECT_CLIENT_DATA_DEFINITION_ID,
+            Flags: SIMCONNECT_CLIENT_DATA_SET_FLAG,
+            dwReserved: DWORD,
+            cbUnitSize: DWORD,
+            pDataSet: *mut crate::ctypes::c_void,
+        ) -> HRESULT,
+        ::libloading::Error,
+    >,
+    pub SimConnect_FlightLoad: Result<
+        unsafe extern "C" fn(
+            hSimConnect: HANDLE,
+            szFileName: *const crate::ctypes::c_char,
+        ) -> HRESULT,
+        ::libloading::Error,
+    >,
+    pub SimConnect_FlightSave: Result<
+        unsafe extern "C" fn(
+            hSimConnect: HANDLE,
+            szFileName: *const crate::ctypes::c_char,
+            szTitle: *const crate::ctypes::c_char,
+            szDescription: *const crate::ctypes::c_char,
+            Flags: DWORD,
+        ) -> HRESULT,
+        ::libloading::Error,
+    >,
+    pub SimConnect_FlightPlanLoad: Result<
+        unsafe extern "C" fn(
+            hSimConnect: HANDLE,
+            szFileName: *const crate::ctypes::c_char,
+        ) -> HRESULT,
+        ::libloading::Error,
+    >,
+    pub SimConnect_Text: Result<
+        unsafe extern "C" fn(
+            hSimConnect: HANDLE,
+            type_: SIMCONNECT_TEXT_TYPE,
+            fTimeSeconds: f32,
+            EventID: SIMCONNECT_CLIENT_EVENT_ID,
+            cbUnitSize: DWORD,
+            pDataSet: *mut crate::ctypes::c_void,
+        ) -> HRESULT,
+        ::libloading::Error,
+    >,
+    pub SimConnect_SubscribeToFacilities: Result<
+        unsafe extern "C" fn(
+            hSimConnect: HANDLE,
+            type_: SIMCONNECT_FACILITY_LIST_TYPE,
+            RequestID: SIMCONNECT_DATA_REQUEST_ID,
+        ) -> HRESULT,
+        ::libloading::Error,
+    >,
+    pub SimConnect_UnsubscribeToFacilities: Result<
+        unsafe extern "C" fn(
+            hSimConnect: HANDLE,
+            type_: SIMCONNECT_FACILITY_LIST_TYPE,
+        ) -> HRESULT,
+        ::libloading::Error,
+    >,
+    pub SimConnect_RequestFacilitiesList: Result<
+        unsafe extern "C" fn(
+            hSimConnect: HANDLE,
+            type_: SIMCONNECT_FACILITY_LIST_TYPE,
+            RequestID: SIMCONNECT_DATA_REQUEST_ID,
+        ) -> HRESULT,
+        ::libloading::Error,
+    >,
+    pub SimConnect_TransmitClientEvent_EX1: Result<
+        unsafe extern "C" fn(
+            hSimConnect: HANDLE,
+            ObjectID: SIMCONNECT_OBJECT_ID,
+            EventID: SIMCONNECT_CLIENT_EVENT_ID,
+            GroupID: SIMCONNECT_NOTIFICATION_GROUP_ID,
+            Flags: SIMCONNECT_EVENT_FLAG,
+            dwData0: DWORD,
+            dwData1: DWORD,
+            dwData2: DWORD,
+            dwData3: DWORD,
+            dwData4: DWORD,
+        ) -> HRESULT,
+        ::libloading::Error,
+    >,
+    pub SimConnect_AddToFacilityDefinition: Result<
+        unsafe extern "C" fn(
+            hSimConnect: HANDLE,
+            DefineID: SIMCONNECT_DATA_DEFINITION_ID,
+            FieldName: *const crate::ctypes::c_char,
+        ) -> HRESULT,
+        ::libloading::Error,
+    >,
+    pub SimConnect_RequestFacilityData: Result<
+        unsafe extern "C" fn(
+            hSimConnect: HANDLE,
+            DefineID: SIMCONNECT_DATA_DEFINITION_ID,
+            RequestID: SIMCONNECT_DATA_REQUEST_ID,
+            ICAO: *const crate::ctypes::c_char,
+            Region: *const crate::ctypes::c_char,
+        ) -> HRESULT,
+        ::libloading::Error,
+    >,
+    pub SimConnect_SubscribeToFacilities_EX1: Result<
+        unsafe extern "C" fn(
+            hSimConnect: HANDLE,
+            type_: SIMCONNECT_FACILITY_LIST_TYPE,
+            newElemInRangeRequestID: SIMCONNECT_DATA_REQUEST_ID,
+            oldElemOutRangeRequestID: SIMCONNECT_DATA_REQUEST_ID,
+        ) -> HRESULT,
+        ::libloading::Error,
+    >,
+    pub SimConnect_UnsubscribeToFacilities_EX1: Result<
+        unsafe extern "C" fn(
+            hSimConnect: HANDLE,
+            type_: SIMCONNECT_FACILITY_LIST_TYPE,
+            bUnsubscribeNewInRange: bool,
+            bUnsubscribeOldOutRange: bool,
+        ) -> HRESULT,
+        ::libloading::Error,
+    >,
+    pub SimConnect_RequestFacilitiesList_EX1: Result<
+        unsafe extern "C" fn(
+            hSimConnect: HANDLE,
+            type_: SIMCONNECT_FACILITY_LIST_TYPE,
+            RequestID: SIMCONNECT_DATA_REQUEST_ID,
+        ) -> HRESULT,
+        ::libloading::Error,
+    >,
+    pub SimConnect_RequestFacilityData_EX1: Result<
+        unsafe extern "C" fn(
+            hSimConnect: HANDLE,
+            DefineID: SIMCONNECT_DATA_DEFINITION_ID,
+            RequestID: SIMCONNECT_DATA_REQUEST_ID,
+            ICAO: *const crate::ctypes::c_char,
+            Region: *const crate::ctypes::c_char,
+            Type: crate::ctypes::c_char,
+        ) -> HRESULT,
+        ::libloading::Error,
+    >,
+    pub SimConnect_RequestJetwayData: Result<
+        unsafe extern "C" fn(
+            hSimConnect: HANDLE,
+            AirportIcao: *const crate::ctypes::c_char,
+            ArrayCount: DWORD,
+            Indexes: *mut crate::ctypes::c_int,
+        ) -> HRESULT,
+        ::libloading::Error,
+    >,
+    pub SimConnect_EnumerateControllers: Result<
+        unsafe extern "C" fn(
+            hSimConnect: HANDLE,
+        ) -> HRESULT,
+        ::libloading::Error,
+    >,
+    pub SimConnect_MapInputEventToClientEvent_EX1: Result<
+        unsafe extern "C" fn(
+            hSimConnect: HANDLE,
+            GroupID: SIMCONNECT_INPUT_GROUP_ID,
+            szInputDefinition: *const crate::ctypes::c_char,
+            DownEventID: SIMCONNECT_CLIENT_EVENT_ID,
+            DownValue: DWORD,
+            UpEventID: SIMCONNECT_CLIENT_EVENT_ID,
+            UpValue: DWORD,
+            bMaskable: BOOL,
+        ) -> HRESULT,
+        ::libloading::Error,
+    >,
+    pub SimConnect_ExecuteAction: Result<
+        unsafe extern "C" fn(
+            hSimConnect: HANDLE,
+            cbRequestID: DWORD,
+            szActionID: *const crate::ctypes::c_char,
+            cbUnitSize: DWORD,
+            pParamValues: *mut crate::ctypes::c_void,
+        ) -> HRESULT,
+        ::libloading::Error,
+    >,
+    pub SimConnect_EnumerateInputEvents: Result<
+        unsafe extern "C" fn(
+            hSimConnect: HANDLE,
+            RequestID: SIMCONNECT_DATA_REQUEST_ID,
+        ) -> HRESULT,
+        ::libloading::Error,
+    >,
+    pub SimConnect_GetInputEvent: Result<
+        unsafe extern "C" fn(
+            hSimConnect: HANDLE,
+            RequestID: SIMCONNECT_DATA_REQUEST_ID,
+            Hash: UINT64,
+        ) -> HRESULT,
+        ::libloading::Error,
+    >,
+    pub SimConnect_SetInputEvent: Result<
+        unsafe extern "C" fn(
+            hSimConnect: HANDLE,
+            Hash: UINT64,
+            cbUnitSize: DWORD,
+            Value: *mut crate::ctypes::c_void,
+        ) -> HRESULT,
+        ::libloading::Error,
+    >,
+    pub SimConnect_SubscribeInputEvent: Result<
+        unsafe extern "C" fn(
+            hSimConnect: HANDLE,
+            Hash: UINT64,
+        ) -> HRESULT,
+        ::libloading::Error,
+    >,
+    pub SimConnect_UnsubscribeInputEvent: Result<
+        unsafe extern "C" fn(
+            hSimConnect: HANDLE,
+            Hash: UINT64,
+        ) -> HRESULT,
+        ::libloading::Error,
+    >,
+    pub SimConnect_EnumerateInputEventParams: Result<
+        unsafe extern "C" fn(
+            hSimConnect: HANDLE,
+            Hash: UINT64,
+        ) -> HRESULT,
+        ::libloading::Error,
+    >,
+    pub SimConnect_AddFacilityDataDefinitionFilter: Result<
+        unsafe extern "C" fn(
+            hSimConnect: HANDLE,
+            DefineID: SIMCONNECT_DATA_DEFINITION_ID,
+            szFilterPath: *const crate::ctypes::c_char,
+            cbUnitSize: DWORD,
+            pFilterData: *mut crate::ctypes::c_void,
+        ) -> HRESULT,
+        ::libloading::Error,
+    >,
+    pub SimConnect_ClearAllFacilityDataDefinitionFilters: Result<
+        unsafe extern "C" fn(
+            hSimConnect: HANDLE,
+            DefineID: SIMCONNECT_DATA_DEFINITION_ID,
+        ) -> HRESULT,
+        ::libloading::Error,
+    >,
+}
+impl SimConnectLibrary {
+    pub unsafe fn new<P>(path: P) -> Result<Self, ::libloading::Error>
+    where
+        P: AsRef<::std::ffi::OsStr>,
+    {
+        let library = ::libloading::Library::new(path)?;
+        Self::from_library(library)
+    }
+    pub unsafe fn from_library<L>(library: L) -> Result<Self, ::libloading::Error>
+    where
+        L: Into<::libloading::Library>,
+    {
+        let __library = library.into();
+        let SimConnect_MapClientEventToSimEvent = __library.get(b"SimConnect_MapClientEventToSimEvent\0").map(|sym| *sym);
+        let SimConnect_TransmitClientEvent = __library.get(b"SimConnect_TransmitClientEvent\0").map(|sym| *sym);
+        let SimConnect_SetSystemEventState = __library.get(b"SimConnect_SetSystemEventState\0").map(|sym| *sym);
+        let SimConnect_AddClientEventToNotificationGroup = __library.get(b"SimConnect_AddClientEventToNotificationGroup\0").map(|sym| *sym);
+        let SimConnect_RemoveClientEvent = __library.get(b"SimConnect_RemoveClientEvent\0").map(|sym| *sym);
+        let SimConnect_SetNotificationGroupPriority = __library.get(b"SimConnect_SetNotificationGroupPriority\0").map(|sym| *sym);
+        let SimConnect_ClearNotificationGroup = __library.get(b"SimConnect_ClearNotificationGroup\0").map(|sym| *sym);
+        let SimConnect_RequestNotificationGroup = __library.get(b"SimConnect_RequestNotificationGroup\0").map(|sym| *sym);
+        let SimConnect_AddToDataDefinition = __library.get(b"SimConnect_AddToDataDefinition\0").map(|sym| *sym);
+        let SimConnect_ClearDataDefinition = __library.get(b"SimConnect_ClearDataDefinition\0").map(|sym| *sym);
+        let SimConnect_RequestDataOnSimObject = __library.get(b"SimConnect_RequestDataOnSimObject\0").map(|sym| *sym);
+        let SimConnect_RequestDataOnSimObjectType = __library.get(b"SimConnect_RequestDataOnSimObjectType\0").map(|sym| *sym);
+        let SimConnect_SetDataOnSimObject = __library.get(b"SimConnect_SetDataOnSimObject\0").map(|sym| *sym);
+        let SimConnect_MapInputEventToClientEvent = __library.get(b"SimConnect_MapInputEventToClientEvent\0").map(|sym| *sym);
+        let SimConnect_SetInputGroupPriority = __library.get(b"SimConnect_SetInputGroupPriority\0").map(|sym| *sym);
+        let SimConnect_RemoveInputEvent = __library.get(b"SimConnect_RemoveInputEvent\0").map(|sym| *sym);
+        let SimConnect_ClearInputGroup = __library.get(b"SimConnect_ClearInputGroup\0").map(|sym| *sym);
+        let SimConnect_SetInputGroupState = __library.get(b"SimConnect_SetInputGroupState\0").map(|sym| *sym);
+        let SimConnect_RequestReservedKey = __library.get(b"SimConnect_RequestReservedKey\0").map(|sym| *sym);
+        let SimConnect_SubscribeToSystemEvent = __library.get(b"SimConnect_SubscribeToSystemEvent\0").map(|sym| *sym);
+        let SimConnect_UnsubscribeFromSystemEvent = __library.get(b"SimConnect_UnsubscribeFromSystemEvent\0").map(|sym| *sym);
+        let SimConnect_WeatherRequestInterpolatedObservation = __library.get(b"SimConnect_WeatherRequestInterpolatedObservation\0").map(|sym| *sym);
+        let SimConnect_WeatherRequestObservationAtStation = __library.get(b"SimConnect_WeatherRequestObservationAtStation\0").map(|sym| *sym);
+        let SimConnect_WeatherRequestObservationAtNearestStation = __library.get(b"SimConnect_WeatherRequestObservationAtNearestStation\0").map(|sym| *sym);
+        let SimConnect_WeatherCreateStation = __library.get(b"SimConnect_WeatherCreateStation\0").map(|sym| *sym);
+        let SimConnect_WeatherRemoveStation = __library.get(b"SimConnect_WeatherRemoveStation\0").map(|sym| *sym);
+        let SimConnect_WeatherSetObservation = __library.get(b"SimConnect_WeatherSetObservation\0").map(|sym| *sym);
+        let SimConnect_WeatherSetModeServer = __library.get(b"SimConnect_WeatherSetModeServer\0").map(|sym| *sym);
+        let SimConnect_WeatherSetModeTheme = __library.get(b"SimConnect_WeatherSetModeTheme\0").map(|sym| *sym);
+        let SimConnect_WeatherSetModeGlobal = __library.get(b"SimConnect_WeatherSetModeGlobal\0").map(|sym| *sym);
+        let SimConnect_WeatherSetModeCustom = __library.get(b"SimConnect_WeatherSetModeCustom\0").map(|sym| *sym);
+        let SimConnect_WeatherSetDynamicUpdateRate = __library.get(b"SimConnect_WeatherSetDynamicUpdateRate\0").map(|sym| *sym);
+        let SimConnect_WeatherRequestCloudState = __library.get(b"SimConnect_WeatherRequestCloudState\0").map(|sym| *sym);
+        let SimConnect_WeatherCreateThermal = __library.get(b"SimConnect_WeatherCreateThermal\0").map(|sym| *sym);
+        let SimConnect_WeatherRemoveThermal = __library.get(b"SimConnect_WeatherRemoveThermal\0").map(|sym| *sym);
+        let SimConnect_AICreateParkedATCAircraft = __library.get(b"SimConnect_AICreateParkedATCAircraft\0").map(|sym| *sym);
+        let SimConnect_AICreateEnrouteATCAircraft = __library.get(b"SimConnect_AICreateEnrouteATCAircraft\0").map(|sym| *sym);
+        let SimConnect_AICreateNonATCAircraft = __library.get(b"SimConnect_AICreateNonATCAircraft\0").map(|sym| *sym);
+        let SimConnect_AICreateSimulatedObject = __library.get(b"SimConnect_AICreateSimulatedObject\0").map(|sym| *sym);
+        let SimConnect_AIReleaseControl = __library.get(b"SimConnect_AIReleaseControl\0").map(|sym| *sym);
+        let SimConnect_AIRemoveObject = __library.get(b"SimConnect_AIRemoveObject\0").map(|sym| *sym);
+        let SimConnect_AISetAircraftFlightPlan = __library.get(b"SimConnect_AISetAircraftFlightPlan\0").map(|sym| *sym);
+        let SimConnect_ExecuteMissionAction = __library.get(b"SimConnect_ExecuteMissionAction\0").map(|sym| *sym);
+        let SimConnect_CompleteCustomMissionAction = __library.get(b"SimConnect_CompleteCustomMissionAction\0").map(|sym| *sym);
+        let SimConnect_Close = __library.get(b"SimConnect_Close\0").map(|sym| *sym);
+        let SimConnect_RetrieveString = __library.get(b"SimConnect_RetrieveString\0").map(|sym| *sym);
+        let SimConnect_GetLastSentPacketID = __library.get(b"SimConnect_GetLastSentPacketID\0").map(|sym| *sym);
+        let SimConnect_Open = __library.get(b"SimConnect_Open\0").map(|sym| *sym);
+        let SimConnect_CallDispatch = __library.get(b"SimConnect_CallDispatch\0").map(|sym| *sym);
+        let SimConnect_GetNextDispatch = __library.get(b"SimConnect_GetNextDispatch\0").map(|sym| *sym);
+        let SimConnect_RequestResponseTimes = __library.get(b"SimConnect_RequestResponseTimes\0").map(|sym| *sym);
+        let SimConnect_InsertString = __library.get(b"SimConnect_InsertString\0").map(|sym| *sym);
+        let SimConnect_CameraSetRelative6DOF = __library.get(b"SimConnect_CameraSetRelative6DOF\0").map(|sym| *sym);
+        let SimConnect_MenuAddItem = __library.get(b"SimConnect_MenuAddItem\0").map(|sym| *sym);
+        let SimConnect_MenuDeleteItem = __library.get(b"SimConnect_MenuDeleteItem\0").map(|sym| *sym);
+        let SimConnect_MenuAddSubItem = __library.get(b"SimConnect_MenuAddSubItem\0").map(|sym| *sym);
+        let SimConnect_MenuDeleteSubItem = __library.get(b"SimConnect_MenuDeleteSubItem\0").map(|sym| *sym);
+        let SimConnect_RequestSystemState = __library.get(b"SimConnect_RequestSystemState\0").map(|sym| *sym);
+        let SimConnect_SetSystemState = __library.get(b"SimConnect_SetSystemState\0").map(|sym| *sym);
+        let SimConnect_MapClientDataNameToID = __library.get(b"SimConnect_MapClientDataNameToID\0").map(|sym| *sym);
+        let SimConnect_CreateClientData = __library.get(b"SimConnect_CreateClientData\0").map(|sym| *sym);
+        let SimConnect_AddToClientDataDefinition = __library.get(b"SimConnect_AddToClientDataDefinition\0").map(|sym| *sym);
+        let SimConnect_ClearClientDataDefinition = __library.get(b"SimConnect_ClearClientDataDefinition\0").map(|sym| *sym);
+        let SimConnect_RequestClientData = __library.get(b"SimConnect_RequestClientData\0").map(|sym| *sym);
+        let SimConnect_SetClientData = __library.get(b"SimConnect_SetClientData\0").map(|sym| *sym);
+        let SimConnect_FlightLoad = __library.get(b"SimConnect_FlightLoad\0").map(|sym| *sym);
+        let SimConnect_FlightSave = __library.get(b"SimConnect_FlightSave\0").map(|sym| *sym);
+        let SimConnect_FlightPlanLoad = __library.get(b"SimConnect_FlightPlanLoad\0").map(|sym| *sym);
+        let SimConnect_Text = __library.get(b"SimConnect_Text\0").map(|sym| *sym);
+        let SimConnect_SubscribeToFacilities = __library.get(b"SimConnect_SubscribeToFacilities\0").map(|sym| *sym);
+        let SimConnect_UnsubscribeToFacilities = __library.get(b"SimConnect_UnsubscribeToFacilities\0").map(|sym| *sym);
+        let SimConnect_RequestFacilitiesList = __library.get(b"SimConnect_RequestFacilitiesList\0").map(|sym| *sym);
+        let SimConnect_TransmitClientEvent_EX1 = __library.get(b"SimConnect_TransmitClientEvent_EX1\0").map(|sym| *sym);
+        let SimConnect_AddToFacilityDefinition = __library.get(b"SimConnect_AddToFacilityDefinition\0").map(|sym| *sym);
+        let SimConnect_RequestFacilityData = __library.get(b"SimConnect_RequestFacilityData\0").map(|sym| *sym);
+        let SimConnect_SubscribeToFacilities_EX1 = __library.get(b"SimConnect_SubscribeToFacilities_EX1\0").map(|sym| *sym);
+        let SimConnect_UnsubscribeToFacilities_EX1 = __library.get(b"SimConnect_UnsubscribeToFacilities_EX1\0").map(|sym| *sym);
+        let SimConnect_RequestFacilitiesList_EX1 = __library.get(b"SimConnect_RequestFacilitiesList_EX1\0").map(|sym| *sym);
+        let SimConnect_RequestFacilityData_EX1 = __library.get(b"SimConnect_RequestFacilityData_EX1\0").map(|sym| *sym);
+        let SimConnect_RequestJetwayData = __library.get(b"SimConnect_RequestJetwayData\0").map(|sym| *sym);
+        let SimConnect_EnumerateControllers = __library.get(b"SimConnect_EnumerateControllers\0").map(|sym| *sym);
+        let SimConnect_MapInputEventToClientEvent_EX1 = __library.get(b"SimConnect_MapInputEventToClientEvent_EX1\0").map(|sym| *sym);
+        let SimConnect_ExecuteAction = __library.get(b"SimConnect_ExecuteAction\0").map(|sym| *sym);
+        let SimConnect_EnumerateInputEvents = __library.get(b"SimConnect_EnumerateInputEvents\0").map(|sym| *sym);
+        let SimConnect_GetInputEvent = __library.get(b"SimConnect_GetInputEvent\0").map(|sym| *sym);
+        let SimConnect_SetInputEvent = __library.get(b"SimConnect_SetInputEvent\0").map(|sym| *sym);
+        let SimConnect_SubscribeInputEvent = __library.get(b"SimConnect_SubscribeInputEvent\0").map(|sym| *sym);
+        let SimConnect_UnsubscribeInputEvent = __library.get(b"SimConnect_UnsubscribeInputEvent\0").map(|sym| *sym);
+        let SimConnect_EnumerateInputEventParams = __library.get(b"SimConnect_EnumerateInputEventParams\0").map(|sym| *sym);
+        let SimConnect_AddFacilityDataDefinitionFilter = __library.get(b"SimConnect_AddFacilityDataDefinitionFilter\0").map(|sym| *sym);
+        let SimConnect_ClearAllFacilityDataDefinitionFilters = __library.get(b"SimConnect_ClearAllFacilityDataDefinitionFilters\0").map(|sym| *sym);
+        Ok(SimConnectLibrary {
+            __library,
+            SimConnect_MapClientEventToSimEvent,
+            SimConnect_TransmitClientEvent,
+            SimConnect_SetSystemEventState,
+            SimConnect_AddClientEventToNotificationGroup,
+            SimConnect_RemoveClientEvent,
+            SimConnect_SetNotificationGroupPriority,
+            SimConnect_ClearNotificationGroup,
+            SimConnect_RequestNotificationGroup,
+            SimConnect_AddToDataDefinition,
+            SimConnect_ClearDataDefinition,
+            SimConnect_RequestDataOnSimObject,
+            SimConnect_RequestDataOnSimObjectType,
+            SimConnect_SetDataOnSimObject,
+            SimConnect_MapInputEventToClientEvent,
+            SimConnect_SetInputGroupPriority,
+            SimConnect_RemoveInputEvent,
+            SimConnect_ClearInputGroup,
+            SimConnect_SetInputGroupState,
+            SimConnect_RequestReservedKey,
+            SimConnect_SubscribeToSystemEvent,
+            SimConnect_UnsubscribeFromSystemEvent,
+            SimConnect_WeatherRequestInterpolatedObservation,
+            SimConnect_WeatherRequestObservationAtStation,
+            SimConnect_WeatherRequestObservationAtNearestStation,
+            SimConnect_WeatherCreateStation,
+            SimConnect_WeatherRemoveStation,
+            SimConnect_WeatherSetObservation,
+            SimConnect_WeatherSetModeServer,
+            SimConnect_WeatherSetModeTheme,
+            SimConnect_WeatherSetModeGlobal,
+            SimConnect_WeatherSetModeCustom,
+            SimConnect_WeatherSetDynamicUpdateRate,
+            SimConnect_WeatherRequestCloudState,
+            SimConnect_WeatherCreateThermal,
+            SimConnect_WeatherRemoveThermal,
+            SimConnect_AICreateParkedATCAircraft,
+            SimConnect_AICreateEnrouteATCAircraft,
+            SimConnect_AICreateNonATCAircraft,
+            SimConnect_AICreateSimulatedObject,
+            SimConnect_AIReleaseControl,
+            SimConnect_AIRemoveObject,
+            SimConnect_AISetAircraftFlightPlan,
+            SimConnect_ExecuteMissionAction,
+            SimConnect_CompleteCustomMissionAction,
+            SimConnect_Close,
+            SimConnect_RetrieveString,
+            SimConnect_GetLastSentPacketID,
+            SimConnect_Open,
+            SimConnect_CallDispatch,
+            SimConnect_GetNextDispatch,
+            SimConnect_RequestResponseTimes,
+            SimConnect_InsertString,
+            SimConnect_CameraSetRelative6DOF,
+            SimConnect_MenuAddItem,
+            SimConnect_MenuDeleteItem,
+            SimConnect_MenuAddSubItem,
+            SimConnect_MenuDeleteSubItem,
+            SimConnect_RequestSystemState,
+            SimConnect_SetSystemState,
+            SimConnect_MapClientDataNameToID,
+            SimConnect_CreateClientData,
+            SimConnect_AddToClientDataDefinition,
+            SimConnect_ClearClientDataDefinition,
+            SimConnect_RequestClientData,
+            SimConnect_SetClientData,
+            SimConnect_FlightLoad,
+            SimConnect_FlightSave,
+            SimConnect_FlightPlanLoad,
+            SimConnect_Text,
+            SimConnect_SubscribeToFacilities,
+            SimConnect_UnsubscribeToFacilities,
+            SimConnect_RequestFacilitiesList,
+            SimConnect_TransmitClientEvent_EX1,
+            SimConnect_AddToFacilityDefinition,
+            SimConnect_RequestFacilityData,
+            SimConnect_SubscribeToFacilities_EX1,
+            SimConnect_UnsubscribeToFacilities_EX1,
+            SimConnect_RequestFacilitiesList_EX1,
+            SimConnect_RequestFacilityData_EX1,
+            SimConnect_RequestJetwayData,
+            SimConnect_EnumerateControllers,
+            SimConnect_MapInputEventToClientEvent_EX1,
+            SimConnect_ExecuteAction,
+            SimConnect_EnumerateInputEvents,
+            SimConnect_GetInputEvent,
+            SimConnect_SetInputEvent,
+            SimConnect_SubscribeInputEvent,
+            SimConnect_UnsubscribeInputEvent,
+            SimConnect_EnumerateInputEventParams,
+            SimConnect_AddFacilityDataDefinitionFilter,
+            SimConnect_ClearAllFacilityDataDefinitionFilters,
+        })
+    }
+    pub unsafe fn SimConnect_MapClientEventToSimEvent(
+        &self,
+        hSimConnect: HANDLE,
+        EventID: SIMCONNECT_CLIENT_EVENT_ID,
+        EventName: *const crate::ctypes::c_char,
+    ) -> HRESULT {
+        (self
+            .SimConnect_MapClientEventToSimEvent
+            .as_ref()
+            .expect("Expected function, got error."))(hSimConnect, EventID, EventName)
+    }
+    pub unsafe fn SimConnect_TransmitClientEvent(
+        &self,
+        hSimConnect: HANDLE,
+        ObjectID: SIMCONNECT_OBJECT_ID,
+        EventID: SIMCONNECT_CLIENT_EVENT_ID,
+        dwData: DWORD,
+        GroupID: SIMCONNECT_NOTIFICATION_GROUP_ID,
+        Flags: SIMCONNECT_EVENT_FLAG,
+    ) -> HRESULT {
+        (self
+            .SimConnect_TransmitClientEvent
+            .as_ref()
+            .expect("Expected function, got error."))(hSimConnect, ObjectID, EventID, dwData, GroupID, Flags)
+    }
+    pub unsafe fn SimConnect_SetSystemEventState(
+        &self,
+        hSimConnect: HANDLE,
+        EventID: SIMCONNECT_CLIENT_EVENT_ID,
+        dwState: SIMCONNECT_STATE,
+    ) -> HRESULT {
+        (self
+            .SimConnect_SetSystemEventState
+            .as_ref()
+            .expect("Expected function, got error."))(hSimConnect, EventID, dwState)
+    }
+    pub unsafe fn SimConnect_AddClientEventToNotificationGroup(
+        &self,
+        hSimConnect: HANDLE,
+        GroupID: SIMCONNECT_NOTIFICATION_GROUP_ID,
+        EventID: SIMCONNECT_CLIENT_EVENT_ID,
+        bMaskable: BOOL,
+    ) -> HRESULT {
+        (self
+            .SimConnect_AddClientEventToNotificationGroup
+            .as_ref()
+            .expect("Expected function, got error."))(hSimConnect, GroupID, EventID, bMaskable)
+    }
+    pub unsafe fn SimConnect_RemoveClientEvent(
+        &self,
+        hSimConnect: HANDLE,
+        GroupID: SIMCONNECT_NOTIFICATION_GROUP_ID,
+        EventID: SIMCONNECT_CLIENT_EVENT_ID,
+    ) -> HRESULT {
+        (self
+            .SimConnect_RemoveClientEvent
+            .as_ref()
+            .expect("Expected function, got error."))(hSimConnect, GroupID, EventID)
+    }
+    pub unsafe fn SimConnect_SetNotificationGroupPriority(
+        &self,
+        hSimConnect: HANDLE,
+        GroupID: SIMCONNECT_NOTIFICATION_GROUP_ID,
+        uPriority: DWORD,
+    ) -> HRESULT {
+        (self
+            .SimConnect_SetNotificationGroupPriority
+            .as_ref()
+            .expect("Expected function, got error."))(hSimConnect, GroupID, uPriority)
+    }
+    pub unsafe fn SimConnect_ClearNotificationGroup(
+        &self,
+        hSimConnect: HANDLE,
+        GroupID: SIMCONNECT_NOTIFICATION_GROUP_ID,
+    ) -> HRESULT {
+        (self
+            .SimConnect_ClearNotificationGroup
+            .as_ref()
+            .expect("Expected function, got error."))(hSimConnect, GroupID)
+    }
+    pub unsafe fn SimConnect_RequestNotificationGroup(
+        &self,
+        hSimConnect: HANDLE,
+        GroupID: SIMCONNECT_NOTIFICATION_GROUP_ID,
+        dwReserved: DWORD,
+        Flags: DWORD,
+    ) -> HRESULT {
+        (self
+            .SimConnect_RequestNotificationGroup
+            .as_ref()
+            .expect("Expected function, got error."))(hSimConnect, GroupID, dwReserved, Flags)
+    }
+    pub unsafe fn SimConnect_AddToDataDefinition(
+        &self,
+        hSimConnect: HANDLE,
+        DefineID: SIMCONNECT_DATA_DEFINITION_ID,
+        DatumName: *const crate::ctypes::c_char,
+        UnitsName: *const crate::ctypes::c_char,
+        DatumType: SIMCONNECT_DATATYPE,
+        fEpsilon: f32,
+        DatumID: DWORD,
+    ) -> HRESULT {
+        (self
+            .SimConnect_AddToDataDefinition
+            .as_ref()
+            .expect("Expected function, got error."))(hSimConnect, DefineID, DatumName, UnitsName, DatumType, fEpsilon, DatumID)
+    }
+    pub unsafe fn SimConnect_ClearDataDefinition(
+        &self,
+        hSimConnect: HANDLE,
+        DefineID: SIMCONNECT_DATA_DEFINITION_ID,
+    ) -> HRESULT {
+        (self
+            .SimConnect_ClearDataDefinition
+            .as_ref()
+            .expect("Expected function, got error."))(hSimConnect, DefineID)
+    }
+    pub unsafe fn SimConnect_RequestDataOnSimObject(
+        &self,
+        hSimConnect: HANDLE,
+        RequestID: SIMCONNECT_DATA_REQUEST_ID,
+        DefineID: SIMCONNECT_DATA_DEFINITION_ID,
+        ObjectID: SIMCONNECT_OBJECT_ID,
+        Period: SIMCONNECT_PERIOD,
+        Flags: SIMCONNECT_DATA_REQUEST_FLAG,
+        origin: DWORD,
+        interval: DWORD,
+        limit: DWORD,
+    ) -> HRESULT {
+        (self
+            .SimConnect_RequestDataOnSimObject
+            .as_ref()
+            .expect("Expected function, got error."))(hSimConnect, RequestID, DefineID, ObjectID, Period, Flags, origin, interval, limit)
+    }
+    pub unsafe fn SimConnect_RequestDataOnSimObjectType(
+        &self,
+        hSimConnect: HANDLE,
+        RequestID: SIMCONNECT_DATA_REQUEST_ID,
+        DefineID: SIMCONNECT_DATA_DEFINITION_ID,
+        dwRadiusMeters: DWORD,
+        type_: SIMCONNECT_SIMOBJECT_TYPE,
+    ) -> HRESULT {
+        (self
+            .SimConnect_RequestDataOnSimObjectType
+            .as_ref()
+            .expect("Expected function, got error."))(hSimConnect, RequestID, DefineID, dwRadiusMeters, type_)
+    }
+    pub unsafe fn SimConnect_SetDataOnSimObject(
+        &self,
+        hSimConnect: HANDLE,
+        DefineID: SIMCONNECT_DATA_DEFINITION_ID,
+        ObjectID: SIMCONNECT_OBJECT_ID,
+        Flags: SIMCONNECT_DATA_SET_FLAG,
+        ArrayCount: DWORD,
+        cbUnitSize: DWORD,
+        pDataSet: *mut crate::ctypes::c_void,
+    ) -> HRESULT {
+        (self
+            .SimConnect_SetDataOnSimObject
+            .as_ref()
+            .expect("Expected function, got error."))(hSimConnect, DefineID, ObjectID, Flags, ArrayCount, cbUnitSize, pDataSet)
+    }
+    pub unsafe fn SimConnect_MapInputEventToClientEvent(
+        &self,
+        hSimConnect: HANDLE,
+        GroupID: SIMCONNECT_INPUT_GROUP_ID,
+        szInputDefinition: *const crate::ctypes::c_char,
+        DownEventID: SIMCONNECT_CLIENT_EVENT_ID,
+        DownValue: DWORD,
+        UpEventID: SIMCONNECT_CLIENT_EVENT_ID,
+        UpValue: DWORD,
+        bMaskable: BOOL,
+    ) -> HRESULT {
+        (self
+            .SimConnect_MapInputEventToClientEvent
+            .as_ref()
+            .expect("Expected function, got error."))(hSimConnect, GroupID, szInputDefinition, DownEventID, DownValue, UpEventID, UpValue, bMaskable)
+    }
+    pub unsafe fn SimConnect_SetInputGroupPriority(
+        &self,
+        hSimConnect: HANDLE,
+        GroupID: SIMCONNECT_INPUT_GROUP_ID,
+        uPriority: DWORD,
+    ) -> HRESULT {
+        (self
+            .SimConnect_SetInputGroupPriority
+            .as_ref()
+            .expect("Expected function, got error."))(hSimConnect, GroupID, uPriority)
+    }
+    pub unsafe fn SimConnect_RemoveInputEvent(
+        &self,
+        hSimConnect: HANDLE,
+        GroupID: SIMCONNECT_INPUT_GROUP_ID,
+        szInputDefinition: *const crate::ctypes::c_char,
+    ) -> HRESULT {
+        (self
+            .SimConnect_RemoveInputEvent
+            .as_ref()
+            .expect("Expected function, got error."))(hSimConnect, GroupID, szInputDefinition)
+    }
+    pub unsafe fn SimConnect_ClearInputGroup(
+        &self,
+        hSimConnect: HANDLE,
+        GroupID: SIMCONNECT_INPUT_GROUP_ID,
+    ) -> HRESULT {
+        (self
+            .SimConnect_ClearInputGroup
+            .as_ref()
+            .expect("Expected function, got error."))(hSimConnect, GroupID)
+    }
+    pub unsafe fn SimConnect_SetInputGroupState(
+        &self,
+        hSimConnect: HANDLE,
+        GroupID: SIMCONNECT_INPUT_GROUP_ID,
+        dwState: DWORD,
+    ) -> HRESULT {
+        (self
+            .SimConnect_SetInputGroupState
+            .as_ref()
+            .expect("Expected function, got error."))(hSimConnect, GroupID, dwState)
+    }
+    pub unsafe fn SimConnect_RequestReservedKey(
+        &self,
+        hSimConnect: HANDLE,
+        EventID: SIMCONNECT_CLIENT_EVENT_ID,
+        szKeyChoice1: *const crate::ctypes::c_char,
+        szKeyChoice2: *const crate::ctypes::c_char,
+        szKeyChoice3: *const crate::ctypes::c_char,
+    ) -> HRESULT {
+        (self
+            .SimConnect_RequestReservedKey
+            .as_ref()
+            .expect("Expected function, got error."))(hSimConnect, EventID, szKeyChoice1, szKeyChoice2, szKeyChoice3)
+    }
+    pub unsafe fn SimConnect_SubscribeToSystemEvent(
+        &self,
+        hSimConnect: HANDLE,
+        EventID: SIMCONNECT_CLIENT_EVENT_ID,
+        SystemEventName: *const crate::ctypes::c_char,
+    ) -> HRESULT {
+        (self
+            .SimConnect_SubscribeToSystemEvent
+            .as_ref()
+            .expect("Expected function, got error."))(hSimConnect, EventID, SystemEventName)
+    }
+    pub unsafe fn SimConnect_UnsubscribeFromSystemEvent(
+        &self,
+        hSimConnect: HANDLE,
+        EventID: SIMCONNECT_CLIENT_EVENT_ID,
+    ) -> HRESULT {
+        (self
+            .SimConnect_UnsubscribeFromSystemEvent
+            .as_ref()
+            .expect("Expected function, got error."))(hSimConnect, EventID)
+    }
+    pub unsafe fn SimConnect_WeatherRequestInterpolatedObservation(
+        &self,
+        hSimConnect: HANDLE,
+        RequestID: SIMCONNECT_DATA_REQUEST_ID,
+        lat: f32,
+        lon: f32,
+        alt: f32,
+    ) -> HRESULT {
+        (self
+            .SimConnect_WeatherRequestInterpolatedObservation
+            .as_ref()
+            .expect("Expected function, got error."))(hSimConnect, RequestID, lat, lon, alt)
+    }
+    pub unsafe fn SimConnect_WeatherRequestObservationAtStation(
+        &self,
+        hSimConnect: HANDLE,
+        RequestID: SIMCONNECT_DATA_REQUEST_ID,
+        szICAO: *const crate::ctypes::c_char,
+    ) -> HRESULT {
+        (self
+            .SimConnect_WeatherRequestObservationAtStation
+            .as_ref()
+            .expect("Expected function, got error."))(hSimConnect, RequestID, szICAO)
+    }
+    pub unsafe fn SimConnect_WeatherRequestObservationAtNearestStation(
+        &self,
+        hSimConnect: HANDLE,
+        RequestID: SIMCONNECT_DATA_REQUEST_ID,
+        lat: f32,
+        lon: f32,
+    ) -> HRESULT {
+        (self
+            .SimConnect_WeatherRequestObservationAtNearestStation
+            .as_ref()
+            .expect("Expected function, got error."))(hSimConnect, RequestID, lat, lon)
+    }
+    pub unsafe fn SimConnect_WeatherCreateStation(
+        &self,
+        hSimConnect: HANDLE,
+        RequestID: SIMCONNECT_DATA_REQUEST_ID,
+        szICAO: *const crate::ctypes::c_char,
+        szName: *const crate::ctypes::c_char,
+        lat: f32,
+        lon: f32,
+        alt: f32,
+    ) -> HRESULT {
+        (self
+            .SimConnect_WeatherCreateStation
+            .as_ref()
+            .expect("Expected function, got error."))(hSimConnect, RequestID, szICAO, szName, lat, lon, alt)
+    }
+    pub unsafe fn SimConnect_WeatherRemoveStation(
+        &self,
+        hSimConnect: HANDLE,
+        RequestID: SIMCONNECT_DATA_REQUEST_ID,
+        szICAO: *const crate::ctypes::c_char,
+    ) -> HRESULT {
+        (self
+            .SimConnect_WeatherRemoveStation
+            .as_ref()
+            .expect("Expected function, got error."))(hSimConnect, RequestID, szICAO)
+    }
+    pub unsafe fn SimConnect_WeatherSetObservation(
+        &self,
+        hSimConnect: HANDLE,
+        Seconds: DWORD,
+        szMETAR: *const crate::ctypes::c_char,
+    ) -> HRESULT {
+        (self
+            .SimConnect_WeatherSetObservation
+            .as_ref()
+            .expect("Expected function, got error."))(hSimConnect, Seconds, szMETAR)
+    }
+    pub unsafe fn SimConnect_WeatherSetModeServer(
+        &self,
+        hSimConnect: HANDLE,
+        dwPort: DWORD,
+        dwSeconds: DWORD,
+    ) -> HRESULT {
+        (self
+            .SimConnect_WeatherSetModeServer
+            .as_ref()
+            .expect("Expected function, got error."))(hSimConnect, dwPort, dwSeconds)
+    }
+    pub unsafe fn SimConnect_WeatherSetModeTheme(
+        &self,
+        hSimConnect: HANDLE,
+        szThemeName: *const crate::ctypes::c_char,
+    ) -> HRESULT {
+        (self
+            .SimConnect_WeatherSetModeTheme
+            .as_ref()
+            .expect("Expected function, got error."))(hSimConnect, szThemeName)
+    }
+    pub unsafe fn SimConnect_WeatherSetModeGlobal(
+        &self,
+        hSimConnect: HANDLE,
+    ) -> HRESULT {
+        (self
+            .SimConnect_WeatherSetModeGlobal
+            .as_ref()
+            .expect("Expected function, got error."))(hSimConnect)
+    }
+    pub unsafe fn SimConnect_WeatherSetModeCustom(
+        &self,
+        hSimConnect: HANDLE,
+    ) -> HRESULT {
+        (self
+            .SimConnect_WeatherSetModeCustom
+            .as_ref()
+            .expect("Expected function, got error."))(hSimConnect)
+    }
+    pub unsafe fn SimConnect_WeatherSetDynamicUpdateRate(
+        &self,
+        hSimConnect: HANDLE,
+        dwRate: DWORD,
+    ) -> HRESULT {
+        (self
+            .SimConnect_WeatherSetDynamicUpdateRate
+            .as_ref()
+            .expect("Expected function, got error."))(hSimConnect, dwRate)
+    }
+    pub unsafe fn SimConnect_WeatherRequestCloudState(
+        &self,
+        hSimConnect: HANDLE,
+        RequestID: SIMCONNECT_DATA_REQUEST_ID,
+        minLat: f32,
+        minLon: f32,
+        minAlt: f32,
+        maxLat: f32,
+        maxLon: f32,
+        maxAlt: f32,
+        dwFlags: DWORD,
+    ) -> HRESULT {
+        (self
+            .SimConnect_WeatherRequestCloudState
+            .as_ref()
+            .expect("Expected function, got error."))(hSimConnect, RequestID, minLat, minLon, minAlt, maxLat, maxLon, maxAlt, dwFlags)
+    }
+    pub unsafe fn SimConnect_WeatherCreateThermal(
+        &self,
+        hSimConnect: HANDLE,
+        RequestID: SIMCONNECT_DATA_REQUEST_ID,
+        lat: f32,
+        lon: f32,
+        alt: f32,
+        radius: f32,
+        height: f32,
+        coreRate: f32,
+        coreTurbulence: f32,
+        sinkRate: f32,
+        sinkTurbulence: f32,
+        coreSize: f32,
+        coreTransitionSize: f32,
+        sinkLayerSize: f32,
+        sinkTransitionSize: f32,
+    ) -> HRESULT {
+        (self
+            .SimConnect_WeatherCreateThermal
+            .as_ref()
+            .expect("Expected function, got error."))(hSimConnect, RequestID, lat, lon, alt, radius, height, coreRate, coreTurbulence, sinkRate, sinkTurbulence, coreSize, coreTransitionSize, sinkLayerSize, sinkTransitionSize)
+    }
+    pub unsafe fn SimConnect_WeatherRemoveThermal(
+        &self,
+        hSimConnect: HANDLE,
+        ObjectID: SIMCONNECT_OBJECT_ID,
+    ) -> HRESULT {
+        (self
+            .SimConnect_WeatherRemoveThermal
+            .as_ref()
+            .expect("Expected function, got error."))(hSimConnect, ObjectID)
+    }
+    pub unsafe fn SimConnect_AICreateParkedATCAircraft(
+        &self,
+        hSimConnect: HANDLE,
+        szContainerTitle: *const crate::ctypes::c_char,
+        szTailNumber: *const crate::ctypes::c_char,
+        szAirportID: *const crate::ctypes::c_char,
+        RequestID: SIMCONNECT_DATA_REQUEST_ID,
+    ) -> HRESULT {
+        (self
+            .SimConnect_AICreateParkedATCAircraft
+            .as_ref()
+            .expect("Expected function, got error."))(hSimConnect, szContainerTitle, szTailNumber, szAirportID, RequestID)
+    }
+    pub unsafe fn SimConnect_AICreateEnrouteATCAircraft(
+        &self,
+        hSimConnect: HANDLE,
+        szContainerTitle: *const crate::ctypes::c_char,
+        szTailNumber: *const crate::ctypes::c_char,
+        iFlightNumber: crate::ctypes::c_int,
+        szFlightPlanPath: *const crate::ctypes::c_char,
+        dFlightPlanPosition: f64,
+        bTouchAndGo: BOOL,
+        RequestID: SIMCONNECT_DATA_REQUEST_ID,
+    ) -> HRESULT {
+        (self
+            .SimConnect_AICreateEnrouteATCAircraft
+            .as_ref()
+            .expect("Expected function, got error."))(hSimConnect, szContainerTitle, szTailNumber, iFlightNumber, szFlightPlanPath, dFlightPlanPosition, bTouchAndGo, RequestID)
+    }
+    pub unsafe fn SimConnect_AICreateNonATCAircraft(
+        &self,
+        hSimConnect: HANDLE,
+        szContainerTitle: *const crate::ctypes::c_char,
+        szTailNumber: *const crate::ctypes::c_char,
+        InitPos: SIMCONNECT_DATA_INITPOSITION,
+        RequestID: SIMCONNECT_DATA_REQUEST_ID,
+    ) -> HRESULT {
+        (self
+            .SimConnect_AICreateNonATCAircraft
+            .as_ref()
+            .expect("Expected function, got error."))(hSimConnect, szContainerTitle, szTailNumber, InitPos, RequestID)
+    }
+    pub unsafe fn SimConnect_AICreateSimulatedObject(
+        &self,
+        hSimConnect: HANDLE,
+        szContainerTitle: *const crate::ctypes::c_char,
+        InitPos: SIMCONNECT_DATA_INITPOSITION,
+        RequestID: SIMCONNECT_DATA_REQUEST_ID,
+    ) -> HRESULT {
+        (self
+            .SimConnect_AICreateSimulatedObject
+            .as_ref()
+            .expect("Expected function, got error."))(hSimConnect, szContainerTitle, InitPos, RequestID)
+    }
+    pub unsafe fn SimConnect_AIReleaseControl(
+        &self,
+        hSimConnect: HANDLE,
+        ObjectID: SIMCONNECT_OBJECT_ID,
+        RequestID: SIMCONNECT_DATA_REQUEST_ID,
+    ) -> HRESULT {
+        (self
+            .SimConnect_AIReleaseControl
+            .as_ref()
+            .expect("Expected function, got error."))(hSimConnect, ObjectID, RequestID)
+    }
+    pub unsafe fn SimConnect_AIRemoveObject(
+        &self,
+        hSimConnect: HANDLE,
+        ObjectID: SIMCONNECT_OBJECT_ID,
+        RequestID: SIMCONNECT_DATA_REQUEST_ID,
+    ) -> HRESULT {
+        (self
+            .SimConnect_AIRemoveObject
+            .as_ref()
+            .expect("Expected function, got error."))(hSimConnect, ObjectID, RequestID)
+    }
+    pub unsafe fn SimConnect_AISetAircraftFlightPlan(
+        &self,
+        hSimConnect: HANDLE,
+        ObjectID: SIMCONNECT_OBJECT_ID,
+        szFlightPlanPath: *const crate::ctypes::c_char,
+        RequestID: SIMCONNECT_DATA_REQUEST_ID,
+    ) -> HRESULT {
+        (self
+            .SimConnect_AISetAircraftFlightPlan
+            .as_ref()
+            .expect("Expected function, got error."))(hSimConnect, ObjectID, szFlightPlanPath, RequestID)
+    }
+    pub unsafe fn SimConnect_ExecuteMissionAction(
+        &self,
+        hSimConnect: HANDLE,
+        guidInstanceId: GUID,
+    ) -> HRESULT {
+        (self
+            .SimConnect_ExecuteMissionAction
+            .as_ref()
+            .expect("Expected function, got error."))(hSimConnect, guidInstanceId)
+    }
+    pub unsafe fn SimConnect_CompleteCustomMissionAction(
+        &self,
+        hSimConnect: HANDLE,
+        guidInstanceId: GUID,
+    ) -> HRESULT {
+        (self
+            .SimConnect_CompleteCustomMissionAction
+            .as_ref()
+            .expect("Expected function, got error."))(hSimConnect, guidInstanceId)
+    }
+    pub unsafe fn SimConnect_Close(
+        &self,
+        hSimConnect: HANDLE,
+    ) -> HRESULT {
+        (self
+            .SimConnect_Close
+            .as_ref()
+            .expect("Expected function, got error."))(hSimConnect)
+    }
+    pub unsafe fn SimConnect_RetrieveString(
+        &self,
+        pData: *mut SIMCONNECT_RECV,
+        cbData: DWORD,
+        pStringV: *mut crate::ctypes::c_void,
+        pszString: *mut *mut crate::ctypes::c_char,
+        pcbString: *mut DWORD,
+    ) -> HRESULT {
+        (self
+            .SimConnect_RetrieveString
+            .as_ref()
+            .expect("Expected function, got error."))(pData, cbData, pStringV, pszString, pcbString)
+    }
+    pub unsafe fn SimConnect_GetLastSentPacketID(
+        &self,
+        hSimConnect: HANDLE,
+        pdwError: *mut DWORD,
+    ) -> HRESULT {
+        (self
+            .SimConnect_GetLastSentPacketID
+            .as_ref()
+            .expect("Expected function, got error."))(hSimConnect, pdwError)
+    }
+    pub unsafe fn SimConnect_Open(
+        &self,
+        phSimConnect: *mut HANDLE,
+        szName: LPCSTR,
+        hWnd: HWND,
+        UserEventWin32: DWORD,
+        hEventHandle: HANDLE,
+        ConfigIndex: DWORD,
+    ) -> HRESULT {
+        (self
+            .SimConnect_Open
+            .as_ref()
+            .expect("Expected function, got error."))(phSimConnect, szName, hWnd, UserEventWin32, hEventHandle, ConfigIndex)
+    }
+    pub unsafe fn SimConnect_CallDispatch(
+        &self,
+        hSimConnect: HANDLE,
+        pfcnDispatch: DispatchProc,
+        pContext: *mut crate::ctypes::c_void,
+    ) -> HRESULT {
+        (self
+            .SimConnect_CallDispatch
+            .as_ref()
+            .expect("Expected function, got error."))(hSimConnect, pfcnDispatch, pContext)
+    }
+    pub unsafe fn SimConnect_GetNextDispatch(
+        &self,
+        hSimConnect: HANDLE,
+        ppData: *mut *mut SIMCONNECT_RECV,
+        pcbData: *mut DWORD,
+    ) -> HRESULT {
+        (self
+            .SimConnect_GetNextDispatch
+            .as_ref()
+            .expect("Expected function, got error."))(hSimConnect, ppData, pcbData)
+    }
+    pub unsafe fn SimConnect_RequestResponseTimes(
+        &self,
+        hSimConnect: HANDLE,
+        nCount: DWORD,
+        fElapsedSeconds: *mut f32,
+    ) -> HRESULT {
+        (self
+            .SimConnect_RequestResponseTimes
+            .as_ref()
+            .expect("Expected function, got error."))(hSimConnect, nCount, fElapsedSeconds)
+    }
+    pub unsafe fn SimConnect_InsertString(
+        &self,
+        pDest: *mut crate::ctypes::c_char,
+        cbDest: DWORD,
+        ppEnd: *mut *mut crate::ctypes::c_void,
+        pcbStringV: *mut DWORD,
+        pSource: *const crate::ctypes::c_char,
+    ) -> HRESULT {
+        (self
+            .SimConnect_InsertString
+            .as_ref()
+            .expect("Expected function, got error."))(pDest, cbDest, ppEnd, pcbStringV, pSource)
+    }
+    pub unsafe fn SimConnect_CameraSetRelative6DOF(
+        &self,
+        hSimConnect: HANDLE,
+        fDeltaX: f32,
+        fDeltaY: f32,
+        fDeltaZ: f32,
+        fPitchDeg: f32,
+        fBankDeg: f32,
+        fHeadingDeg: f32,
+    ) -> HRESULT {
+        (self
+            .SimConnect_CameraSetRelative6DOF
+            .as_ref()
+            .expect("Expected function, got error."))(hSimConnect, fDeltaX, fDeltaY, fDeltaZ, fPitchDeg, fBankDeg, fHeadingDeg)
+    }
+    pub unsafe fn SimConnect_MenuAddItem(
+        &self,
+        hSimConnect: HANDLE,
+        szMenuItem: *const crate::ctypes::c_char,
+        MenuEventID: SIMCONNECT_CLIENT_EVENT_ID,
+        dwData: DWORD,
+    ) -> HRESULT {
+        (self
+            .SimConnect_MenuAddItem
+            .as_ref()
+            .expect("Expected function, got error."))(hSimConnect, szMenuItem, MenuEventID, dwData)
+    }
+    pub unsafe fn SimConnect_MenuDeleteItem(
+        &self,
+        hSimConnect: HANDLE,
+        MenuEventID: SIMCONNECT_CLIENT_EVENT_ID,
+    ) -> HRESULT {
+        (self
+            .SimConnect_MenuDeleteItem
+            .as_ref()
+            .expect("Expected function, got error."))(hSimConnect, MenuEventID)
+    }
+    pub unsafe fn SimConnect_MenuAddSubItem(
+        &self,
+        hSimConnect: HANDLE,
+        MenuEventID: SIMCONNECT_CLIENT_EVENT_ID,
+        szMenuItem: *const crate::ctypes::c_char,
+        SubMenuEventID: SIMCONNECT_CLIENT_EVENT_ID,
+        dwData: DWORD,
+    ) -> HRESULT {
+        (self
+            .SimConnect_MenuAddSubItem
+            .as_ref()
+            .expect("Expected function, got error."))(hSimConnect, MenuEventID, szMenuItem, SubMenuEventID, dwData)
+    }
+    pub unsafe fn SimConnect_MenuDeleteSubItem(
+        &self,
+        hSimConnect: HANDLE,
+        MenuEventID: SIMCONNECT_CLIENT_EVENT_ID,
+        SubMenuEventID: SIMCONNECT_CLIENT_EVENT_ID,
+    ) -> HRESULT {
+        (self
+            .SimConnect_MenuDeleteSubItem
+            .as_ref()
+            .expect("Expected function, got error."))(hSimConnect, MenuEventID, SubMenuEventID)
+    }
+    pub unsafe fn SimConnect_RequestSystemState(
+        &self,
+        hSimConnect: HANDLE,
+        RequestID: SIMCONNECT_DATA_REQUEST_ID,
+        szState: *const crate::ctypes::c_char,
+    ) -> HRESULT {
+        (self
+            .SimConnect_RequestSystemState
+            .as_ref()
+            .expect("Expected function, got error."))(hSimConnect, RequestID, szState)
+    }
+    pub unsafe fn SimConnect_SetSystemState(
+        &self,
+        hSimConnect: HANDLE,
+        szState: *const crate::ctypes::c_char,
+        dwInteger: DWORD,
+        fFloat: f32,
+        szString: *const crate::ctypes::c_char,
+    ) -> HRESULT {
+        (self
+            .SimConnect_SetSystemState
+            .as_ref()
+            .expect("Expected function, got error."))(hSimConnect, szState, dwInteger, fFloat, szString)
+    }
+    pub unsafe fn SimConnect_MapClientDataNameToID(
+        &self,
+        hSimConnect: HANDLE,
+        szClientDataName: *const crate::ctypes::c_char,
+        ClientDataID: SIMCONNECT_CLIENT_DATA_ID,
+    ) -> HRESULT {
+        (self
+            .SimConnect_MapClientDataNameToID
+            .as_ref()
+            .expect("Expected function, got error."))(hSimConnect, szClientDataName, ClientDataID)
+    }
+    pub unsafe fn SimConnect_CreateClientData(
+        &self,
+        hSimConnect: HANDLE,
+        ClientDataID: SIMCONNECT_CLIENT_DATA_ID,
+        dwSize: DWORD,
+        Flags: SIMCONNECT_CREATE_CLIENT_DATA_FLAG,
+    ) -> HRESULT {
+        (self
+            .SimConnect_CreateClientData
+            .as_ref()
+            .expect("Expected function, got error."))(hSimConnect, ClientDataID, dwSize, Flags)
+    }
+    pub unsafe fn SimConnect_AddToClientDataDefinition(
+        &self,
+        hSimConnect: HANDLE,
+        DefineID: SIMCONNECT_CLIENT_DATA_DEFINITION_ID,
+        dwOffset: DWORD,
+        dwSizeOrType: DWORD,
+        fEpsilon: f32,
+        DatumID: DWORD,
+    ) -> HRESULT {
+        (self
+            .SimConnect_AddToClientDataDefinition
+            .as_ref()
+            .expect("Expected function, got error."))(hSimConnect, DefineID, dwOffset, dwSizeOrType, fEpsilon, DatumID)
+    }
+    pub unsafe fn SimConnect_ClearClientDataDefinition(
+        &self,
+        hSimConnect: HANDLE,
+        DefineID: SIMCONNECT_CLIENT_DATA_DEFINITION_ID,
+    ) -> HRESULT {
+        (self
+            .SimConnect_ClearClientDataDefinition
+            .as_ref()
+            .expect("Expected function, got error."))(hSimConnect, DefineID)
+    }
+    pub unsafe fn SimConnect_RequestClientData(
+        &self,
+        hSimConnect: HANDLE,
+        ClientDataID: SIMCONNECT_CLIENT_DATA_ID,
+        RequestID: SIMCONNECT_DATA_REQUEST_ID,
+        DefineID: SIMCONNECT_CLIENT_DATA_DEFINITION_ID,
+        Period: SIMCONNECT_CLIENT_DATA_PERIOD,
+        Flags: SIMCONNECT_CLIENT_DATA_REQUEST_FLAG,
+        origin: DWORD,
+        interval: DWORD,
+        limit: DWORD,
+    ) -> HRESULT {
+        (self
+            .SimConnect_RequestClientData
+            .as_ref()
+            .expect("Expected function, got error."))(hSimConnect, ClientDataID, RequestID, DefineID, Period, Flags, origin, interval, limit)
+    }
+    pub unsafe fn SimConnect_SetClientData(
+        &self,
+        hSimConnect: HANDLE,
+        ClientDataID: SIMCONNECT_CLIENT_DATA_ID,
+        DefineID: SIMCONNECT_CLIENT_DATA_DEFINITION_ID,
+        Flags: SIMCONNECT_CLIENT_DATA_SET_FLAG,
+        dwReserved: DWORD,
+        cbUnitSize: DWORD,
+        pDataSet: *mut crate::ctypes::c_void,
+    ) -> HRESULT {
+        (self
+            .SimConnect_SetClientData
+            .as_ref()
+            .expect("Expected function, got error."))(hSimConnect, ClientDataID, DefineID, Flags, dwReserved, cbUnitSize, pDataSet)
+    }
+    pub unsafe fn SimConnect_FlightLoad(
+        &self,
+        hSimConnect: HANDLE,
+        szFileName: *const crate::ctypes::c_char,
+    ) -> HRESULT {
+        (self
+            .SimConnect_FlightLoad
+            .as_ref()
+            .expect("Expected function, got error."))(hSimConnect, szFileName)
+    }
+    pub unsafe fn SimConnect_FlightSave(
+        &self,
+        hSimConnect: HANDLE,
+        szFileName: *const crate::ctypes::c_char,
+        szTitle: *const crate::ctypes::c_char,
+        szDescription: *const crate::ctypes::c_char,
+        Flags: DWORD,
+    ) -> HRESULT {
+        (self
+            .SimConnect_FlightSave
+            .as_ref()
+            .expect("Expected function, got error."))(hSimConnect, szFileName, szTitle, szDescription, Flags)
+    }
+    pub unsafe fn SimConnect_FlightPlanLoad(
+        &self,
+        hSimConnect: HANDLE,
+        szFileName: *const crate::ctypes::c_char,
+    ) -> HRESULT {
+        (self
+            .SimConnect_FlightPlanLoad
+            .as_ref()
+            .expect("Expected function, got error."))(hSimConnect, szFileName)
+    }
+    pub unsafe fn SimConnect_Text(
+        &self,
+        hSimConnect: HANDLE,
+        type_: SIMCONNECT_TEXT_TYPE,
+        fTimeSeconds: f32,
+        EventID: SIMCONNECT_CLIENT_EVENT_ID,
+        cbUnitSize: DWORD,
+        pDataSet: *mut crate::ctypes::c_void,
+    ) -> HRESULT {
+        (self
+            .SimConnect_Text
+            .as_ref()
+            .expect("Expected function, got error."))(hSimConnect, type_, fTimeSeconds, EventID, cbUnitSize, pDataSet)
+    }
+    pub unsafe fn SimConnect_SubscribeToFacilities(
+        &self,
+        hSimConnect: HANDLE,
+        type_: SIMCONNECT_FACILITY_LIST_TYPE,
+        RequestID: SIMCONNECT_DATA_REQUEST_ID,
+    ) -> HRESULT {
+        (self
+            .SimConnect_SubscribeToFacilities
+            .as_ref()
+            .expect("Expected function, got error."))(hSimConnect, type_, RequestID)
+    }
+    pub unsafe fn SimConnect_UnsubscribeToFacilities(
+        &self,
+        hSimConnect: HANDLE,
+        type_: SIMCONNECT_FACILITY_LIST_TYPE,
+    ) -> HRESULT {
+        (self
+            .SimConnect_UnsubscribeToFacilities
+            .as_ref()
+            .expect("Expected function, got error."))(hSimConnect, type_)
+    }
+    pub unsafe fn SimConnect_RequestFacilitiesList(
+        &self,
+        hSimConnect: HANDLE,
+        type_: SIMCONNECT_FACILITY_LIST_TYPE,
+        RequestID: SIMCONNECT_DATA_REQUEST_ID,
+    ) -> HRESULT {
+        (self
+            .SimConnect_RequestFacilitiesList
+            .as_ref()
+            .expect("Expected function, got error."))(hSimConnect, type_, RequestID)
+    }
+    pub unsafe fn SimConnect_TransmitClientEvent_EX1(
+        &self,
+        hSimConnect: HANDLE,
+        ObjectID: SIMCONNECT_OBJECT_ID,
+        EventID: SIMCONNECT_CLIENT_EVENT_ID,
+        GroupID: SIMCONNECT_NOTIFICATION_GROUP_ID,
+        Flags: SIMCONNECT_EVENT_FLAG,
+        dwData0: DWORD,
+        dwData1: DWORD,
+        dwData2: DWORD,
+        dwData3: DWORD,
+        dwData4: DWORD,
+    ) -> HRESULT {
+        (self
+            .SimConnect_TransmitClientEvent_EX1
+            .as_ref()
+            .expect("Expected function, got error."))(hSimConnect, ObjectID, EventID, GroupID, Flags, dwData0, dwData1, dwData2, dwData3, dwData4)
+    }
+    pub unsafe fn SimConnect_AddToFacilityDefinition(
+        &self,
+        hSimConnect: HANDLE,
+        DefineID: SIMCONNECT_DATA_DEFINITION_ID,
+        FieldName: *const crate::ctypes::c_char,
+    ) -> HRESULT {
+        (self
+            .SimConnect_AddToFacilityDefinition
+            .as_ref()
+            .expect("Expected function, got error."))(hSimConnect, DefineID, FieldName)
+    }
+    pub unsafe fn SimConnect_RequestFacilityData(
+        &self,
+        hSimConnect: HANDLE,
+        DefineID: SIMCONNECT_DATA_DEFINITION_ID,
+        RequestID: SIMCONNECT_DATA_REQUEST_ID,
+        ICAO: *const crate::ctypes::c_char,
+        Region: *const crate::ctypes::c_char,
+    ) -> HRESULT {
+        (self
+            .SimConnect_RequestFacilityData
+            .as_ref()
+            .expect("Expected function, got error."))(hSimConnect, DefineID, RequestID, ICAO, Region)
+    }
+    pub unsafe fn SimConnect_SubscribeToFacilities_EX1(
+        &self,
+        hSimConnect: HANDLE,
+        type_: SIMCONNECT_FACILITY_LIST_TYPE,
+        newElemInRangeRequestID: SIMCONNECT_DATA_REQUEST_ID,
+        oldElemOutRangeRequestID: SIMCONNECT_DATA_REQUEST_ID,
+    ) -> HRESULT {
+        (self
+            .SimConnect_SubscribeToFacilities_EX1
+            .as_ref()
+            .expect("Expected function, got error."))(hSimConnect, type_, newElemInRangeRequestID, oldElemOutRangeRequestID)
+    }
+    pub unsafe fn SimConnect_UnsubscribeToFacilities_EX1(
+        &self,
+        hSimConnect: HANDLE,
+        type_: SIMCONNECT_FACILITY_LIST_TYPE,
+        bUnsubscribeNewInRange: bool,
+        bUnsubscribeOldOutRange: bool,
+    ) -> HRESULT {
+        (self
+            .SimConnect_UnsubscribeToFacilities_EX1
+            .as_ref()
+            .expect("Expected function, got error."))(hSimConnect, type_, bUnsubscribeNewInRange, bUnsubscribeOldOutRange)
+    }
+    pub unsafe fn SimConnect_RequestFacilitiesList_EX1(
+        &self,
+        hSimConnect: HANDLE,
+        type_: SIMCONNECT_FACILITY_LIST_TYPE,
+        RequestID: SIMCONNECT_DATA_REQUEST_ID,
+    ) -> HRESULT {
+        (self
+            .SimConnect_RequestFacilitiesList_EX1
+            .as_ref()
+            .expect("Expected function, got error."))(hSimConnect, type_, RequestID)
+    }
+    pub unsafe fn SimConnect_RequestFacilityData_EX1(
+        &self,
+        hSimConnect: HANDLE,
+        DefineID: SIMCONNECT_DATA_DEFINITION_ID,
+        RequestID: SIMCONNECT_DATA_REQUEST_ID,
+        ICAO: *const crate::ctypes::c_char,
+        Region: *const crate::ctypes::c_char,
+        Type: crate::ctypes::c_char,
+    ) -> HRESULT {
+        (self
+            .SimConnect_RequestFacilityData_EX1
+            .as_ref()
+            .expect("Expected function, got error."))(hSimConnect, DefineID, RequestID, ICAO, Region, Type)
+    }
+    pub unsafe fn SimConnect_RequestJetwayData(
+        &self,
+        hSimConnect: HANDLE,
+        AirportIcao: *const crate::ctypes::c_char,
+        ArrayCount: DWORD,
+        Indexes: *mut crate::ctypes::c_int,
+    ) -> HRESULT {
+        (self
+            .SimConnect_RequestJetwayData
+            .as_ref()
+            .expect("Expected function, got error."))(hSimConnect, AirportIcao, ArrayCount, Indexes)
+    }
+    pub unsafe fn SimConnect_EnumerateControllers(
+        &self,
+        hSimConnect: HANDLE,
+    ) -> HRESULT {
+        (self
+            .SimConnect_EnumerateControllers
+            .as_ref()
+            .expect("Expected function, got error."))(hSimConnect)
+    }
+    pub unsafe fn SimConnect_MapInputEventToClientEvent_EX1(
+        &self,
+        hSimConnect: HANDLE,
+        GroupID: SIMCONNECT_INPUT_GROUP_ID,
+        szInputDefinition: *const crate::ctypes::c_char,
+        DownEventID: SIMCONNECT_CLIENT_EVENT_ID,
+        DownValue: DWORD,
+        UpEventID: SIMCONNECT_CLIENT_EVENT_ID,
+        UpValue: DWORD,
+        bMaskable: BOOL,
+    ) -> HRESULT {
+        (self
+            .SimConnect_MapInputEventToClientEvent_EX1
+            .as_ref()
+            .expect("Expected function, got error."))(hSimConnect, GroupID, szInputDefinition, DownEventID, DownValue, UpEventID, UpValue, bMaskable)
+    }
+    pub unsafe fn SimConnect_ExecuteAction(
+        &self,
+        hSimConnect: HANDLE,
+        cbRequestID: DWORD,
+        szActionID: *const crate::ctypes::c_char,
+        cbUnitSize: DWORD,
+        pParamValues: *mut crate::ctypes::c_void,
+    ) -> HRESULT {
+        (self
+            .SimConnect_ExecuteAction
+            .as_ref()
+            .expect("Expected function, got error."))(hSimConnect, cbRequestID, szActionID, cbUnitSize, pParamValues)
+    }
+    pub unsafe fn SimConnect_EnumerateInputEvents(
+        &self,
+        hSimConnect: HANDLE,
+        RequestID: SIMCONNECT_DATA_REQUEST_ID,
+    ) -> HRESULT {
+        (self
+            .SimConnect_EnumerateInputEvents
+            .as_ref()
+            .expect("Expected function, got error."))(hSimConnect, RequestID)
+    }
+    pub unsafe fn SimConnect_GetInputEvent(
+        &self,
+        hSimConnect: HANDLE,
+        RequestID: SIMCONNECT_DATA_REQUEST_ID,
+        Hash: UINT64,
+    ) -> HRESULT {
+        (self
+            .SimConnect_GetInputEvent
+            .as_ref()
+            .expect("Expected function, got error."))(hSimConnect, RequestID, Hash)
+    }
+    pub unsafe fn SimConnect_SetInputEvent(
+        &self,
+        hSimConnect: HANDLE,
+        Hash: UINT64,
+        cbUnitSize: DWORD,
+        Value: *mut crate::ctypes::c_void,
+    ) -> HRESULT {
+        (self
+            .SimConnect_SetInputEvent
+            .as_ref()
+            .expect("Expected function, got error."))(hSimConnect, Hash, cbUnitSize, Value)
+    }
+    pub unsafe fn SimConnect_SubscribeInputEvent(
+        &self,
+        hSimConnect: HANDLE,
+        Hash: UINT64,
+    ) -> HRESULT {
+        (self
+            .SimConnect_SubscribeInputEvent
+            .as_ref()
+            .expect("Expected function, got error."))(hSimConnect, Hash)
+    }
+    pub unsafe fn SimConnect_UnsubscribeInputEvent(
+        &self,
+        hSimConnect: HANDLE,
+        Hash: UINT64,
+    ) -> HRESULT {
+        (self
+            .SimConnect_UnsubscribeInputEvent
+            .as_ref()
+            .expect("Expected function, got error."))(hSimConnect, Hash)
+    }
+    pub unsafe fn SimConnect_EnumerateInputEventParams(
+        &self,
+        hSimConnect: HANDLE,
+        Hash: UINT64,
+    ) -> HRESULT {
+        (self
+            .SimConnect_EnumerateInputEventParams
+            .as_ref()
+            .expect("Expected function, got error."))(hSimConnect, Hash)
+    }
+    pub unsafe fn SimConnect_AddFacilityDataDefinitionFilter(
+        &self,
+        hSimConnect: HANDLE,
+        DefineID: SIMCONNECT_DATA_DEFINITION_ID,
+        szFilterPath: *const crate::ctypes::c_char,
+        cbUnitSize: DWORD,
+        pFilterData: *mut crate::ctypes::c_void,
+    ) -> HRESULT {
+        (self
+            .SimConnect_AddFacilityDataDefinitionFilter
+            .as_ref()
+            .expect("Expected function, got error."))(hSimConnect, DefineID, szFilterPath, cbUnitSize, pFilterData)
+    }
+    pub unsafe fn SimConnect_ClearAllFacilityDataDefinitionFilters(
+        &self,
+        hSimConnect: HANDLE,
+        DefineID: SIMCONNECT_DATA_DEFINITION_ID,
+    ) -> HRESULT {
+        (self
+            .SimConnect_ClearAllFacilityDataDefinitionFilters
+            .as_ref()
+            .expect("Expected function, got error."))(hSimConnect, DefineID)
+    }
+}
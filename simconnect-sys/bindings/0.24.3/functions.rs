@@ -0,0 +1,735 @@
+// generated from SimConnect.h of SDK 0.24.3, regenerate with SIMCONNECT_UPDATE_BINDINGS=1
+
+extern "C" {
+    pub fn SimConnect_MapClientEventToSimEvent(
+        hSimConnect: HANDLE,
+        EventID: SIMCONNECT_CLIENT_EVENT_ID,
+        EventName: *const crate::ctypes::c_char,
+    ) -> HRESULT;
+}
+extern "C" {
+    pub fn SimConnect_TransmitClientEvent(
+        hSimConnect: HANDLE,
+        ObjectID: SIMCONNECT_OBJECT_ID,
+        EventID: SIMCONNECT_CLIENT_EVENT_ID,
+        dwData: DWORD,
+        GroupID: SIMCONNECT_NOTIFICATION_GROUP_ID,
+        Flags: SIMCONNECT_EVENT_FLAG,
+    ) -> HRESULT;
+}
+extern "C" {
+    pub fn SimConnect_SetSystemEventState(
+        hSimConnect: HANDLE,
+        EventID: SIMCONNECT_CLIENT_EVENT_ID,
+        dwState: SIMCONNECT_STATE,
+    ) -> HRESULT;
+}
+extern "C" {
+    pub fn SimConnect_AddClientEventToNotificationGroup(
+        hSimConnect: HANDLE,
+        GroupID: SIMCONNECT_NOTIFICATION_GROUP_ID,
+        EventID: SIMCONNECT_CLIENT_EVENT_ID,
+        bMaskable: BOOL,
+    ) -> HRESULT;
+}
+extern "C" {
+    pub fn SimConnect_RemoveClientEvent(
+        hSimConnect: HANDLE,
+        GroupID: SIMCONNECT_NOTIFICATION_GROUP_ID,
+        EventID: SIMCONNECT_CLIENT_EVENT_ID,
+    ) -> HRESULT;
+}
+extern "C" {
+    pub fn SimConnect_SetNotificationGroupPriority(
+        hSimConnect: HANDLE,
+        GroupID: SIMCONNECT_NOTIFICATION_GROUP_ID,
+        uPriority: DWORD,
+    ) -> HRESULT;
+}
+extern "C" {
+    pub fn SimConnect_ClearNotificationGroup(
+        hSimConnect: HANDLE,
+        GroupID: SIMCONNECT_NOTIFICATION_GROUP_ID,
+    ) -> HRESULT;
+}
+extern "C" {
+    pub fn SimConnect_RequestNotificationGroup(
+        hSimConnect: HANDLE,
+        GroupID: SIMCONNECT_NOTIFICATION_GROUP_ID,
+        dwReserved: DWORD,
+        Flags: DWORD,
+    ) -> HRESULT;
+}
+extern "C" {
+    pub fn SimConnect_AddToDataDefinition(
+        hSimConnect: HANDLE,
+        DefineID: SIMCONNECT_DATA_DEFINITION_ID,
+        DatumName: *const crate::ctypes::c_char,
+        UnitsName: *const crate::ctypes::c_char,
+        DatumType: SIMCONNECT_DATATYPE,
+        fEpsilon: f32,
+        DatumID: DWORD,
+    ) -> HRESULT;
+}
+extern "C" {
+    pub fn SimConnect_ClearDataDefinition(
+        hSimConnect: HANDLE,
+        DefineID: SIMCONNECT_DATA_DEFINITION_ID,
+    ) -> HRESULT;
+}
+extern "C" {
+    pub fn SimConnect_RequestDataOnSimObject(
+        hSimConnect: HANDLE,
+        RequestID: SIMCONNECT_DATA_REQUEST_ID,
+        DefineID: SIMCONNECT_DATA_DEFINITION_ID,
+        ObjectID: SIMCONNECT_OBJECT_ID,
+        Period: SIMCONNECT_PERIOD,
+        Flags: SIMCONNECT_DATA_REQUEST_FLAG,
+        origin: DWORD,
+        interval: DWORD,
+        limit: DWORD,
+    ) -> HRESULT;
+}
+extern "C" {
+    pub fn SimConnect_RequestDataOnSimObjectType(
+        hSimConnect: HANDLE,
+        RequestID: SIMCONNECT_DATA_REQUEST_ID,
+        DefineID: SIMCONNECT_DATA_DEFINITION_ID,
+        dwRadiusMeters: DWORD,
+        type_: SIMCONNECT_SIMOBJECT_TYPE,
+    ) -> HRESULT;
+}
+extern "C" {
+    pub fn SimConnect_SetDataOnSimObject(
+        hSimConnect: HANDLE,
+        DefineID: SIMCONNECT_DATA_DEFINITION_ID,
+        ObjectID: SIMCONNECT_OBJECT_ID,
+        Flags: SIMCONNECT_DATA_SET_FLAG,
+        ArrayCount: DWORD,
+        cbUnitSize: DWORD,
+        pDataSet: *mut crate::ctypes::c_void,
+    ) -> HRESULT;
+}
+extern "C" {
+    pub fn SimConnect_MapInputEventToClientEvent(
+        hSimConnect: HANDLE,
+        GroupID: SIMCONNECT_INPUT_GROUP_ID,
+        szInputDefinition: *const crate::ctypes::c_char,
+        DownEventID: SIMCONNECT_CLIENT_EVENT_ID,
+        DownValue: DWORD,
+        UpEventID: SIMCONNECT_CLIENT_EVENT_ID,
+        UpValue: DWORD,
+        bMaskable: BOOL,
+    ) -> HRESULT;
+}
+extern "C" {
+    pub fn SimConnect_SetInputGroupPriority(
+        hSimConnect: HANDLE,
+        GroupID: SIMCONNECT_INPUT_GROUP_ID,
+        uPriority: DWORD,
+    ) -> HRESULT;
+}
+extern "C" {
+    pub fn SimConnect_RemoveInputEvent(
+        hSimConnect: HANDLE,
+        GroupID: SIMCONNECT_INPUT_GROUP_ID,
+        szInputDefinition: *const crate::ctypes::c_char,
+    ) -> HRESULT;
+}
+extern "C" {
+    pub fn SimConnect_ClearInputGroup(
+        hSimConnect: HANDLE,
+        GroupID: SIMCONNECT_INPUT_GROUP_ID,
+    ) -> HRESULT;
+}
+extern "C" {
+    pub fn SimConnect_SetInputGroupState(
+        hSimConnect: HANDLE,
+        GroupID: SIMCONNECT_INPUT_GROUP_ID,
+        dwState: DWORD,
+    ) -> HRESULT;
+}
+extern "C" {
+    pub fn SimConnect_RequestReservedKey(
+        hSimConnect: HANDLE,
+        EventID: SIMCONNECT_CLIENT_EVENT_ID,
+        szKeyChoice1: *const crate::ctypes::c_char,
+        szKeyChoice2: *const crate::ctypes::c_char,
+        szKeyChoice3: *const crate::ctypes::c_char,
+    ) -> HRESULT;
+}
+extern "C" {
+    pub fn SimConnect_SubscribeToSystemEvent(
+        hSimConnect: HANDLE,
+        EventID: SIMCONNECT_CLIENT_EVENT_ID,
+        SystemEventName: *const crate::ctypes::c_char,
+    ) -> HRESULT;
+}
+extern "C" {
+    pub fn SimConnect_UnsubscribeFromSystemEvent(
+        hSimConnect: HANDLE,
+        EventID: SIMCONNECT_CLIENT_EVENT_ID,
+    ) -> HRESULT;
+}
+extern "C" {
+    pub fn SimConnect_WeatherRequestInterpolatedObservation(
+        hSimConnect: HANDLE,
+        RequestID: SIMCONNECT_DATA_REQUEST_ID,
+        lat: f32,
+        lon: f32,
+        alt: f32,
+    ) -> HRESULT;
+}
+extern "C" {
+    pub fn SimConnect_WeatherRequestObservationAtStation(
+        hSimConnect: HANDLE,
+        RequestID: SIMCONNECT_DATA_REQUEST_ID,
+        szICAO: *const crate::ctypes::c_char,
+    ) -> HRESULT;
+}
+extern "C" {
+    pub fn SimConnect_WeatherRequestObservationAtNearestStation(
+        hSimConnect: HANDLE,
+        RequestID: SIMCONNECT_DATA_REQUEST_ID,
+        lat: f32,
+        lon: f32,
+    ) -> HRESULT;
+}
+extern "C" {
+    pub fn SimConnect_WeatherCreateStation(
+        hSimConnect: HANDLE,
+        RequestID: SIMCONNECT_DATA_REQUEST_ID,
+        szICAO: *const crate::ctypes::c_char,
+        szName: *const crate::ctypes::c_char,
+        lat: f32,
+        lon: f32,
+        alt: f32,
+    ) -> HRESULT;
+}
+extern "C" {
+    pub fn SimConnect_WeatherRemoveStation(
+        hSimConnect: HANDLE,
+        RequestID: SIMCONNECT_DATA_REQUEST_ID,
+        szICAO: *const crate::ctypes::c_char,
+    ) -> HRESULT;
+}
+extern "C" {
+    pub fn SimConnect_WeatherSetObservation(
+        hSimConnect: HANDLE,
+        Seconds: DWORD,
+        szMETAR: *const crate::ctypes::c_char,
+    ) -> HRESULT;
+}
+extern "C" {
+    pub fn SimConnect_WeatherSetModeServer(
+        hSimConnect: HANDLE,
+        dwPort: DWORD,
+        dwSeconds: DWORD,
+    ) -> HRESULT;
+}
+extern "C" {
+    pub fn SimConnect_WeatherSetModeTheme(
+        hSimConnect: HANDLE,
+        szThemeName: *const crate::ctypes::c_char,
+    ) -> HRESULT;
+}
+extern "C" {
+    pub fn SimConnect_WeatherSetModeGlobal(
+        hSimConnect: HANDLE,
+    ) -> HRESULT;
+}
+extern "C" {
+    pub fn SimConnect_WeatherSetModeCustom(
+        hSimConnect: HANDLE,
+    ) -> HRESULT;
+}
+extern "C" {
+    pub fn SimConnect_WeatherSetDynamicUpdateRate(
+        hSimConnect: HANDLE,
+        dwRate: DWORD,
+    ) -> HRESULT;
+}
+extern "C" {
+    pub fn SimConnect_WeatherRequestCloudState(
+        hSimConnect: HANDLE,
+        RequestID: SIMCONNECT_DATA_REQUEST_ID,
+        minLat: f32,
+        minLon: f32,
+        minAlt: f32,
+        maxLat: f32,
+        maxLon: f32,
+        maxAlt: f32,
+        dwFlags: DWORD,
+    ) -> HRESULT;
+}
+extern "C" {
+    pub fn SimConnect_WeatherCreateThermal(
+        hSimConnect: HANDLE,
+        RequestID: SIMCONNECT_DATA_REQUEST_ID,
+        lat: f32,
+        lon: f32,
+        alt: f32,
+        radius: f32,
+        height: f32,
+        coreRate: f32,
+        coreTurbulence: f32,
+        sinkRate: f32,
+        sinkTurbulence: f32,
+        coreSize: f32,
+        coreTransitionSize: f32,
+        sinkLayerSize: f32,
+        sinkTransitionSize: f32,
+    ) -> HRESULT;
+}
+extern "C" {
+    pub fn SimConnect_WeatherRemoveThermal(
+        hSimConnect: HANDLE,
+        ObjectID: SIMCONNECT_OBJECT_ID,
+    ) -> HRESULT;
+}
+extern "C" {
+    pub fn SimConnect_AICreateParkedATCAircraft(
+        hSimConnect: HANDLE,
+        szContainerTitle: *const crate::ctypes::c_char,
+        szTailNumber: *const crate::ctypes::c_char,
+        szAirportID: *const crate::ctypes::c_char,
+        RequestID: SIMCONNECT_DATA_REQUEST_ID,
+    ) -> HRESULT;
+}
+extern "C" {
+    pub fn SimConnect_AICreateEnrouteATCAircraft(
+        hSimConnect: HANDLE,
+        szContainerTitle: *const crate::ctypes::c_char,
+        szTailNumber: *const crate::ctypes::c_char,
+        iFlightNumber: crate::ctypes::c_int,
+        szFlightPlanPath: *const crate::ctypes::c_char,
+        dFlightPlanPosition: f64,
+        bTouchAndGo: BOOL,
+        RequestID: SIMCONNECT_DATA_REQUEST_ID,
+    ) -> HRESULT;
+}
+extern "C" {
+    pub fn SimConnect_AICreateNonATCAircraft(
+        hSimConnect: HANDLE,
+        szContainerTitle: *const crate::ctypes::c_char,
+        szTailNumber: *const crate::ctypes::c_char,
+        InitPos: SIMCONNECT_DATA_INITPOSITION,
+        RequestID: SIMCONNECT_DATA_REQUEST_ID,
+    ) -> HRESULT;
+}
+extern "C" {
+    pub fn SimConnect_AICreateSimulatedObject(
+        hSimConnect: HANDLE,
+        szContainerTitle: *const crate::ctypes::c_char,
+        InitPos: SIMCONNECT_DATA_INITPOSITION,
+        RequestID: SIMCONNECT_DATA_REQUEST_ID,
+    ) -> HRESULT;
+}
+extern "C" {
+    pub fn SimConnect_AIReleaseControl(
+        hSimConnect: HANDLE,
+        ObjectID: SIMCONNECT_OBJECT_ID,
+        RequestID: SIMCONNECT_DATA_REQUEST_ID,
+    ) -> HRESULT;
+}
+extern "C" {
+    pub fn SimConnect_AIRemoveObject(
+        hSimConnect: HANDLE,
+        ObjectID: SIMCONNECT_OBJECT_ID,
+        RequestID: SIMCONNECT_DATA_REQUEST_ID,
+    ) -> HRESULT;
+}
+extern "C" {
+    pub fn SimConnect_AISetAircraftFlightPlan(
+        hSimConnect: HANDLE,
+        ObjectID: SIMCONNECT_OBJECT_ID,
+        szFlightPlanPath: *const crate::ctypes::c_char,
+        RequestID: SIMCONNECT_DATA_REQUEST_ID,
+    ) -> HRESULT;
+}
+extern "C" {
+    pub fn SimConnect_ExecuteMissionAction(
+        hSimConnect: HANDLE,
+        guidInstanceId: GUID,
+    ) -> HRESULT;
+}
+extern "C" {
+    pub fn SimConnect_CompleteCustomMissionAction(
+        hSimConnect: HANDLE,
+        guidInstanceId: GUID,
+    ) -> HRESULT;
+}
+extern "C" {
+    pub fn SimConnect_Close(
+        hSimConnect: HANDLE,
+    ) -> HRESULT;
+}
+extern "C" {
+    pub fn SimConnect_RetrieveString(
+        pData: *mut SIMCONNECT_RECV,
+        cbData: DWORD,
+        pStringV: *mut crate::ctypes::c_void,
+        pszString: *mut *mut crate::ctypes::c_char,
+        pcbString: *mut DWORD,
+    ) -> HRESULT;
+}
+extern "C" {
+    pub fn SimConnect_GetLastSentPacketID(
+        hSimConnect: HANDLE,
+        pdwError: *mut DWORD,
+    ) -> HRESULT;
+}
+extern "C" {
+    pub fn SimConnect_Open(
+        phSimConnect: *mut HANDLE,
+        szName: LPCSTR,
+        hWnd: HWND,
+        UserEventWin32: DWORD,
+        hEventHandle: HANDLE,
+        ConfigIndex: DWORD,
+    ) -> HRESULT;
+}
+extern "C" {
+    pub fn SimConnect_CallDispatch(
+        hSimConnect: HANDLE,
+        pfcnDispatch: DispatchProc,
+        pContext: *mut crate::ctypes::c_void,
+    ) -> HRESULT;
+}
+extern "C" {
+    pub fn SimConnect_GetNextDispatch(
+        hSimConnect: HANDLE,
+        ppData: *mut *mut SIMCONNECT_RECV,
+        pcbData: *mut DWORD,
+    ) -> HRESULT;
+}
+extern "C" {
+    pub fn SimConnect_RequestResponseTimes(
+        hSimConnect: HANDLE,
+        nCount: DWORD,
+        fElapsedSeconds: *mut f32,
+    ) -> HRESULT;
+}
+extern "C" {
+    pub fn SimConnect_InsertString(
+        pDest: *mut crate::ctypes::c_char,
+        cbDest: DWORD,
+        ppEnd: *mut *mut crate::ctypes::c_void,
+        pcbStringV: *mut DWORD,
+        pSource: *const crate::ctypes::c_char,
+    ) -> HRESULT;
+}
+extern "C" {
+    pub fn SimConnect_CameraSetRelative6DOF(
+        hSimConnect: HANDLE,
+        fDeltaX: f32,
+        fDeltaY: f32,
+        fDeltaZ: f32,
+        fPitchDeg: f32,
+        fBankDeg: f32,
+        fHeadingDeg: f32,
+    ) -> HRESULT;
+}
+extern "C" {
+    pub fn SimConnect_MenuAddItem(
+        hSimConnect: HANDLE,
+        szMenuItem: *const crate::ctypes::c_char,
+        MenuEventID: SIMCONNECT_CLIENT_EVENT_ID,
+        dwData: DWORD,
+    ) -> HRESULT;
+}
+extern "C" {
+    pub fn SimConnect_MenuDeleteItem(
+        hSimConnect: HANDLE,
+        MenuEventID: SIMCONNECT_CLIENT_EVENT_ID,
+    ) -> HRESULT;
+}
+extern "C" {
+    pub fn SimConnect_MenuAddSubItem(
+        hSimConnect: HANDLE,
+        MenuEventID: SIMCONNECT_CLIENT_EVENT_ID,
+        szMenuItem: *const crate::ctypes::c_char,
+        SubMenuEventID: SIMCONNECT_CLIENT_EVENT_ID,
+        dwData: DWORD,
+    ) -> HRESULT;
+}
+extern "C" {
+    pub fn SimConnect_MenuDeleteSubItem(
+        hSimConnect: HANDLE,
+        MenuEventID: SIMCONNECT_CLIENT_EVENT_ID,
+        SubMenuEventID: SIMCONNECT_CLIENT_EVENT_ID,
+    ) -> HRESULT;
+}
+extern "C" {
+    pub fn SimConnect_RequestSystemState(
+        hSimConnect: HANDLE,
+        RequestID: SIMCONNECT_DATA_REQUEST_ID,
+        szState: *const crate::ctypes::c_char,
+    ) -> HRESULT;
+}
+extern "C" {
+    pub fn SimConnect_SetSystemState(
+        hSimConnect: HANDLE,
+        szState: *const crate::ctypes::c_char,
+        dwInteger: DWORD,
+        fFloat: f32,
+        szString: *const crate::ctypes::c_char,
+    ) -> HRESULT;
+}
+extern "C" {
+    pub fn SimConnect_MapClientDataNameToID(
+        hSimConnect: HANDLE,
+        szClientDataName: *const crate::ctypes::c_char,
+        ClientDataID: SIMCONNECT_CLIENT_DATA_ID,
+    ) -> HRESULT;
+}
+extern "C" {
+    pub fn SimConnect_CreateClientData(
+        hSimConnect: HANDLE,
+        ClientDataID: SIMCONNECT_CLIENT_DATA_ID,
+        dwSize: DWORD,
+        Flags: SIMCONNECT_CREATE_CLIENT_DATA_FLAG,
+    ) -> HRESULT;
+}
+extern "C" {
+    pub fn SimConnect_AddToClientDataDefinition(
+        hSimConnect: HANDLE,
+        DefineID: SIMCONNECT_CLIENT_DATA_DEFINITION_ID,
+        dwOffset: DWORD,
+        dwSizeOrType: DWORD,
+        fEpsilon: f32,
+        DatumID: DWORD,
+    ) -> HRESULT;
+}
+extern "C" {
+    pub fn SimConnect_ClearClientDataDefinition(
+        hSimConnect: HANDLE,
+        DefineID: SIMCONNECT_CLIENT_DATA_DEFINITION_ID,
+    ) -> HRESULT;
+}
+extern "C" {
+    pub fn SimConnect_RequestClientData(
+        hSimConnect: HANDLE,
+        ClientDataID: SIMCONNECT_CLIENT_DATA_ID,
+        RequestID: SIMCONNECT_DATA_REQUEST_ID,
+        DefineID: SIMCONNECT_CLIENT_DATA_DEFINITION_ID,
+        Period: SIMCONNECT_CLIENT_DATA_PERIOD,
+        Flags: SIMCONNECT_CLIENT_DATA_REQUEST_FLAG,
+        origin: DWORD,
+        interval: DWORD,
+        limit: DWORD,
+    ) -> HRESULT;
+}
+extern "C" {
+    pub fn SimConnect_SetClientData(
+        hSimConnect: HANDLE,
+        ClientDataID: SIMCONNECT_CLIENT_DATA_ID,
+        DefineID: SIMCONNECT_CLIENT_DATA_DEFINITION_ID,
+        Flags: SIMCONNECT_CLIENT_DATA_SET_FLAG,
+        dwReserved: DWORD,
+        cbUnitSize: DWORD,
+        pDataSet: *mut crate::ctypes::c_void,
+    ) -> HRESULT;
+}
+extern "C" {
+    pub fn SimConnect_FlightLoad(
+        hSimConnect: HANDLE,
+        szFileName: *const crate::ctypes::c_char,
+    ) -> HRESULT;
+}
+extern "C" {
+    pub fn SimConnect_FlightSave(
+        hSimConnect: HANDLE,
+        szFileName: *const crate::ctypes::c_char,
+        szTitle: *const crate::ctypes::c_char,
+        szDescription: *const crate::ctypes::c_char,
+        Flags: DWORD,
+    ) -> HRESULT;
+}
+extern "C" {
+    pub fn SimConnect_FlightPlanLoad(
+        hSimConnect: HANDLE,
+        szFileName: *const crate::ctypes::c_char,
+    ) -> HRESULT;
+}
+extern "C" {
+    pub fn SimConnect_Text(
+        hSimConnect: HANDLE,
+        type_: SIMCONNECT_TEXT_TYPE,
+        fTimeSeconds: f32,
+        EventID: SIMCONNECT_CLIENT_EVENT_ID,
+        cbUnitSize: DWORD,
+        pDataSet: *mut crate::ctypes::c_void,
+    ) -> HRESULT;
+}
+extern "C" {
+    pub fn SimConnect_SubscribeToFacilities(
+        hSimConnect: HANDLE,
+        type_: SIMCONNECT_FACILITY_LIST_TYPE,
+        RequestID: SIMCONNECT_DATA_REQUEST_ID,
+    ) -> HRESULT;
+}
+extern "C" {
+    pub fn SimConnect_UnsubscribeToFacilities(
+        hSimConnect: HANDLE,
+        type_: SIMCONNECT_FACILITY_LIST_TYPE,
+    ) -> HRESULT;
+}
+extern "C" {
+    pub fn SimConnect_RequestFacilitiesList(
+        hSimConnect: HANDLE,
+        type_: SIMCONNECT_FACILITY_LIST_TYPE,
+        RequestID: SIMCONNECT_DATA_REQUEST_ID,
+    ) -> HRESULT;
+}
+extern "C" {
+    pub fn SimConnect_TransmitClientEvent_EX1(
+        hSimConnect: HANDLE,
+        ObjectID: SIMCONNECT_OBJECT_ID,
+        EventID: SIMCONNECT_CLIENT_EVENT_ID,
+        GroupID: SIMCONNECT_NOTIFICATION_GROUP_ID,
+        Flags: SIMCONNECT_EVENT_FLAG,
+        dwData0: DWORD,
+        dwData1: DWORD,
+        dwData2: DWORD,
+        dwData3: DWORD,
+        dwData4: DWORD,
+    ) -> HRESULT;
+}
+extern "C" {
+    pub fn SimConnect_AddToFacilityDefinition(
+        hSimConnect: HANDLE,
+        DefineID: SIMCONNECT_DATA_DEFINITION_ID,
+        FieldName: *const crate::ctypes::c_char,
+    ) -> HRESULT;
+}
+extern "C" {
+    pub fn SimConnect_RequestFacilityData(
+        hSimConnect: HANDLE,
+        DefineID: SIMCONNECT_DATA_DEFINITION_ID,
+        RequestID: SIMCONNECT_DATA_REQUEST_ID,
+        ICAO: *const crate::ctypes::c_char,
+        Region: *const crate::ctypes::c_char,
+    ) -> HRESULT;
+}
+extern "C" {
+    pub fn SimConnect_SubscribeToFacilities_EX1(
+        hSimConnect: HANDLE,
+        type_: SIMCONNECT_FACILITY_LIST_TYPE,
+        newElemInRangeRequestID: SIMCONNECT_DATA_REQUEST_ID,
+        oldElemOutRangeRequestID: SIMCONNECT_DATA_REQUEST_ID,
+    ) -> HRESULT;
+}
+extern "C" {
+    pub fn SimConnect_UnsubscribeToFacilities_EX1(
+        hSimConnect: HANDLE,
+        type_: SIMCONNECT_FACILITY_LIST_TYPE,
+        bUnsubscribeNewInRange: bool,
+        bUnsubscribeOldOutRange: bool,
+    ) -> HRESULT;
+}
+extern "C" {
+    pub fn SimConnect_RequestFacilitiesList_EX1(
+        hSimConnect: HANDLE,
+        type_: SIMCONNECT_FACILITY_LIST_TYPE,
+        RequestID: SIMCONNECT_DATA_REQUEST_ID,
+    ) -> HRESULT;
+}
+extern "C" {
+    pub fn SimConnect_RequestFacilityData_EX1(
+        hSimConnect: HANDLE,
+        DefineID: SIMCONNECT_DATA_DEFINITION_ID,
+        RequestID: SIMCONNECT_DATA_REQUEST_ID,
+        ICAO: *const crate::ctypes::c_char,
+        Region: *const crate::ctypes::c_char,
+        Type: crate::ctypes::c_char,
+    ) -> HRESULT;
+}
+extern "C" {
+    pub fn SimConnect_RequestJetwayData(
+        hSimConnect: HANDLE,
+        AirportIcao: *const crate::ctypes::c_char,
+        ArrayCount: DWORD,
+        Indexes: *mut crate::ctypes::c_int,
+    ) -> HRESULT;
+}
+extern "C" {
+    pub fn SimConnect_EnumerateControllers(
+        hSimConnect: HANDLE,
+    ) -> HRESULT;
+}
+extern "C" {
+    pub fn SimConnect_MapInputEventToClientEvent_EX1(
+        hSimConnect: HANDLE,
+        GroupID: SIMCONNECT_INPUT_GROUP_ID,
+        szInputDefinition: *const crate::ctypes::c_char,
+        DownEventID: SIMCONNECT_CLIENT_EVENT_ID,
+        DownValue: DWORD,
+        UpEventID: SIMCONNECT_CLIENT_EVENT_ID,
+        UpValue: DWORD,
+        bMaskable: BOOL,
+    ) -> HRESULT;
+}
+extern "C" {
+    pub fn SimConnect_ExecuteAction(
+        hSimConnect: HANDLE,
+        cbRequestID: DWORD,
+        szActionID: *const crate::ctypes::c_char,
+        cbUnitSize: DWORD,
+        pParamValues: *mut crate::ctypes::c_void,
+    ) -> HRESULT;
+}
+extern "C" {
+    pub fn SimConnect_EnumerateInputEvents(
+        hSimConnect: HANDLE,
+        RequestID: SIMCONNECT_DATA_REQUEST_ID,
+    ) -> HRESULT;
+}
+extern "C" {
+    pub fn SimConnect_GetInputEvent(
+        hSimConnect: HANDLE,
+        RequestID: SIMCONNECT_DATA_REQUEST_ID,
+        Hash: UINT64,
+    ) -> HRESULT;
+}
+extern "C" {
+    pub fn SimConnect_SetInputEvent(
+        hSimConnect: HANDLE,
+        Hash: UINT64,
+        cbUnitSize: DWORD,
+        Value: *mut crate::ctypes::c_void,
+    ) -> HRESULT;
+}
+extern "C" {
+    pub fn SimConnect_SubscribeInputEvent(
+        hSimConnect: HANDLE,
+        Hash: UINT64,
+    ) -> HRESULT;
+}
+extern "C" {
+    pub fn SimConnect_UnsubscribeInputEvent(
+        hSimConnect: HANDLE,
+        Hash: UINT64,
+    ) -> HRESULT;
+}
+extern "C" {
+    pub fn SimConnect_EnumerateInputEventParams(
+        hSimConnect: HANDLE,
+        Hash: UINT64,
+    ) -> HRESULT;
+}
+extern "C" {
+    pub fn SimConnect_AddFacilityDataDefinitionFilter(
+        hSimConnect: HANDLE,
+        DefineID: SIMCONNECT_DATA_DEFINITION_ID,
+        szFilterPath: *const crate::ctypes::c_char,
+        cbUnitSize: DWORD,
+        pFilterData: *mut crate::ctypes::c_void,
+    ) -> HRESULT;
+}
+extern "C" {
+    pub fn SimConnect_ClearAllFacilityDataDefinitionFilters(
+        hSimConnect: HANDLE,
+        DefineID: SIMCONNECT_DATA_DEFINITION_ID,
+    ) -> HRESULT;
+}
@@ -1,25 +1,30 @@
 use std::env;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 // env vars
-const ENV_SIMCONNECT_DIR: &'static str = "SIMCONNECT_DIR";
-const ENV_SIMCONNECT_NO_VENDOR: &'static str = "SIMCONNECT_NO_VENDOR";
+const ENV_SIMCONNECT_DIR: &str = "SIMCONNECT_DIR";
+const ENV_SIMCONNECT_NO_VENDOR: &str = "SIMCONNECT_NO_VENDOR";
+const ENV_SIMCONNECT_UPDATE_BINDINGS: &str = "SIMCONNECT_UPDATE_BINDINGS";
+const ENV_SIMCONNECT_DLLTOOL: &str = "SIMCONNECT_DLLTOOL";
+
+include!("generate.rs");
 
 // defaults
-const DEFAULT_SIMCONNECT_DIR: &'static str = "C:\\MSFS SDK\\SimConnect SDK";
+const DEFAULT_SIMCONNECT_DIR: &str = "C:\\MSFS SDK\\SimConnect SDK";
 
 fn main() {
 
     // rebuild if env vars change
     println!("cargo:rerun-if-env-changed={ENV_SIMCONNECT_DIR}");
     println!("cargo:rerun-if-env-changed={ENV_SIMCONNECT_NO_VENDOR}");
+    println!("cargo:rerun-if-env-changed={ENV_SIMCONNECT_UPDATE_BINDINGS}");
+    println!("cargo:rerun-if-env-changed={ENV_SIMCONNECT_DLLTOOL}");
         
     // convert feature flags to booleans
     let feature_vendored;
     let feature_static;
     #[cfg(not(feature = "c_msfs_sdk"))] {
-        feature_vendored = true && 
-            env::var(ENV_SIMCONNECT_NO_VENDOR).map_or(true, |s| s == "0");
+        feature_vendored = env::var(ENV_SIMCONNECT_NO_VENDOR).map_or(true, |s| s == "0");
     }
     #[cfg(feature = "c_msfs_sdk")] {
         feature_vendored = false;
@@ -31,11 +36,47 @@ fn main() {
         feature_static = false;
     }
 
+    // there is no SimConnect to link against outside of windows, so don't
+    // require libclang there and use the pre-generated bindings
+    let target = env::var("TARGET").unwrap();
+    let target_windows = env::var("CARGO_CFG_TARGET_OS").unwrap() == "windows";
+    let target_gnu = env::var("CARGO_CFG_TARGET_ENV").unwrap() == "gnu";
+    let use_bindgen = target_windows && cfg!(not(feature = "no_bindgen"));
+    let link = target_windows && cfg!(not(any(feature = "types_only", feature = "dynamic")));
+
+    // determine which sdk version to use, the newest one selected with an
+    // `sdk_*` feature so dependencies can only raise it, or the latest
+    let sdk_versions = vendored_sdk_versions();
+    let sdk_version = sdk_versions.iter()
+        .filter(|version| env::var(format!("CARGO_FEATURE_SDK_{}", version.replace('.', "_"))).is_ok())
+        .max_by_key(|version| parse_version(version))
+        .cloned()
+        .unwrap_or_else(latest_sdk_version);
+
+    // expose the selected version, and a cfg for every vendored version up to
+    // it to mark items that only exist in newer sdks. dependents get both in
+    // DEP_SIMCONNECT_SDK_VERSION and DEP_SIMCONNECT_SDK_VERSIONS to do the same
+    println!("cargo:rustc-env=SIMCONNECT_SDK_VERSION={sdk_version}");
+    println!("cargo:sdk_version={sdk_version}");
+    println!("cargo:sdk_versions={}", sdk_versions.join(","));
+    for version in &sdk_versions {
+        let cfg = format!("sdk_at_least_{}", version.replace('.', "_"));
+        println!("cargo:rustc-check-cfg=cfg({cfg})");
+        if parse_version(version) <= parse_version(&sdk_version) {
+            println!("cargo:rustc-cfg={cfg}");
+        }
+    }
+
     // determine which sdk directory to use
     let simconnect_dir = match feature_vendored {
-        true => PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("sdk"),
-        false => PathBuf::from(env::var("SIMCONNECT_DIR")
-            .unwrap_or(DEFAULT_SIMCONNECT_DIR.to_string()))
+        true => PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("sdk").join(&sdk_version),
+        false => PathBuf::from(env::var(ENV_SIMCONNECT_DIR).unwrap_or_else(|_| {
+            // the default is a windows path, cross compiling needs it set
+            if !env::var("HOST").unwrap().contains("windows") {
+                panic!("{ENV_SIMCONNECT_DIR} must point to the SimConnect SDK when not building on Windows");
+            }
+            DEFAULT_SIMCONNECT_DIR.to_string()
+        }))
     };
 
     // determine path for SimConnect header
@@ -52,56 +93,162 @@ fn main() {
         "SimConnect"
     };
 
-    // emit linking config for simconnect dependencies
-    for lib in vec![
-        "shlwapi",
-        "user32",
-        "Ws2_32"
-    ] {
-        println!("cargo:rustc-link-lib={}", lib);
+    // functions are extern declarations when linked, a table of function
+    // pointers when loaded at runtime, or left out so only types and
+    // constants are exposed
+    let parts = if cfg!(feature = "types_only") {
+        vec![BINDINGS_TYPES]
+    } else if cfg!(feature = "dynamic") {
+        vec![BINDINGS_TYPES, BINDINGS_DYNAMIC]
+    } else {
+        vec![BINDINGS_TYPES, BINDINGS_FUNCTIONS]
+    };
+
+    // refresh every part of the pre-generated bindings for the vendored sdk
+    let update = env::var(ENV_SIMCONNECT_UPDATE_BINDINGS).is_ok_and(|s| s != "0");
+    if update && use_bindgen && feature_vendored {
+        let dir = pregenerated_dir(&sdk_version);
+        std::fs::create_dir_all(&dir).unwrap();
+        for part in [BINDINGS_TYPES, BINDINGS_FUNCTIONS, BINDINGS_DYNAMIC] {
+            let header = format!("// generated from SimConnect.h of SDK {}, regenerate with {ENV_SIMCONNECT_UPDATE_BINDINGS}=1\n\n", sdk_version);
+            std::fs::write(dir.join(part), header + &generate(&simconnect_header, &target, part))
+                .expect("Couldn't update pre-generated SimConnect bindings!");
+        }
     }
 
-    // emit linking configuration
-    println!("cargo:rustc-link-lib={}", simconnect_lib);
-    println!("cargo:rustc-link-search={}", simconnect_lib_dir.display());
+    // generate bindings using bindgen and clang, or use the ones checked in
+    // for the vendored sdk
+    let bindings: String = parts.into_iter()
+        .map(|part| match use_bindgen {
+            true => generate(&simconnect_header, &target, part),
+            false => pregenerated(feature_vendored, &sdk_version, part),
+        })
+        .collect();
 
-    // hack to ensure DLL is copied into deps directory to make `cargo run` work
-    if !feature_static {
-        let dll = "SimConnect.dll";
-        let profile = env::var("PROFILE").unwrap();
-        let target_dir = get_cargo_target_dir().unwrap().join(profile).join("deps");
-        let _ = std::fs::copy(simconnect_lib_dir.join(dll), target_dir.join(dll));
+    let out_dir = PathBuf::from(env::var("OUT_DIR").unwrap());
+    std::fs::write(out_dir.join("bindings.rs"), &bindings)
+        .expect("Couldn't write SimConnect bindings!");
+
+    if link {
+
+        // emit linking config for simconnect dependencies, lowercase so the
+        // mingw libs are found on case sensitive file systems
+        for lib in [
+            "shlwapi",
+            "user32",
+            "ws2_32"
+        ] {
+            println!("cargo:rustc-link-lib={}", lib);
+        }
+
+        // emit linking configuration, the gnu toolchain gets an import
+        // library generated from the functions in the bindings as it can't
+        // rely on the msvc one
+        if target_gnu {
+            if feature_static {
+                panic!("the static SimConnect lib is built with MSVC and can't be linked for {target}");
+            }
+            mingw_import_lib(&bindings, &out_dir);
+            println!("cargo:rustc-link-lib=dylib=SimConnect");
+            println!("cargo:rustc-link-search=native={}", out_dir.display());
+        } else {
+            println!("cargo:rustc-link-lib={}", simconnect_lib);
+            println!("cargo:rustc-link-search={}", simconnect_lib_dir.display());
+        }
+
+        // hack to ensure DLL is copied into deps directory to make `cargo run` work
+        if !feature_static {
+            let dll = "SimConnect.dll";
+            let target_dir = get_cargo_profile_dir().unwrap().join("deps");
+            let _ = std::fs::copy(simconnect_lib_dir.join(dll), target_dir.join(dll));
+        }
     }
+}
 
-    // generate bindings using bindgen and clang
-    let bindings = bindgen::Builder::default()
-        .header("wrapper.h")
-        .header(simconnect_header)
-        .prepend_enum_name(false)
-        // .layout_tests(false)
-        .allowlist_var("MAX_.*")
-        .allowlist_var("INITPOSITION_.*")
-        .allowlist_item("(?i)SIMCONNECT.*")
+// generates one part of the bindings for the SimConnect header
+fn generate(header: &str, target: &str, part: &str) -> String {
+    builder("wrapper.h", header, target, part)
         .parse_callbacks(Box::new(bindgen::CargoCallbacks::new()))
-        .clang_args(&["-x", "c++", "-std=c++17"])
-        .derive_default(true)
         .generate()
-        .expect("Unable to generate SimConnect bindings");
+        .expect("Unable to generate SimConnect bindings")
+        .to_string()
+}
 
-    let out_dir = PathBuf::from(env::var("OUT_DIR").unwrap());
-    bindings
-        .write_to_file(out_dir.join("bindings.rs"))
-        .expect("Couldn't write SimConnect bindings!");
-        // panic!("test")
+// reads bindings checked in for a vendored sdk version
+fn pregenerated(vendored: bool, version: &str, file: &str) -> String {
+    if !vendored {
+        panic!("pre-generated SimConnect bindings are only available for the vendored SDK, \
+            bindgen is needed for the SDK in {ENV_SIMCONNECT_DIR}");
+    }
+    let path = pregenerated_dir(version).join(file);
+    println!("cargo:rerun-if-changed={}", path.display());
+    std::fs::read_to_string(&path)
+        .unwrap_or_else(|e| panic!("Couldn't read pre-generated SimConnect bindings {}: {e}", path.display()))
 }
 
-fn get_cargo_target_dir() -> Result<std::path::PathBuf, Box<dyn std::error::Error>> {
-    let skip_triple = std::env::var("TARGET")? == std::env::var("HOST")?;
-    let skip_parent_dirs = if skip_triple { 4 } else { 5 };
-    let out_dir = std::path::PathBuf::from(std::env::var("OUT_DIR")?);
-    let mut current = out_dir.as_path();
-    for _ in 0..skip_parent_dirs {
-        current = current.parent().ok_or("not found")?;
+// writes libSimConnect.dll.a to `out_dir` for the mingw linker, using
+// dlltool on a module definition listing every function in the bindings
+fn mingw_import_lib(bindings: &str, out_dir: &Path) {
+    let mut def = String::from("LIBRARY SimConnect.dll\nEXPORTS\n");
+    for line in bindings.lines() {
+        if let Some(function) = line.trim().strip_prefix("pub fn SimConnect_") {
+            let name = function.split('(').next().unwrap();
+            def += &format!("    SimConnect_{name}\n");
+        }
+    }
+    let def_file = out_dir.join("SimConnect.def");
+    std::fs::write(&def_file, def).expect("Couldn't write SimConnect.def!");
+
+    let dlltool = env::var(ENV_SIMCONNECT_DLLTOOL).unwrap_or_else(|_| {
+        match env::var("HOST").unwrap().contains("windows") {
+            true => "dlltool".to_string(),
+            false => "x86_64-w64-mingw32-dlltool".to_string(),
+        }
+    });
+    let status = std::process::Command::new(&dlltool)
+        .args(["--machine", "i386:x86-64", "--dllname", "SimConnect.dll", "--input-def"])
+        .arg(&def_file)
+        .arg("--output-lib")
+        .arg(out_dir.join("libSimConnect.dll.a"))
+        .status()
+        .unwrap_or_else(|e| panic!("Couldn't run {dlltool}, set {ENV_SIMCONNECT_DLLTOOL} to the mingw dlltool: {e}"));
+    if !status.success() {
+        panic!("{dlltool} failed to create the SimConnect import library ({status})");
     }
-    Ok(std::path::PathBuf::from(current))
+}
+
+fn pregenerated_dir(version: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("bindings").join(version)
+}
+
+// the latest sdk release, which the crate version follows
+fn latest_sdk_version() -> String {
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("sdk").join("version.txt");
+    std::fs::read_to_string(path).expect("Couldn't read sdk/version.txt").trim().to_string()
+}
+
+// every sdk version vendored side by side in `sdk/<version>`
+fn vendored_sdk_versions() -> Vec<String> {
+    let sdk_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("sdk");
+    let mut versions: Vec<String> = std::fs::read_dir(&sdk_dir)
+        .expect("Couldn't read the vendored SDKs!")
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.path().join("include").join("SimConnect.h").is_file())
+        .map(|entry| entry.file_name().to_string_lossy().into_owned())
+        .collect();
+    versions.sort_by_key(|version| parse_version(version));
+    println!("cargo:rerun-if-changed={}", sdk_dir.display());
+    versions
+}
+
+fn parse_version(version: &str) -> Vec<u32> {
+    version.split('.').map(|n| n.parse().unwrap_or(0)).collect()
+}
+
+// the directory binaries are built in, `target/<profile>` or
+// `target/<triple>/<profile>` when building for an explicit target
+fn get_cargo_profile_dir() -> Result<std::path::PathBuf, Box<dyn std::error::Error>> {
+    let out_dir = std::path::PathBuf::from(std::env::var("OUT_DIR")?);
+    let profile_dir = out_dir.ancestors().nth(3).ok_or("not found")?;
+    Ok(std::path::PathBuf::from(profile_dir))
 }
\ No newline at end of file
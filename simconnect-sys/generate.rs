@@ -0,0 +1,44 @@
+// bindgen configuration for the SimConnect header, shared by build.rs and
+// examples/sdk-version so headers are compared as they would be bound
+
+// parts of the bindings, as pre-generated for each sdk version
+const BINDINGS_TYPES: &str = "types.rs";
+const BINDINGS_FUNCTIONS: &str = "functions.rs";
+const BINDINGS_DYNAMIC: &str = "dynamic.rs";
+
+// returns a builder for one part of the bindings, `wrapper` is the path to
+// wrapper.h with the Windows definitions SimConnect.h relies on and `target`
+// the rust target the bindings are for
+fn builder(wrapper: &str, header: &str, target: &str, part: &str) -> bindgen::Builder {
+    let builder = bindgen::Builder::default()
+        .header(wrapper)
+        .header(header)
+        .prepend_enum_name(false)
+        .ctypes_prefix("crate::ctypes")
+        .clang_args(&["-x", "c++", "-std=c++17"])
+        .clang_arg(format!("--target={}", clang_target(target)))
+        .derive_default(true);
+    match part {
+        BINDINGS_TYPES => builder
+            .allowlist_var("MAX_.*")
+            .allowlist_var("INITPOSITION_.*")
+            .allowlist_item("(?i)SIMCONNECT.*")
+            .ignore_functions(),
+        BINDINGS_FUNCTIONS => builder
+            .allowlist_function("SimConnect_.*")
+            .allowlist_recursively(false),
+        BINDINGS_DYNAMIC => builder
+            .allowlist_function("SimConnect_.*")
+            .allowlist_recursively(false)
+            .dynamic_library_name("SimConnectLibrary"),
+        _ => unreachable!(),
+    }
+}
+
+// the msvc target for the architecture of `target`, so clang parses
+// SimConnect.h with the Windows data model (32 bit `long`) and `__int64`
+// whatever the host or the target's environment
+fn clang_target(target: &str) -> String {
+    let arch = target.split('-').next().unwrap();
+    format!("{arch}-pc-windows-msvc")
+}
@@ -0,0 +1,183 @@
+// sizes, alignments and field offsets of the SimConnect structs as compiled
+// for Windows, where `DWORD` is 32 bits and SimConnect.h packs every struct to
+// 1 byte. messages are decoded straight from these layouts, so bindings
+// generated with the wrong data model or packing fail here rather than
+// corrupting data. `_base` is the struct a C++ struct inherits from.
+
+use std::mem::{align_of, offset_of, size_of};
+
+use super::*;
+
+macro_rules! layout {
+    ($($name:ident: $size:literal, $align:literal { $($field:ident: $offset:literal),* $(,)? })*) => {
+        $(
+            #[test]
+            fn $name() {
+                assert_eq!(size_of::<$name>(), $size, "size of {}", stringify!($name));
+                assert_eq!(align_of::<$name>(), $align, "alignment of {}", stringify!($name));
+                $(
+                    assert_eq!(offset_of!($name, $field), $offset, "offset of {}::{}", stringify!($name), stringify!($field));
+                )*
+            }
+        )*
+    };
+}
+
+layout! {
+    SIMCONNECT_RECV: 12, 1 { dwSize: 0, dwVersion: 4, dwID: 8 }
+    SIMCONNECT_RECV_EXCEPTION: 24, 1 { _base: 0, dwException: 12, dwSendID: 16, dwIndex: 20 }
+    SIMCONNECT_RECV_OPEN: 308, 1 {
+        _base: 0,
+        szApplicationName: 12,
+        dwApplicationVersionMajor: 268,
+        dwApplicationVersionMinor: 272,
+        dwApplicationBuildMajor: 276,
+        dwApplicationBuildMinor: 280,
+        dwSimConnectVersionMajor: 284,
+        dwSimConnectVersionMinor: 288,
+        dwSimConnectBuildMajor: 292,
+        dwSimConnectBuildMinor: 296,
+        dwReserved1: 300,
+        dwReserved2: 304,
+    }
+    SIMCONNECT_RECV_QUIT: 12, 1 { _base: 0 }
+    SIMCONNECT_RECV_EVENT: 24, 1 { _base: 0, uGroupID: 12, uEventID: 16, dwData: 20 }
+    SIMCONNECT_RECV_EVENT_FILENAME: 288, 1 { _base: 0, szFileName: 24, dwFlags: 284 }
+    SIMCONNECT_RECV_EVENT_OBJECT_ADDREMOVE: 28, 1 { _base: 0, eObjType: 24 }
+    SIMCONNECT_RECV_EVENT_FRAME: 32, 1 { _base: 0, fFrameRate: 24, fSimSpeed: 28 }
+    SIMCONNECT_RECV_EVENT_MULTIPLAYER_SERVER_STARTED: 24, 1 { _base: 0 }
+    SIMCONNECT_RECV_EVENT_MULTIPLAYER_CLIENT_STARTED: 24, 1 { _base: 0 }
+    SIMCONNECT_RECV_EVENT_MULTIPLAYER_SESSION_ENDED: 24, 1 { _base: 0 }
+    SIMCONNECT_RECV_EVENT_EX1: 40, 1 {
+        _base: 0,
+        uGroupID: 12,
+        uEventID: 16,
+        dwData0: 20,
+        dwData1: 24,
+        dwData2: 28,
+        dwData3: 32,
+        dwData4: 36,
+    }
+    SIMCONNECT_DATA_RACE_RESULT: 1080, 1 {
+        dwNumberOfRacers: 0,
+        MissionGUID: 4,
+        szPlayerName: 20,
+        szSessionType: 280,
+        szAircraft: 540,
+        szPlayerRole: 800,
+        fTotalTime: 1060,
+        fPenaltyTime: 1068,
+        dwIsDisqualified: 1076,
+    }
+    SIMCONNECT_RECV_EVENT_RACE_END: 1108, 1 { _base: 0, dwRacerNumber: 24, RacerData: 28 }
+    SIMCONNECT_RECV_EVENT_RACE_LAP: 1108, 1 { _base: 0, dwLapIndex: 24, RacerData: 28 }
+    SIMCONNECT_RECV_SIMOBJECT_DATA: 44, 1 {
+        _base: 0,
+        dwRequestID: 12,
+        dwObjectID: 16,
+        dwDefineID: 20,
+        dwFlags: 24,
+        dwentrynumber: 28,
+        dwoutof: 32,
+        dwDefineCount: 36,
+        dwData: 40,
+    }
+    SIMCONNECT_RECV_SIMOBJECT_DATA_BYTYPE: 44, 1 { _base: 0 }
+    SIMCONNECT_RECV_CLIENT_DATA: 44, 1 { _base: 0 }
+    SIMCONNECT_RECV_WEATHER_OBSERVATION: 17, 1 { _base: 0, dwRequestID: 12, szMetar: 16 }
+    SIMCONNECT_RECV_CLOUD_STATE: 21, 1 { _base: 0, dwRequestID: 12, dwArraySize: 16, rgbData: 20 }
+    SIMCONNECT_RECV_ASSIGNED_OBJECT_ID: 20, 1 { _base: 0, dwRequestID: 12, dwObjectID: 16 }
+    SIMCONNECT_RECV_RESERVED_KEY: 92, 1 { _base: 0, szChoiceReserved: 12, szReservedKey: 42 }
+    SIMCONNECT_RECV_SYSTEM_STATE: 284, 1 {
+        _base: 0,
+        dwRequestID: 12,
+        dwInteger: 16,
+        fFloat: 20,
+        szString: 24,
+    }
+    SIMCONNECT_RECV_CUSTOM_ACTION: 45, 1 {
+        _base: 0,
+        guidInstanceId: 24,
+        dwWaitForCompletion: 40,
+        szPayLoad: 44,
+    }
+    SIMCONNECT_RECV_EVENT_WEATHER_MODE: 24, 1 { _base: 0 }
+    SIMCONNECT_RECV_FACILITIES_LIST: 28, 1 {
+        _base: 0,
+        dwRequestID: 12,
+        dwArraySize: 16,
+        dwEntryNumber: 20,
+        dwOutOf: 24,
+    }
+    SIMCONNECT_RECV_LIST_TEMPLATE: 28, 1 {
+        _base: 0,
+        dwRequestID: 12,
+        dwArraySize: 16,
+        dwEntryNumber: 20,
+        dwOutOf: 24,
+    }
+    SIMCONNECT_DATA_FACILITY_AIRPORT: 33, 1 {
+        Ident: 0,
+        Region: 6,
+        Latitude: 9,
+        Longitude: 17,
+        Altitude: 25,
+    }
+    SIMCONNECT_RECV_AIRPORT_LIST: 61, 1 { _base: 0, rgData: 28 }
+    SIMCONNECT_DATA_FACILITY_WAYPOINT: 37, 1 { _base: 0, fMagVar: 33 }
+    SIMCONNECT_RECV_WAYPOINT_LIST: 65, 1 { _base: 0, rgData: 28 }
+    SIMCONNECT_DATA_FACILITY_NDB: 41, 1 { _base: 0, fFrequency: 37 }
+    SIMCONNECT_RECV_NDB_LIST: 69, 1 { _base: 0, rgData: 28 }
+    SIMCONNECT_DATA_FACILITY_VOR: 77, 1 {
+        _base: 0,
+        Flags: 41,
+        fLocalizer: 45,
+        GlideLat: 49,
+        GlideLon: 57,
+        GlideAlt: 65,
+        fGlideSlopeAngle: 73,
+    }
+    SIMCONNECT_RECV_VOR_LIST: 105, 1 { _base: 0, rgData: 28 }
+    SIMCONNECT_RECV_FACILITY_DATA: 44, 1 {
+        _base: 0,
+        UserRequestId: 12,
+        UniqueRequestId: 16,
+        ParentUniqueRequestId: 20,
+        Type: 24,
+        IsListItem: 28,
+        ItemIndex: 32,
+        ListSize: 36,
+        Data: 40,
+    }
+    SIMCONNECT_RECV_FACILITY_DATA_END: 16, 1 { _base: 0, RequestId: 12 }
+    SIMCONNECT_DATA_LATLONALT: 24, 1 { Latitude: 0, Longitude: 8, Altitude: 16 }
+    SIMCONNECT_DATA_PBH: 12, 1 { Pitch: 0, Bank: 4, Heading: 8 }
+    SIMCONNECT_RECV_FACILITY_MINIMAL_LIST: 67, 1 { _base: 0, rgData: 28 }
+    SIMCONNECT_DATA_INITPOSITION: 56, 1 {
+        Latitude: 0,
+        Longitude: 8,
+        Altitude: 16,
+        Pitch: 24,
+        Bank: 32,
+        Heading: 40,
+        OnGround: 48,
+        Airspeed: 52,
+    }
+    SIMCONNECT_DATA_MARKERSTATE: 68, 1 { szMarkerName: 0, dwMarkerState: 64 }
+    SIMCONNECT_DATA_WAYPOINT: 44, 1 {
+        Latitude: 0,
+        Longitude: 8,
+        Altitude: 16,
+        Flags: 24,
+        ktsSpeed: 28,
+        percentThrottle: 36,
+    }
+    SIMCONNECT_DATA_XYZ: 24, 1 { x: 0, y: 8, z: 16 }
+    SIMCONNECT_RECV_JETWAY_DATA: 188, 1 { _base: 0, rgData: 28 }
+    SIMCONNECT_RECV_ACTION_CALLBACK: 276, 1 { _base: 0, szActionID: 12, cbRequestId: 272 }
+    SIMCONNECT_RECV_ENUMERATE_INPUT_EVENTS: 104, 1 { _base: 0, rgData: 28 }
+    SIMCONNECT_RECV_GET_INPUT_EVENT: 24, 1 { _base: 0, dwRequestID: 12, eType: 16, Value: 20 }
+    SIMCONNECT_RECV_SUBSCRIBE_INPUT_EVENT: 28, 1 { _base: 0, Hash: 12, eType: 20, Value: 24 }
+    SIMCONNECT_RECV_ENUMERATE_INPUT_EVENT_PARAMS: 280, 1 { _base: 0, Hash: 12, Value: 20 }
+    SIMCONNECT_RECV_CONTROLLERS_LIST: 304, 1 { _base: 0, rgData: 28 }
+}
@@ -1,11 +1,58 @@
 #![allow(non_upper_case_globals)]
 #![allow(non_camel_case_types)]
 #![allow(non_snake_case)]
+
+/// C types with the sizes they have on Windows, used by the bindings on every
+/// target so `DWORD` stays 32 bits wide.
+pub mod ctypes {
+    pub use std::os::raw::{
+        c_char, c_double, c_float, c_int, c_longlong, c_schar, c_short, c_uchar, c_uint,
+        c_ulonglong, c_ushort, c_void,
+    };
+    pub type c_long = i32;
+    pub type c_ulong = u32;
+}
+
+// generated code, the function table of the dynamic bindings trips a few lints
+#[allow(clippy::type_complexity, clippy::too_many_arguments, clippy::missing_safety_doc)]
 mod ffi {
     include!(concat!(env!("OUT_DIR"), "/bindings.rs"));
 }
 pub use ffi::*;
 
+/// Version of the SimConnect SDK the bindings were generated from, selected
+/// with the `sdk_*` features.
+pub const SDK_VERSION: &str = env!("SIMCONNECT_SDK_VERSION");
+
+#[cfg(feature = "dynamic")]
+pub use libloading;
+
+/// File name of the SimConnect library loaded by [`SimConnectLibrary::load`].
+#[cfg(feature = "dynamic")]
+pub const SIMCONNECT_DLL: &str = "SimConnect.dll";
+
+#[cfg(all(feature = "dynamic", not(feature = "types_only")))]
+impl SimConnectLibrary {
+
+    /// Loads SimConnect.dll from the standard library search path, which
+    /// starts with the directory of the executable.
+    ///
+    /// Functions missing from the loaded library, e.g. `_EX1` functions on an
+    /// older SDK, are an `Err` in their field of the table, calling them
+    /// through the methods of the same name panics.
+    ///
+    /// # Safety
+    ///
+    /// Loading a library runs its initialisation code, see
+    /// [`libloading::Library::new`].
+    pub unsafe fn load() -> Result<Self, libloading::Error> {
+        Self::new(SIMCONNECT_DLL)
+    }
+}
+
+#[cfg(test)]
+mod layout;
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -16,4 +63,12 @@ mod tests {
         assert_eq!(SIMCONNECT_UNUSED, u32::MAX);
         Ok(())
     }
+
+    #[test]
+    pub fn sdk_version() -> Result<()> {
+        // whichever sdk the features selected, it has to be a vendored one
+        let sdk = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("sdk").join(SDK_VERSION);
+        assert!(sdk.join("include").join("SimConnect.h").is_file(), "SDK {SDK_VERSION} isn't vendored");
+        Ok(())
+    }
 }
\ No newline at end of file
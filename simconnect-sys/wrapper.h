@@ -18,6 +18,9 @@ typedef HANDLE HWND;
 typedef LONG HRESULT;
 typedef /* __nullterminated */ CONST CHAR *LPCSTR;
 
+// SimConnect.h is only meant for the Windows data model, where long is 32 bits
+static_assert(sizeof(DWORD) == 4, "SimConnect.h must be parsed for a Windows target");
+
 typedef struct _GUID {
   unsigned long  Data1;
   unsigned short Data2;
@@ -0,0 +1,21 @@
+use std::env;
+
+// sets the same `sdk_at_least_*` cfgs as simconnect-sys, from the sdk version
+// it selected, so wrappers of calls missing from older sdks are left out
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+
+    let sdk_version = env::var("DEP_SIMCONNECT_SDK_VERSION").expect("simconnect-sys didn't report its SDK version");
+    let sdk_versions = env::var("DEP_SIMCONNECT_SDK_VERSIONS").expect("simconnect-sys didn't report its SDK versions");
+    for version in sdk_versions.split(',').filter(|version| !version.is_empty()) {
+        let cfg = format!("sdk_at_least_{}", version.replace('.', "_"));
+        println!("cargo:rustc-check-cfg=cfg({cfg})");
+        if parse_version(version) <= parse_version(&sdk_version) {
+            println!("cargo:rustc-cfg={cfg}");
+        }
+    }
+}
+
+fn parse_version(version: &str) -> Vec<u32> {
+    version.split('.').map(|n| n.parse().unwrap_or(0)).collect()
+}
@@ -0,0 +1,50 @@
+use std::ffi::CString;
+
+use crate::connection::SimConnect;
+use crate::error::Result;
+use crate::ids::RequestId;
+use crate::request::Request;
+use crate::types::InitPosition;
+
+impl SimConnect {
+
+    /// Creates an aircraft that isn't controlled by ATC at `position`.
+    ///
+    /// The new object id is sent back in a `SIMCONNECT_RECV_ASSIGNED_OBJECT_ID`
+    /// for `request_id`.
+    pub fn ai_create_non_atc_aircraft(
+        &self,
+        container_title: &str,
+        tail_number: &str,
+        position: &InitPosition,
+        request_id: RequestId,
+    ) -> Result<()> {
+        let c_title = CString::new(container_title)?;
+        let c_tail = CString::new(tail_number)?;
+        self.send(Request::AiCreateNonAtcAircraft {
+            container_title: &c_title,
+            tail_number: &c_tail,
+            position,
+            request_id,
+        })
+    }
+
+    /// Creates a simulation object other than an aircraft at `position`.
+    ///
+    /// The new object id is sent back in a `SIMCONNECT_RECV_ASSIGNED_OBJECT_ID`
+    /// for `request_id`.
+    pub fn ai_create_simulated_object(
+        &self,
+        container_title: &str,
+        position: &InitPosition,
+        request_id: RequestId,
+    ) -> Result<()> {
+        let c_title = CString::new(container_title)?;
+        self.send(Request::AiCreateSimulatedObject { container_title: &c_title, position, request_id })
+    }
+
+    /// Removes an object created by this client.
+    pub fn ai_remove_object(&self, object_id: u32, request_id: RequestId) -> Result<()> {
+        self.send(Request::AiRemoveObject { object_id, request_id })
+    }
+}
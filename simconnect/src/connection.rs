@@ -0,0 +1,400 @@
+use std::cell::{RefCell, RefMut};
+use std::collections::VecDeque;
+use std::net::ToSocketAddrs;
+#[cfg(windows)]
+use std::ptr::NonNull;
+use std::sync::Arc;
+use std::time::Duration;
+
+use simconnect_sys::*;
+
+#[cfg(windows)]
+use crate::ffi::{self, Ffi};
+use crate::error::{Exception, Result};
+use crate::ids::Ids;
+use crate::net::{self, Net, Protocol};
+use crate::record::{self, Recorder, Replay};
+use crate::recv::Recv;
+use crate::request::Request;
+
+// number of requests remembered for exception reporting, exceptions normally
+// arrive within a few dispatches of the request that caused them
+const SEND_RECORD_LEN: usize = 512;
+
+// how often connections without an event handle check for messages
+#[cfg_attr(not(windows), allow(dead_code))]
+pub(crate) const POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+/// An open connection to SimConnect.
+///
+/// Connections are made through SimConnect.dll on Windows, or by speaking the
+/// SimConnect network protocol directly with [`SimConnect::connect`], which
+/// also works on other platforms. The connection is closed when it is
+/// dropped, use [`SimConnect::close`] to observe any error returned while
+/// closing.
+///
+/// `SimConnect` is `Send` so it can be moved to a dedicated dispatch thread,
+/// but it is not `Sync`, all calls on a connection must come from the thread
+/// that currently owns it.
+///
+/// Every request sent through the connection is recorded with its packet id,
+/// so exceptions reported by SimConnect can be traced back to the call that
+/// caused them, see [`SimConnect::exception`].
+#[derive(Debug)]
+pub struct SimConnect {
+    backend: Backend,
+    sent: RefCell<VecDeque<(u32, String)>>,
+    recorder: RefCell<Option<Recorder>>,
+    ids: Ids,
+}
+
+#[derive(Debug)]
+pub(crate) enum Backend {
+    #[cfg(windows)]
+    Ffi(Ffi),
+    Net(Net),
+    Replay(Replay),
+}
+
+// SAFETY: a SimConnect handle is not tied to the thread that opened it, it is
+// only unsafe to use the same handle from multiple threads concurrently, which
+// is prevented by `SimConnect` not being `Sync`.
+unsafe impl Send for SimConnect {}
+
+/// Keeps a callback registered with [`SimConnect::notify`] alive.
+// the fields are only held to be dropped
+#[derive(Debug)]
+#[allow(dead_code)]
+pub(crate) enum Notifier {
+    #[cfg(windows)]
+    Waiter(ffi::Waiter),
+    Net(net::Notifier),
+    Replay(record::ReplayNotifier),
+}
+
+impl SimConnect {
+
+    /// Opens a connection to SimConnect using the default options.
+    ///
+    /// `name` is the client name reported to the simulator.
+    pub fn open(name: &str) -> Result<Self> {
+        OpenOptions::new().open(name)
+    }
+
+    /// Returns a new set of [`OpenOptions`].
+    pub fn options() -> OpenOptions {
+        OpenOptions::new()
+    }
+
+    /// Connects to a SimConnect server at `addr` over TCP, speaking the
+    /// network protocol of Microsoft Flight Simulator without SimConnect.dll.
+    ///
+    /// `addr` is the address and port the simulator was configured to listen
+    /// on in its `SimConnect.xml`, see [`OpenOptions::connect`] to pick the
+    /// protocol version.
+    ///
+    /// ```no_run
+    /// use simconnect::SimConnect;
+    ///
+    /// let sc = SimConnect::connect("192.168.1.10:500", "Example")?;
+    /// # Ok::<(), simconnect::Error>(())
+    /// ```
+    pub fn connect(addr: impl ToSocketAddrs, name: &str) -> Result<Self> {
+        OpenOptions::new().connect(addr, name)
+    }
+
+    fn with_backend(backend: Backend) -> Self {
+        Self {
+            backend,
+            sent: RefCell::new(VecDeque::with_capacity(SEND_RECORD_LEN)),
+            recorder: RefCell::new(None),
+            ids: Ids::default(),
+        }
+    }
+
+    pub(crate) fn with_replay(replay: Replay) -> Self {
+        Self::with_backend(Backend::Replay(replay))
+    }
+
+    /// Takes ownership of a raw SimConnect handle.
+    ///
+    /// # Safety
+    ///
+    /// `handle` must be a valid handle returned by `SimConnect_Open` that is
+    /// not owned by anything else, it will be closed when the returned
+    /// connection is dropped.
+    #[cfg(windows)]
+    pub unsafe fn from_raw(handle: HANDLE) -> Option<Self> {
+        NonNull::new(handle).map(|handle| Self::with_backend(Backend::Ffi(Ffi::from_raw(handle))))
+    }
+
+    /// Returns the raw SimConnect handle, without giving up ownership.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the connection was made with [`SimConnect::connect`] or
+    /// [`SimConnect::replay`], which have no SimConnect handle.
+    #[cfg(windows)]
+    pub fn as_raw(&self) -> HANDLE {
+        match &self.backend {
+            Backend::Ffi(ffi) => ffi.as_raw(),
+            Backend::Net(_) | Backend::Replay(_) => panic!("only SimConnect.dll connections have a SimConnect handle"),
+        }
+    }
+
+    /// Consumes the connection, returning the raw SimConnect handle.
+    ///
+    /// The caller becomes responsible for calling `SimConnect_Close`. If the
+    /// connection was opened with an event created by this crate, the event
+    /// is leaked as SimConnect keeps signalling it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the connection was made with [`SimConnect::connect`] or
+    /// [`SimConnect::replay`], which have no SimConnect handle.
+    #[cfg(windows)]
+    pub fn into_raw(self) -> HANDLE {
+        match self.backend {
+            Backend::Ffi(ffi) => ffi.into_raw(),
+            Backend::Net(_) | Backend::Replay(_) => panic!("only SimConnect.dll connections have a SimConnect handle"),
+        }
+    }
+
+    /// Closes the connection, returning any error from `SimConnect_Close`.
+    pub fn close(self) -> Result<()> {
+        let recording = self.stop_recording();
+        match self.backend {
+            #[cfg(windows)]
+            Backend::Ffi(ffi) => ffi.close()?,
+            Backend::Net(net) => net.close()?,
+            Backend::Replay(_) => {}
+        }
+        recording
+    }
+
+    /// Returns the packet id of the last request sent on this connection.
+    pub fn last_send_id(&self) -> Result<u32> {
+        match &self.backend {
+            #[cfg(windows)]
+            Backend::Ffi(ffi) => ffi.last_send_id(),
+            Backend::Net(net) => net.last_send_id(),
+            Backend::Replay(replay) => replay.last_send_id(),
+        }
+    }
+
+    /// Returns a description of the request sent with packet id `send_id`,
+    /// if it is still in the send record.
+    pub fn sent_call(&self, send_id: u32) -> Option<String> {
+        self.sent.borrow().iter()
+            .rev()
+            .find(|(id, _)| *id == send_id)
+            .map(|(_, call)| call.clone())
+    }
+
+    /// Converts a received exception into an [`Exception`], naming the call
+    /// that caused it when it was sent through this connection.
+    pub fn exception(&self, e: &SIMCONNECT_RECV_EXCEPTION) -> Exception {
+        let exception = Exception::new(e.dwException, e.dwSendID, e.dwIndex);
+        match self.sent_call(e.dwSendID) {
+            Some(call) => exception.with_call(call),
+            None => exception,
+        }
+    }
+
+    pub(crate) fn ids(&self) -> &Ids {
+        &self.ids
+    }
+
+    pub(crate) fn backend(&self) -> &Backend {
+        &self.backend
+    }
+
+    pub(crate) fn recorder(&self) -> RefMut<'_, Option<Recorder>> {
+        self.recorder.borrow_mut()
+    }
+
+    /// Writes a received packet to the recording in progress, if any.
+    pub(crate) fn record_received(&self, packet: &[u8]) {
+        if let Some(recorder) = self.recorder().as_mut() {
+            recorder.received(packet);
+        }
+    }
+
+    /// Adds a sent request to the send record, and to the recording in
+    /// progress if any.
+    pub(crate) fn record_sent(&self, send_id: u32, call: String) {
+        if let Some(recorder) = self.recorder().as_mut() {
+            recorder.sent(send_id, &call);
+        }
+        let mut sent = self.sent.borrow_mut();
+        if sent.len() == SEND_RECORD_LEN {
+            sent.pop_front();
+        }
+        sent.push_back((send_id, call));
+    }
+
+    /// Attaches the recorded call to a received exception.
+    pub(crate) fn with_sent_call(&self, recv: Recv) -> Recv {
+        match recv {
+            Recv::Exception(e) => match self.sent_call(e.send_id()) {
+                Some(call) => Recv::Exception(e.with_call(call)),
+                None => Recv::Exception(e),
+            },
+            recv => recv,
+        }
+    }
+
+    /// Calls `f` from a background thread whenever a message may be ready,
+    /// until the returned [`Notifier`] is dropped.
+    #[cfg_attr(not(feature = "async"), allow(dead_code))]
+    pub(crate) fn notify(&self, f: Arc<dyn Fn() + Send + Sync>) -> Result<Notifier> {
+        match &self.backend {
+            #[cfg(windows)]
+            Backend::Ffi(ffi) => ffi.notify(f).map(Notifier::Waiter),
+            Backend::Net(net) => Ok(Notifier::Net(net.notify(f))),
+            Backend::Replay(replay) => replay.notify(f).map(Notifier::Replay),
+        }
+    }
+
+    /// Blocks until a message may be ready, or `timeout` elapses.
+    ///
+    /// Connections opened without an event to wait on fall back to sleeping
+    /// for a short interval, as they have no way to be woken up.
+    pub(crate) fn wait(&self, timeout: Option<Duration>) {
+        match &self.backend {
+            #[cfg(windows)]
+            Backend::Ffi(ffi) => ffi.wait(timeout),
+            Backend::Net(net) => net.wait(timeout),
+            Backend::Replay(replay) => replay.wait(timeout),
+        }
+    }
+
+    /// Sends `request`, checking the result and recording the packet id
+    /// along with a description of the call.
+    pub(crate) fn send(&self, request: Request<'_>) -> Result<()> {
+        match &self.backend {
+            #[cfg(windows)]
+            Backend::Ffi(ffi) => ffi.send(&request)?,
+            Backend::Net(net) => net.send(&request)?,
+            // replays only answer with what was recorded
+            Backend::Replay(_) => return Ok(()),
+        }
+
+        // the request was sent, failing to record it shouldn't fail the call
+        if let Ok(send_id) = self.last_send_id() {
+            self.record_sent(send_id, format!("{}({})", request.name(), request));
+        }
+        Ok(())
+    }
+}
+
+/// Options used to open a [`SimConnect`] connection.
+///
+/// ```no_run
+/// use simconnect::SimConnect;
+///
+/// let sc = SimConnect::options()
+///     .config_index(1)
+///     .open("Example")?;
+/// # Ok::<(), simconnect::Error>(())
+/// ```
+#[derive(Debug, Clone)]
+pub struct OpenOptions {
+    #[cfg(windows)]
+    window: HWND,
+    #[cfg(windows)]
+    user_event: u32,
+    #[cfg(windows)]
+    event_handle: HANDLE,
+    config_index: u32,
+    protocol: Protocol,
+}
+
+impl Default for OpenOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl OpenOptions {
+
+    /// Returns the default options, no window, no event handle, the default
+    /// configuration index of 0 and the Microsoft Flight Simulator protocol.
+    pub fn new() -> Self {
+        Self {
+            #[cfg(windows)]
+            window: std::ptr::null_mut(),
+            #[cfg(windows)]
+            user_event: 0,
+            #[cfg(windows)]
+            event_handle: std::ptr::null_mut(),
+            config_index: 0,
+            protocol: Protocol::default(),
+        }
+    }
+
+    /// Posts the Win32 message `user_event` to `window` whenever a message
+    /// is ready to be dispatched.
+    #[cfg(windows)]
+    pub fn window(&mut self, window: HWND, user_event: u32) -> &mut Self {
+        self.window = window;
+        self.user_event = user_event;
+        self
+    }
+
+    /// Signals the Win32 event `event_handle` whenever a message is ready to
+    /// be dispatched.
+    ///
+    /// The event handle is not owned by the connection and must outlive it.
+    #[cfg(windows)]
+    pub fn event_handle(&mut self, event_handle: HANDLE) -> &mut Self {
+        self.event_handle = event_handle;
+        self
+    }
+
+    /// Selects the connection configuration from `SimConnect.cfg`, use
+    /// `SIMCONNECT_OPEN_CONFIGINDEX_LOCAL` to force a local connection.
+    pub fn config_index(&mut self, config_index: u32) -> &mut Self {
+        self.config_index = config_index;
+        self
+    }
+
+    /// Selects the protocol version spoken by network connections, the
+    /// default is [`Protocol::Msfs`].
+    ///
+    /// Only used by [`OpenOptions::connect`], and by
+    /// [`OpenOptions::open`] outside of Windows.
+    pub fn protocol(&mut self, protocol: Protocol) -> &mut Self {
+        self.protocol = protocol;
+        self
+    }
+
+    /// Opens a connection to SimConnect using these options.
+    ///
+    /// On Windows this goes through SimConnect.dll. Unless a window or event
+    /// handle was given, the connection creates its own event so
+    /// [`SimConnect::recv`] can block without polling.
+    ///
+    /// Elsewhere the address is read from the selected configuration of a
+    /// `SimConnect.cfg` next to the executable or in the working directory,
+    /// as SimConnect.dll would, and the connection is made with
+    /// [`OpenOptions::connect`].
+    pub fn open(&self, name: &str) -> Result<SimConnect> {
+        #[cfg(windows)]
+        {
+            let ffi = Ffi::open(name, self.window, self.user_event, self.event_handle, self.config_index)?;
+            Ok(SimConnect::with_backend(Backend::Ffi(ffi)))
+        }
+        #[cfg(not(windows))]
+        {
+            self.connect(net::config_address(self.config_index)?, name)
+        }
+    }
+
+    /// Connects to a SimConnect server at `addr` over TCP using these
+    /// options, see [`SimConnect::connect`].
+    pub fn connect(&self, addr: impl ToSocketAddrs, name: &str) -> Result<SimConnect> {
+        let net = Net::connect(addr, name, self.protocol)?;
+        Ok(SimConnect::with_backend(Backend::Net(net)))
+    }
+}
@@ -0,0 +1,128 @@
+use std::ffi::CString;
+
+use crate::connection::SimConnect;
+use crate::error::Result;
+use crate::flags::{DataRequestFlags, DataSetFlags};
+use crate::ids::{DefineId, RequestId};
+use crate::request::Request;
+use crate::types::{DataType, Period, SimObjectType};
+
+/// Optional parameters of [`SimConnect::request_data_on_sim_object_with`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RequestOptions {
+
+    /// Whether to only send changed data and whether to tag it.
+    pub flags: DataRequestFlags,
+
+    /// Number of periods to wait before the first data is sent.
+    pub origin: u32,
+
+    /// Number of periods between each transmission.
+    pub interval: u32,
+
+    /// Number of transmissions before the request ends, 0 for no limit.
+    pub limit: u32,
+}
+
+impl SimConnect {
+
+    /// Adds the simulation variable `name` in `units` to the data definition
+    /// `define_id`.
+    ///
+    /// `units` may be empty for string and structure types, `datum_id` is
+    /// only used with tagged data and should otherwise be `SIMCONNECT_UNUSED`.
+    pub fn add_to_data_definition(
+        &self,
+        define_id: DefineId,
+        name: &str,
+        units: &str,
+        datum_type: DataType,
+        epsilon: f32,
+        datum_id: u32,
+    ) -> Result<()> {
+        let c_name = CString::new(name)?;
+        let c_units = CString::new(units)?;
+        self.send(Request::AddToDataDefinition {
+            define_id,
+            name: &c_name,
+            units: &c_units,
+            data_type: datum_type,
+            epsilon,
+            datum_id,
+        })
+    }
+
+    /// Removes every entry from the data definition `define_id`.
+    ///
+    /// An id allocated with [`SimConnect::new_define_id`] is released and may
+    /// be handed out again.
+    pub fn clear_data_definition(&self, define_id: DefineId) -> Result<()> {
+        self.send(Request::ClearDataDefinition { define_id })?;
+        self.release_define_id(define_id);
+        Ok(())
+    }
+
+    /// Requests the data definition `define_id` for `object_id` every `period`.
+    pub fn request_data_on_sim_object(
+        &self,
+        request_id: RequestId,
+        define_id: DefineId,
+        object_id: u32,
+        period: Period,
+    ) -> Result<()> {
+        self.request_data_on_sim_object_with(request_id, define_id, object_id, period, RequestOptions::default())
+    }
+
+    /// Requests the data definition `define_id` for `object_id` every
+    /// `period`, with flags and timing given by `options`.
+    ///
+    /// Stopping a request with [`Period::Never`] releases an id allocated
+    /// with [`SimConnect::new_request_id`], so it may be handed out again.
+    pub fn request_data_on_sim_object_with(
+        &self,
+        request_id: RequestId,
+        define_id: DefineId,
+        object_id: u32,
+        period: Period,
+        options: RequestOptions,
+    ) -> Result<()> {
+        self.send(Request::RequestDataOnSimObject { request_id, define_id, object_id, period, options })?;
+        if period == Period::Never {
+            self.release_request_id(request_id);
+        }
+        Ok(())
+    }
+
+    /// Requests the data definition `define_id` once for every object of
+    /// `object_type` within `radius_meters` of the user.
+    pub fn request_data_on_sim_object_type(
+        &self,
+        request_id: RequestId,
+        define_id: DefineId,
+        radius_meters: u32,
+        object_type: SimObjectType,
+    ) -> Result<()> {
+        self.send(Request::RequestDataOnSimObjectType { request_id, define_id, radius_meters, object_type })
+    }
+
+    /// Sets the data definition `define_id` on `object_id` from `data`.
+    ///
+    /// `data` must be laid out as described by the data definition, or as
+    /// tagged data when `flags` contains [`DataSetFlags::TAGGED`].
+    pub fn set_data_on_sim_object(
+        &self,
+        define_id: DefineId,
+        object_id: u32,
+        flags: DataSetFlags,
+        data: &[u8],
+    ) -> Result<()> {
+        self.send(Request::SetDataOnSimObject { define_id, object_id, flags, data })
+    }
+
+    /// Requests the system state `state`, answered with a
+    /// `SIMCONNECT_RECV_SYSTEM_STATE` for `request_id`.
+    pub fn request_system_state(&self, request_id: RequestId, state: &str) -> Result<()> {
+        let c_state = CString::new(state)?;
+        self.send(Request::RequestSystemState { request_id, state: &c_state })
+    }
+}
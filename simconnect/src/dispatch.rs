@@ -0,0 +1,254 @@
+#[cfg(windows)]
+use std::any::Any;
+#[cfg(windows)]
+use std::ffi::c_void;
+#[cfg(windows)]
+use std::panic::{self, AssertUnwindSafe};
+use std::time::{Duration, Instant};
+
+#[cfg(windows)]
+use simconnect_sys::*;
+
+use crate::connection::{Backend, SimConnect};
+#[cfg(windows)]
+use crate::error::check;
+#[cfg(windows)]
+use crate::ffi::sys;
+use crate::error::{Error, Result};
+use crate::recv::Recv;
+use crate::record::Entry;
+
+impl SimConnect {
+
+    /// Returns the next queued message, or `None` if there is nothing queued.
+    ///
+    /// `SimConnect_GetNextDispatch` reports an empty queue as `E_FAIL`, which
+    /// is returned here as `Ok(None)`, any other failure is an error. Network
+    /// connections return [`Error::Closed`] once the server has hung up and
+    /// every message before that was received, replayed connections once
+    /// every recorded message was received.
+    pub fn try_recv(&self) -> Result<Option<Recv>> {
+        let record = |packet: &[u8]| self.record_received(packet);
+        let recv = match self.backend() {
+            #[cfg(windows)]
+            Backend::Ffi(ffi) => ffi.try_recv(record)?,
+            Backend::Net(net) => net.try_recv(record)?,
+            Backend::Replay(replay) => loop {
+                match replay.next()? {
+                    Some(Entry::Received { packet, .. }) => {
+                        record(&packet);
+                        break Some(Recv::decode(&packet)?);
+                    }
+                    Some(Entry::Sent { send_id, call, .. }) => self.record_sent(send_id, call),
+                    None => break None,
+                }
+            },
+        };
+        Ok(recv.map(|recv| self.with_sent_call(recv)))
+    }
+
+    /// Blocks until a message is received.
+    pub fn recv(&self) -> Result<Recv> {
+        loop {
+            if let Some(recv) = self.try_recv()? {
+                return Ok(recv);
+            }
+            self.wait(None);
+        }
+    }
+
+    /// Blocks until a message is received or `timeout` elapses, returning
+    /// `None` on timeout.
+    pub fn recv_timeout(&self, timeout: Duration) -> Result<Option<Recv>> {
+        let deadline = Instant::now() + timeout;
+        loop {
+            if let Some(recv) = self.try_recv()? {
+                return Ok(Some(recv));
+            }
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            if remaining.is_zero() {
+                return Ok(None);
+            }
+            self.wait(Some(remaining));
+        }
+    }
+
+    /// Calls `f` for every queued message using `SimConnect_CallDispatch`,
+    /// or by receiving until the queue is empty on network and replayed
+    /// connections.
+    ///
+    /// A panic in `f` stops the remaining messages from being handed to it
+    /// and is resumed once `SimConnect_CallDispatch` has returned, so it never
+    /// unwinds through SimConnect. A message that fails to decode is skipped
+    /// and the first such error is returned after dispatching.
+    ///
+    /// ```no_run
+    /// use simconnect::{Recv, SimConnect};
+    ///
+    /// struct App { quit: bool }
+    ///
+    /// let sc = SimConnect::open("Example")?;
+    /// let mut app = App { quit: false };
+    /// while !app.quit {
+    ///     sc.dispatch(|recv| if let Recv::Quit = recv {
+    ///         app.quit = true;
+    ///     })?;
+    ///     std::thread::sleep(std::time::Duration::from_millis(10));
+    /// }
+    /// # Ok::<(), simconnect::Error>(())
+    /// ```
+    pub fn dispatch<F: FnMut(Recv)>(&self, f: F) -> Result<()> {
+        match self.backend() {
+            #[cfg(windows)]
+            Backend::Ffi(ffi) => self.call_dispatch(ffi.as_raw(), f),
+            Backend::Net(_) | Backend::Replay(_) => self.dispatch_queued(f),
+        }
+    }
+
+    #[cfg(windows)]
+    fn call_dispatch<F: FnMut(Recv)>(&self, handle: HANDLE, mut f: F) -> Result<()> {
+        let mut context = DispatchContext { sc: self, f: &mut f, error: None, panic: None };
+        let hr = unsafe {
+            sys!(SimConnect_CallDispatch(
+                handle,
+                Some(dispatch_proc::<F>),
+                &mut context as *mut DispatchContext<'_, F> as *mut c_void,
+            ))
+        };
+        if let Some(payload) = context.panic {
+            panic::resume_unwind(payload);
+        }
+        check("SimConnect_CallDispatch", hr?)?;
+        context.error.map_or(Ok(()), Err)
+    }
+
+    // dispatch without SimConnect_CallDispatch, matching its handling of
+    // decode errors
+    fn dispatch_queued<F: FnMut(Recv)>(&self, mut f: F) -> Result<()> {
+        let mut error = None;
+        loop {
+            match self.try_recv() {
+                Ok(Some(recv)) => f(recv),
+                Ok(None) => return error.map_or(Ok(()), Err),
+                Err(Error::Decode(e)) => {
+                    error.get_or_insert(Error::Decode(e));
+                }
+                Err(e) => return Err(e),
+            }
+        }
+    }
+
+    /// Returns an iterator that blocks for each message, ending after
+    /// [`Recv::Quit`], a failed `HRESULT` or a closed connection.
+    ///
+    /// ```no_run
+    /// use simconnect::{Recv, SimConnect};
+    ///
+    /// let sc = SimConnect::open("Example")?;
+    /// for recv in sc.iter() {
+    ///     if let Recv::Event(event) = recv? {
+    ///         println!("event {}: {}", event.event_id, event.data);
+    ///     }
+    /// }
+    /// # Ok::<(), simconnect::Error>(())
+    /// ```
+    pub fn iter(&self) -> Iter<'_> {
+        Iter { sc: self, done: false }
+    }
+
+    /// Returns an iterator over the messages already queued, it never blocks
+    /// and ends as soon as the queue is empty.
+    pub fn try_iter(&self) -> TryIter<'_> {
+        TryIter { sc: self, done: false }
+    }
+}
+
+/// Blocking iterator over received messages, see [`SimConnect::iter`].
+#[derive(Debug)]
+pub struct Iter<'a> {
+    sc: &'a SimConnect,
+    done: bool,
+}
+
+impl Iterator for Iter<'_> {
+    type Item = Result<Recv>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let recv = self.sc.recv();
+        // a failed HRESULT or a closed connection would fail forever, decode
+        // errors are limited to one packet
+        self.done = match &recv {
+            Ok(recv) => matches!(recv, Recv::Quit),
+            Err(e) => e.ends_connection(),
+        };
+        Some(recv)
+    }
+}
+
+/// Non-blocking iterator over queued messages, see [`SimConnect::try_iter`].
+#[derive(Debug)]
+pub struct TryIter<'a> {
+    sc: &'a SimConnect,
+    done: bool,
+}
+
+impl Iterator for TryIter<'_> {
+    type Item = Result<Recv>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let recv = self.sc.try_recv().transpose();
+        self.done = match &recv {
+            Some(Ok(_)) => false,
+            Some(Err(e)) => e.ends_connection(),
+            None => true,
+        };
+        recv
+    }
+}
+
+// state shared with `dispatch_proc` through the `pContext` pointer
+#[cfg(windows)]
+struct DispatchContext<'a, F> {
+    sc: &'a SimConnect,
+    f: &'a mut F,
+    error: Option<Error>,
+    panic: Option<Box<dyn Any + Send>>,
+}
+
+#[cfg(windows)]
+unsafe extern "C" fn dispatch_proc<F: FnMut(Recv)>(
+    data: *mut SIMCONNECT_RECV,
+    cb_data: DWORD,
+    context: *mut c_void,
+) {
+    // SAFETY: `context` is the `DispatchContext` passed to SimConnect_CallDispatch
+    // by `dispatch`, which outlives the call
+    let context = &mut *(context as *mut DispatchContext<'_, F>);
+    if context.panic.is_some() {
+        return;
+    }
+
+    if !data.is_null() {
+        context.sc.record_received(std::slice::from_raw_parts(data as *const u8, cb_data as usize));
+    }
+    let recv = match Recv::from_raw(data, cb_data) {
+        Ok(recv) => context.sc.with_sent_call(recv),
+        Err(e) => {
+            context.error.get_or_insert(e.into());
+            return;
+        },
+    };
+
+    // unwinding into SimConnect is undefined behaviour, hold on to the
+    // panic until we're back in rust
+    let f = &mut *context.f;
+    if let Err(payload) = panic::catch_unwind(AssertUnwindSafe(|| f(recv))) {
+        context.panic = Some(payload);
+    }
+}
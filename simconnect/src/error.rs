@@ -0,0 +1,387 @@
+use std::ffi::NulError;
+use std::fmt;
+
+use simconnect_sys::HRESULT;
+
+use crate::recv::DecodeError;
+
+// generic failure HRESULT, SimConnect.h doesn't define it for us
+#[cfg_attr(not(windows), allow(dead_code))]
+pub(crate) const E_FAIL: HRESULT = 0x80004005_u32 as HRESULT;
+
+/// Result type returned by the safe SimConnect API.
+pub type Result<T, E = Error> = std::result::Result<T, E>;
+
+/// Errors returned by the safe SimConnect API.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+
+    /// A SimConnect function returned a failure `HRESULT`.
+    #[error("{call} failed (HRESULT {hr:#010x})")]
+    Hresult {
+        call: &'static str,
+        hr: HRESULT,
+    },
+
+    /// SimConnect reported an exception for a previous request.
+    #[error(transparent)]
+    Exception(#[from] Exception),
+
+    /// A received packet could not be decoded.
+    #[error(transparent)]
+    Decode(#[from] DecodeError),
+
+    /// A string argument contained an interior nul byte.
+    #[error("string argument contains an interior nul byte")]
+    Nul(#[from] NulError),
+
+    /// The simulator closed the connection, before a response arrived or
+    /// while receiving over the network.
+    #[error("the connection was closed")]
+    Closed,
+
+    /// An operating system error outside of SimConnect.
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+
+    /// A string doesn't fit the fixed size field it is stored in.
+    #[error("string of {len} bytes is longer than the {max} bytes that fit")]
+    StringTooLong {
+        len: usize,
+        max: usize,
+    },
+
+    /// An argument is outside of the values the call accepts.
+    #[error("invalid argument `{name}`: {reason}")]
+    InvalidArgument {
+        name: &'static str,
+        reason: &'static str,
+    },
+
+    /// SimConnect.dll could not be loaded, with the `dynamic` feature.
+    #[cfg(feature = "dynamic")]
+    #[error("failed to load SimConnect.dll: {0}")]
+    Load(#[source] simconnect_sys::libloading::Error),
+
+    /// A SimConnect function can't be called on this connection, as it is
+    /// missing from the SimConnect.dll loaded with the `dynamic` feature, e.g.
+    /// a function added in a newer SDK.
+    #[error("{0} is not available on this connection")]
+    Unavailable(&'static str),
+}
+
+impl Error {
+
+    /// Returns the failing `HRESULT`, if this error was caused by one.
+    pub fn hresult(&self) -> Option<HRESULT> {
+        match self {
+            Error::Hresult { hr, .. } => Some(*hr),
+            _ => None,
+        }
+    }
+
+    // errors that will keep happening on every following receive
+    pub(crate) fn ends_connection(&self) -> bool {
+        match self {
+            Error::Hresult { .. } | Error::Closed | Error::Io(_) | Error::Unavailable(_) => true,
+            #[cfg(feature = "dynamic")]
+            Error::Load(_) => true,
+            _ => false,
+        }
+    }
+}
+
+/// Converts the `HRESULT` returned by `call` into a [`Result`].
+#[cfg_attr(not(windows), allow(dead_code))]
+pub(crate) fn check(call: &'static str, hr: HRESULT) -> Result<()> {
+    // mirrors the SUCCEEDED() macro, S_FALSE and friends are not failures
+    if hr >= 0 {
+        Ok(())
+    } else {
+        Err(Error::Hresult { call, hr })
+    }
+}
+
+/// An exception reported asynchronously by SimConnect
+/// (`SIMCONNECT_RECV_ID_EXCEPTION`).
+///
+/// SimConnect only reports the packet id of the request that failed, the
+/// connection keeps a record of recent requests so [`Exception::call`] can
+/// name the exact API call that caused it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Exception {
+    code: u32,
+    send_id: u32,
+    index: u32,
+    call: Option<String>,
+}
+
+impl Exception {
+
+    /// Index value used when the exception isn't tied to a parameter.
+    pub const UNKNOWN_INDEX: u32 = u32::MAX;
+
+    /// Send id used when the exception isn't tied to a request.
+    pub const UNKNOWN_SEND_ID: u32 = 0;
+
+    /// Creates an exception from the raw `dwException`, `dwSendID` and
+    /// `dwIndex` fields.
+    pub fn new(code: u32, send_id: u32, index: u32) -> Self {
+        Self { code, send_id, index, call: None }
+    }
+
+    /// Attaches a description of the call that caused this exception.
+    pub fn with_call(mut self, call: impl Into<String>) -> Self {
+        self.call = Some(call.into());
+        self
+    }
+
+    /// The raw `SIMCONNECT_EXCEPTION` code.
+    pub fn code(&self) -> u32 {
+        self.code
+    }
+
+    /// The exception kind, or `None` for codes newer than this crate.
+    pub fn kind(&self) -> Option<ExceptionKind> {
+        ExceptionKind::from_code(self.code)
+    }
+
+    /// The packet id of the request that caused the exception.
+    pub fn send_id(&self) -> u32 {
+        self.send_id
+    }
+
+    /// The index of the parameter that caused the exception, if known.
+    ///
+    /// Indices start at 1 for the first parameter after the handle.
+    pub fn index(&self) -> Option<u32> {
+        match self.index {
+            Self::UNKNOWN_INDEX => None,
+            index => Some(index),
+        }
+    }
+
+    /// Description of the API call that caused the exception, if it was
+    /// sent through this crate and is still in the connection's send record.
+    pub fn call(&self) -> Option<&str> {
+        self.call.as_deref()
+    }
+}
+
+impl fmt::Display for Exception {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.kind() {
+            Some(kind) => write!(f, "{}: {}", kind.name(), kind.description())?,
+            None => write!(f, "SIMCONNECT_EXCEPTION {}: unknown exception", self.code)?,
+        }
+        write!(f, " (send id {}", self.send_id)?;
+        if let Some(index) = self.index() {
+            write!(f, ", parameter {}", index)?;
+        }
+        write!(f, ")")?;
+        if let Some(call) = &self.call {
+            write!(f, " in {}", call)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for Exception {}
+
+/// A raw value that isn't one of the values of a `SimConnect.h` enum, the
+/// error of converting to one of its rust enums with `TryFrom`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+#[error("{value} is not a valid {name}")]
+pub struct UnknownValue {
+
+    /// The name of the enum in `SimConnect.h`.
+    pub name: &'static str,
+
+    /// The raw value.
+    pub value: i64,
+}
+
+macro_rules! exception_kinds {
+    ($($variant:ident = $code:literal, $name:literal, $desc:literal;)*) => {
+
+        /// The `SIMCONNECT_EXCEPTION_*` codes defined by `SimConnect.h`.
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        #[repr(u32)]
+        #[non_exhaustive]
+        pub enum ExceptionKind {
+            $($variant = $code,)*
+        }
+
+        impl ExceptionKind {
+
+            /// Returns the kind for a raw `SIMCONNECT_EXCEPTION` code.
+            pub fn from_code(code: u32) -> Option<Self> {
+                match code {
+                    $($code => Some(Self::$variant),)*
+                    _ => None,
+                }
+            }
+
+            /// The name of the constant in `SimConnect.h`.
+            pub fn name(self) -> &'static str {
+                match self {
+                    $(Self::$variant => $name,)*
+                }
+            }
+
+            /// A human readable description, taken from the SDK documentation.
+            pub fn description(self) -> &'static str {
+                match self {
+                    $(Self::$variant => $desc,)*
+                }
+            }
+        }
+
+        impl From<ExceptionKind> for u32 {
+            fn from(kind: ExceptionKind) -> Self {
+                kind as u32
+            }
+        }
+
+        impl TryFrom<u32> for ExceptionKind {
+            type Error = UnknownValue;
+
+            fn try_from(code: u32) -> std::result::Result<Self, UnknownValue> {
+                Self::from_code(code).ok_or(UnknownValue { name: "SIMCONNECT_EXCEPTION", value: code as i64 })
+            }
+        }
+
+        impl TryFrom<i32> for ExceptionKind {
+            type Error = UnknownValue;
+
+            fn try_from(code: i32) -> std::result::Result<Self, UnknownValue> {
+                u32::try_from(code).ok()
+                    .and_then(Self::from_code)
+                    .ok_or(UnknownValue { name: "SIMCONNECT_EXCEPTION", value: code as i64 })
+            }
+        }
+    };
+}
+
+exception_kinds! {
+    None = 0, "SIMCONNECT_EXCEPTION_NONE",
+        "no exception";
+    Error = 1, "SIMCONNECT_EXCEPTION_ERROR",
+        "an unspecific SimConnect error has occurred";
+    SizeMismatch = 2, "SIMCONNECT_EXCEPTION_SIZE_MISMATCH",
+        "the size of the data provided does not match the size required";
+    UnrecognizedId = 3, "SIMCONNECT_EXCEPTION_UNRECOGNIZED_ID",
+        "the client event, request, data definition or object id was not recognized";
+    Unopened = 4, "SIMCONNECT_EXCEPTION_UNOPENED",
+        "communication with the SimConnect server has not been opened";
+    VersionMismatch = 5, "SIMCONNECT_EXCEPTION_VERSION_MISMATCH",
+        "a versioning error has occurred";
+    TooManyGroups = 6, "SIMCONNECT_EXCEPTION_TOO_MANY_GROUPS",
+        "the maximum number of groups allowed has been reached";
+    NameUnrecognized = 7, "SIMCONNECT_EXCEPTION_NAME_UNRECOGNIZED",
+        "the simulation event name is not recognized";
+    TooManyEventNames = 8, "SIMCONNECT_EXCEPTION_TOO_MANY_EVENT_NAMES",
+        "the maximum number of event names allowed has been reached";
+    EventIdDuplicate = 9, "SIMCONNECT_EXCEPTION_EVENT_ID_DUPLICATE",
+        "the event id has been used already";
+    TooManyMaps = 10, "SIMCONNECT_EXCEPTION_TOO_MANY_MAPS",
+        "the maximum number of mappings allowed has been reached";
+    TooManyObjects = 11, "SIMCONNECT_EXCEPTION_TOO_MANY_OBJECTS",
+        "the maximum number of objects allowed has been reached";
+    TooManyRequests = 12, "SIMCONNECT_EXCEPTION_TOO_MANY_REQUESTS",
+        "the maximum number of requests allowed has been reached";
+    WeatherInvalidPort = 13, "SIMCONNECT_EXCEPTION_WEATHER_INVALID_PORT",
+        "an invalid port number was requested";
+    WeatherInvalidMetar = 14, "SIMCONNECT_EXCEPTION_WEATHER_INVALID_METAR",
+        "the METAR string is invalid";
+    WeatherUnableToGetObservation = 15, "SIMCONNECT_EXCEPTION_WEATHER_UNABLE_TO_GET_OBSERVATION",
+        "unable to get the weather observation";
+    WeatherUnableToCreateStation = 16, "SIMCONNECT_EXCEPTION_WEATHER_UNABLE_TO_CREATE_STATION",
+        "unable to create the weather station";
+    WeatherUnableToRemoveStation = 17, "SIMCONNECT_EXCEPTION_WEATHER_UNABLE_TO_REMOVE_STATION",
+        "unable to remove the weather station";
+    InvalidDataType = 18, "SIMCONNECT_EXCEPTION_INVALID_DATA_TYPE",
+        "the data type requested does not apply to the type of data requested";
+    InvalidDataSize = 19, "SIMCONNECT_EXCEPTION_INVALID_DATA_SIZE",
+        "the size of the data provided is not what is expected";
+    DataError = 20, "SIMCONNECT_EXCEPTION_DATA_ERROR",
+        "a generic data error has occurred";
+    InvalidArray = 21, "SIMCONNECT_EXCEPTION_INVALID_ARRAY",
+        "an invalid array has been sent to SimConnect_SetDataOnSimObject";
+    CreateObjectFailed = 22, "SIMCONNECT_EXCEPTION_CREATE_OBJECT_FAILED",
+        "the attempt to create an AI object failed";
+    LoadFlightplanFailed = 23, "SIMCONNECT_EXCEPTION_LOAD_FLIGHTPLAN_FAILED",
+        "the specified flight plan could not be found, or did not load correctly";
+    OperationInvalidForObjectType = 24, "SIMCONNECT_EXCEPTION_OPERATION_INVALID_FOR_OBJECT_TYPE",
+        "the operation requested does not apply to the object type";
+    IllegalOperation = 25, "SIMCONNECT_EXCEPTION_ILLEGAL_OPERATION",
+        "the AI operation requested cannot be completed";
+    AlreadySubscribed = 26, "SIMCONNECT_EXCEPTION_ALREADY_SUBSCRIBED",
+        "the client has already subscribed to that event";
+    InvalidEnum = 27, "SIMCONNECT_EXCEPTION_INVALID_ENUM",
+        "the type enum value is unknown";
+    DefinitionError = 28, "SIMCONNECT_EXCEPTION_DEFINITION_ERROR",
+        "there is a problem with a data definition";
+    DuplicateId = 29, "SIMCONNECT_EXCEPTION_DUPLICATE_ID",
+        "the id has already been used";
+    DatumId = 30, "SIMCONNECT_EXCEPTION_DATUM_ID",
+        "the datum id is not recognized";
+    OutOfBounds = 31, "SIMCONNECT_EXCEPTION_OUT_OF_BOUNDS",
+        "the radius given is outside the acceptable range";
+    AlreadyCreated = 32, "SIMCONNECT_EXCEPTION_ALREADY_CREATED",
+        "a client data area with the requested name has already been created";
+    ObjectOutsideRealityBubble = 33, "SIMCONNECT_EXCEPTION_OBJECT_OUTSIDE_REALITY_BUBBLE",
+        "the object is outside the reality bubble";
+    ObjectContainer = 34, "SIMCONNECT_EXCEPTION_OBJECT_CONTAINER",
+        "there is a problem with the object container";
+    ObjectAi = 35, "SIMCONNECT_EXCEPTION_OBJECT_AI",
+        "there is a problem with the AI object";
+    ObjectAtc = 36, "SIMCONNECT_EXCEPTION_OBJECT_ATC",
+        "there is a problem with the ATC object";
+    ObjectSchedule = 37, "SIMCONNECT_EXCEPTION_OBJECT_SCHEDULE",
+        "there is a problem with the object schedule";
+    JetwayData = 38, "SIMCONNECT_EXCEPTION_JETWAY_DATA",
+        "the jetway data request failed";
+    ActionNotFound = 39, "SIMCONNECT_EXCEPTION_ACTION_NOT_FOUND",
+        "the given action cannot be found";
+    NotAnAction = 40, "SIMCONNECT_EXCEPTION_NOT_AN_ACTION",
+        "the given action does not exist";
+    IncorrectActionParams = 41, "SIMCONNECT_EXCEPTION_INCORRECT_ACTION_PARAMS",
+        "wrong parameters have been given to the action";
+    GetInputEventFailed = 42, "SIMCONNECT_EXCEPTION_GET_INPUT_EVENT_FAILED",
+        "the input event could not be read, check the hash is valid";
+    SetInputEventFailed = 43, "SIMCONNECT_EXCEPTION_SET_INPUT_EVENT_FAILED",
+        "the input event could not be set, check the hash and value are valid";
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn exception_display() {
+        let e = Exception::new(7, 12, 1)
+            .with_call("SimConnect_MapClientEventToSimEvent(1, \"brakez\")");
+        assert_eq!(e.kind(), Some(ExceptionKind::NameUnrecognized));
+        assert_eq!(e.to_string(), "SIMCONNECT_EXCEPTION_NAME_UNRECOGNIZED: \
+            the simulation event name is not recognized (send id 12, parameter 1) \
+            in SimConnect_MapClientEventToSimEvent(1, \"brakez\")");
+
+        let e = Exception::new(1000, 3, Exception::UNKNOWN_INDEX);
+        assert_eq!(e.kind(), None);
+        assert_eq!(e.index(), None);
+        assert_eq!(e.to_string(), "SIMCONNECT_EXCEPTION 1000: unknown exception (send id 3)");
+    }
+
+    #[test]
+    fn exception_kinds_match_header() {
+        use simconnect_sys::*;
+        assert_eq!(ExceptionKind::from_code(SIMCONNECT_EXCEPTION_SIZE_MISMATCH as u32),
+            Some(ExceptionKind::SizeMismatch));
+        assert_eq!(ExceptionKind::from_code(SIMCONNECT_EXCEPTION_SET_INPUT_EVENT_FAILED as u32),
+            Some(ExceptionKind::SetInputEventFailed));
+        assert_eq!(ExceptionKind::try_from(SIMCONNECT_EXCEPTION_DUPLICATE_ID), Ok(ExceptionKind::DuplicateId));
+        assert_eq!(u32::from(ExceptionKind::DuplicateId), SIMCONNECT_EXCEPTION_DUPLICATE_ID as u32);
+        assert!(ExceptionKind::try_from(-1).is_err());
+    }
+}
@@ -0,0 +1,119 @@
+use std::ffi::CString;
+
+use crate::connection::SimConnect;
+use crate::error::Result;
+use crate::flags::EventFlags;
+use crate::ids::{ClientEventId, GroupId};
+use crate::request::Request;
+
+impl SimConnect {
+
+    /// Maps the client event `event_id` to the simulation event `name`.
+    pub fn map_client_event_to_sim_event(&self, event_id: ClientEventId, name: &str) -> Result<()> {
+        let c_name = CString::new(name)?;
+        self.send(Request::MapClientEventToSimEvent { event_id, name: &c_name })
+    }
+
+    /// Transmits the client event `event_id` with `data` to `object_id`, as
+    /// part of the notification group `group_id`.
+    pub fn transmit_client_event(
+        &self,
+        object_id: u32,
+        event_id: ClientEventId,
+        data: u32,
+        group_id: GroupId,
+        flags: EventFlags,
+    ) -> Result<()> {
+        self.send(Request::TransmitClientEvent { object_id, event_id, data, group_id: group_id.get(), flags })
+    }
+
+    /// Transmits the client event `event_id` with `data` to `object_id` at
+    /// `priority`, one of the `SIMCONNECT_GROUP_PRIORITY_*` values.
+    ///
+    /// [`EventFlags::GROUPID_IS_PRIORITY`] is added to `flags`.
+    pub fn transmit_client_event_with_priority(
+        &self,
+        object_id: u32,
+        event_id: ClientEventId,
+        data: u32,
+        priority: u32,
+        flags: EventFlags,
+    ) -> Result<()> {
+        let flags = flags | EventFlags::GROUPID_IS_PRIORITY;
+        self.send(Request::TransmitClientEvent { object_id, event_id, data, group_id: priority, flags })
+    }
+
+    /// Transmits the client event `event_id` with up to five parameters to
+    /// `object_id`, as part of the notification group `group_id`.
+    #[cfg(sdk_at_least_0_24_3)]
+    pub fn transmit_client_event_ex1(
+        &self,
+        object_id: u32,
+        event_id: ClientEventId,
+        group_id: GroupId,
+        flags: EventFlags,
+        data: [u32; 5],
+    ) -> Result<()> {
+        self.send(Request::TransmitClientEventEx1 { object_id, event_id, group_id: group_id.get(), flags, data })
+    }
+
+    /// Transmits the client event `event_id` with up to five parameters to
+    /// `object_id` at `priority`, one of the `SIMCONNECT_GROUP_PRIORITY_*` values.
+    ///
+    /// [`EventFlags::GROUPID_IS_PRIORITY`] is added to `flags`.
+    #[cfg(sdk_at_least_0_24_3)]
+    pub fn transmit_client_event_ex1_with_priority(
+        &self,
+        object_id: u32,
+        event_id: ClientEventId,
+        priority: u32,
+        flags: EventFlags,
+        data: [u32; 5],
+    ) -> Result<()> {
+        let flags = flags | EventFlags::GROUPID_IS_PRIORITY;
+        self.send(Request::TransmitClientEventEx1 { object_id, event_id, group_id: priority, flags, data })
+    }
+
+    /// Subscribes to the system event `name`, which will be received as the
+    /// client event `event_id`.
+    pub fn subscribe_to_system_event(&self, event_id: ClientEventId, name: &str) -> Result<()> {
+        let c_name = CString::new(name)?;
+        self.send(Request::SubscribeToSystemEvent { event_id, name: &c_name })
+    }
+
+    /// Unsubscribes the client event `event_id` from its system event.
+    pub fn unsubscribe_from_system_event(&self, event_id: ClientEventId) -> Result<()> {
+        self.send(Request::UnsubscribeFromSystemEvent { event_id })
+    }
+
+    /// Turns reporting of the system event subscribed as `event_id` on or off.
+    pub fn set_system_event_state(&self, event_id: ClientEventId, on: bool) -> Result<()> {
+        self.send(Request::SetSystemEventState { event_id, on })
+    }
+
+    /// Adds the client event `event_id` to the notification group `group_id`.
+    pub fn add_client_event_to_notification_group(
+        &self,
+        group_id: GroupId,
+        event_id: ClientEventId,
+        maskable: bool,
+    ) -> Result<()> {
+        self.send(Request::AddClientEventToNotificationGroup { group_id, event_id, maskable })
+    }
+
+    /// Removes the client event `event_id` from the notification group `group_id`.
+    pub fn remove_client_event(&self, group_id: GroupId, event_id: ClientEventId) -> Result<()> {
+        self.send(Request::RemoveClientEvent { group_id, event_id })
+    }
+
+    /// Sets the priority of the notification group `group_id`, see the
+    /// `SIMCONNECT_GROUP_PRIORITY_*` constants.
+    pub fn set_notification_group_priority(&self, group_id: GroupId, priority: u32) -> Result<()> {
+        self.send(Request::SetNotificationGroupPriority { group_id, priority })
+    }
+
+    /// Removes all client events from the notification group `group_id`.
+    pub fn clear_notification_group(&self, group_id: GroupId) -> Result<()> {
+        self.send(Request::ClearNotificationGroup { group_id })
+    }
+}
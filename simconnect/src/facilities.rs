@@ -0,0 +1,238 @@
+use simconnect_sys::*;
+
+use crate::recv::{Decode, DecodeError, Reader};
+use crate::types::{LatLonAlt, Pbh, Xyz};
+
+/// An airport from a facilities list (`SIMCONNECT_DATA_FACILITY_AIRPORT`).
+#[derive(Debug, Clone, PartialEq)]
+pub struct Airport {
+    pub ident: String,
+    pub region: String,
+    pub latitude: f64,
+    pub longitude: f64,
+    pub altitude: f64,
+}
+
+/// A waypoint from a facilities list (`SIMCONNECT_DATA_FACILITY_WAYPOINT`).
+#[derive(Debug, Clone, PartialEq)]
+pub struct Waypoint {
+    pub airport: Airport,
+    pub mag_var: f32,
+}
+
+/// An NDB from a facilities list (`SIMCONNECT_DATA_FACILITY_NDB`).
+#[derive(Debug, Clone, PartialEq)]
+pub struct Ndb {
+    pub waypoint: Waypoint,
+
+    /// Frequency in Hz.
+    pub frequency: u32,
+}
+
+/// A VOR from a facilities list (`SIMCONNECT_DATA_FACILITY_VOR`).
+#[derive(Debug, Clone, PartialEq)]
+pub struct Vor {
+    pub ndb: Ndb,
+
+    /// `SIMCONNECT_RECV_ID_VOR_LIST_HAS_*` flags.
+    pub flags: u32,
+    pub localizer: f32,
+    pub glide_lat: f64,
+    pub glide_lon: f64,
+    pub glide_alt: f64,
+    pub glide_slope_angle: f32,
+}
+
+/// An ICAO identifier (`SIMCONNECT_ICAO`).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Icao {
+
+    /// The facility type, e.g. `'A'` for airports.
+    pub kind: u8,
+    pub ident: String,
+    pub region: String,
+    pub airport: String,
+}
+
+/// A facility from a minimal facilities list (`SIMCONNECT_FACILITY_MINIMAL`).
+#[derive(Debug, Clone, PartialEq)]
+pub struct FacilityMinimal {
+    pub icao: Icao,
+    pub lla: LatLonAlt,
+}
+
+/// The state of a jetway (`SIMCONNECT_JETWAY_DATA`).
+#[derive(Debug, Clone, PartialEq)]
+pub struct JetwayData {
+    pub airport_icao: String,
+    pub parking_index: i32,
+    pub lla: LatLonAlt,
+    pub pbh: Pbh,
+    pub status: i32,
+    pub door: i32,
+    pub exit_door_relative_pos: Xyz,
+    pub main_handle_pos: Xyz,
+    pub secondary_handle: Xyz,
+    pub wheel_ground_lock: Xyz,
+    pub jetway_object_id: u32,
+    pub attached_object_id: u32,
+}
+
+/// A connected input device (`SIMCONNECT_CONTROLLER_ITEM`).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ControllerItem {
+    pub device_name: String,
+    pub device_id: u32,
+    pub product_id: u32,
+    pub composite_id: u32,
+    pub hardware_version: HardwareVersion,
+}
+
+/// The hardware version of a controller (`SIMCONNECT_VERSION_BASE_TYPE`).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct HardwareVersion {
+    pub major: u16,
+    pub minor: u16,
+    pub revision: u16,
+    pub build: u16,
+}
+
+/// The type of an input event's value (`SIMCONNECT_INPUT_EVENT_TYPE`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum InputEventType {
+    Double,
+    String,
+}
+
+/// An input event of the current aircraft (`SIMCONNECT_INPUT_EVENT_DESCRIPTOR`).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InputEventDescriptor {
+    pub name: String,
+    pub hash: u64,
+    pub value_type: InputEventType,
+}
+
+impl Decode for Airport {
+    const SIZE: usize = 6 + 3 + 24;
+
+    fn decode(r: &mut Reader<'_>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            ident: r.string(6)?,
+            region: r.string(3)?,
+            latitude: r.f64()?,
+            longitude: r.f64()?,
+            altitude: r.f64()?,
+        })
+    }
+}
+
+impl Decode for Waypoint {
+    const SIZE: usize = Airport::SIZE + 4;
+
+    fn decode(r: &mut Reader<'_>) -> Result<Self, DecodeError> {
+        Ok(Self { airport: r.decode()?, mag_var: r.f32()? })
+    }
+}
+
+impl Decode for Ndb {
+    const SIZE: usize = Waypoint::SIZE + 4;
+
+    fn decode(r: &mut Reader<'_>) -> Result<Self, DecodeError> {
+        Ok(Self { waypoint: r.decode()?, frequency: r.u32()? })
+    }
+}
+
+impl Decode for Vor {
+    const SIZE: usize = Ndb::SIZE + 4 + 4 + 24 + 4;
+
+    fn decode(r: &mut Reader<'_>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            ndb: r.decode()?,
+            flags: r.u32()?,
+            localizer: r.f32()?,
+            glide_lat: r.f64()?,
+            glide_lon: r.f64()?,
+            glide_alt: r.f64()?,
+            glide_slope_angle: r.f32()?,
+        })
+    }
+}
+
+impl Decode for Icao {
+    const SIZE: usize = 1 + 6 + 3 + 5;
+
+    fn decode(r: &mut Reader<'_>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            kind: r.u8()?,
+            ident: r.string(6)?,
+            region: r.string(3)?,
+            airport: r.string(5)?,
+        })
+    }
+}
+
+impl Decode for FacilityMinimal {
+    const SIZE: usize = Icao::SIZE + LatLonAlt::SIZE;
+
+    fn decode(r: &mut Reader<'_>) -> Result<Self, DecodeError> {
+        Ok(Self { icao: r.decode()?, lla: r.decode()? })
+    }
+}
+
+impl Decode for JetwayData {
+    const SIZE: usize = 8 + 4 + LatLonAlt::SIZE + Pbh::SIZE + 4 + 4 + 4 * Xyz::SIZE + 4 + 4;
+
+    fn decode(r: &mut Reader<'_>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            airport_icao: r.string(8)?,
+            parking_index: r.i32()?,
+            lla: r.decode()?,
+            pbh: r.decode()?,
+            status: r.i32()?,
+            door: r.i32()?,
+            exit_door_relative_pos: r.decode()?,
+            main_handle_pos: r.decode()?,
+            secondary_handle: r.decode()?,
+            wheel_ground_lock: r.decode()?,
+            jetway_object_id: r.u32()?,
+            attached_object_id: r.u32()?,
+        })
+    }
+}
+
+impl Decode for ControllerItem {
+    const SIZE: usize = 256 + 12 + HardwareVersion::SIZE;
+
+    fn decode(r: &mut Reader<'_>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            device_name: r.string(256)?,
+            device_id: r.u32()?,
+            product_id: r.u32()?,
+            composite_id: r.u32()?,
+            hardware_version: r.decode()?,
+        })
+    }
+}
+
+impl Decode for HardwareVersion {
+    const SIZE: usize = 8;
+
+    fn decode(r: &mut Reader<'_>) -> Result<Self, DecodeError> {
+        Ok(Self { major: r.u16()?, minor: r.u16()?, revision: r.u16()?, build: r.u16()? })
+    }
+}
+
+impl Decode for InputEventDescriptor {
+    const SIZE: usize = 64 + 8 + 4;
+
+    fn decode(r: &mut Reader<'_>) -> Result<Self, DecodeError> {
+        let name = r.string(64)?;
+        let hash = r.u64()?;
+        let value_type = match r.u32()? {
+            SIMCONNECT_INPUT_EVENT_TYPE_DOUBLE => InputEventType::Double,
+            SIMCONNECT_INPUT_EVENT_TYPE_STRING => InputEventType::String,
+            value => return Err(r.invalid("eType", value)),
+        };
+        Ok(Self { name, hash, value_type })
+    }
+}
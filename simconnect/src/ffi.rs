@@ -0,0 +1,368 @@
+#[cfg(feature = "dynamic")]
+use std::ffi::OsStr;
+use std::ffi::{c_void, CString};
+use std::mem::ManuallyDrop;
+use std::ptr::NonNull;
+use std::sync::Arc;
+#[cfg(feature = "dynamic")]
+use std::sync::OnceLock;
+use std::thread::JoinHandle;
+use std::time::Duration;
+
+use simconnect_sys::*;
+use windows_sys::Win32::Foundation::{CloseHandle, WAIT_OBJECT_0, WAIT_TIMEOUT};
+use windows_sys::Win32::System::Threading::{
+    CreateEventW, SetEvent, WaitForMultipleObjects, WaitForSingleObject, INFINITE,
+};
+
+#[cfg(feature = "dynamic")]
+use crate::connection::SimConnect;
+use crate::connection::POLL_INTERVAL;
+use crate::error::{check, Error, Result, E_FAIL};
+use crate::recv::Recv;
+use crate::request::Request;
+
+// calls a SimConnect function, returning a `Result<HRESULT>` as with the
+// `dynamic` feature the function is looked up in the loaded SimConnect.dll
+macro_rules! sys {
+    ($function:ident($($arg:expr),* $(,)?)) => {{
+        #[cfg(not(feature = "dynamic"))]
+        let hr: $crate::error::Result<HRESULT> = Ok($function($($arg),*));
+        #[cfg(feature = "dynamic")]
+        let hr: $crate::error::Result<HRESULT> = $crate::ffi::library().and_then(|library| match &library.$function {
+            Ok(function) => Ok(function($($arg),*)),
+            Err(_) => Err($crate::error::Error::Unavailable(stringify!($function))),
+        });
+        hr
+    }};
+}
+pub(crate) use sys;
+
+#[cfg(feature = "dynamic")]
+static LIBRARY: OnceLock<SimConnectLibrary> = OnceLock::new();
+
+// the loaded SimConnect.dll, loading it from the default search path on
+// first use. failures aren't kept so opening can be retried
+#[cfg(feature = "dynamic")]
+pub(crate) fn library() -> Result<&'static SimConnectLibrary> {
+    match LIBRARY.get() {
+        Some(library) => Ok(library),
+        None => {
+            let library = unsafe { SimConnectLibrary::load() }.map_err(Error::Load)?;
+            Ok(LIBRARY.get_or_init(|| library))
+        }
+    }
+}
+
+type Event = windows_sys::Win32::Foundation::HANDLE;
+
+/// A connection made through SimConnect.dll.
+#[derive(Debug)]
+pub(crate) struct Ffi {
+    handle: NonNull<c_void>,
+    event: Option<WaitEvent>,
+}
+
+impl Ffi {
+
+    pub(crate) fn open(
+        name: &str,
+        window: HWND,
+        user_event: u32,
+        event_handle: HANDLE,
+        config_index: u32,
+    ) -> Result<Self> {
+        let name = CString::new(name)?;
+        let event = if window.is_null() && event_handle.is_null() {
+            WaitEvent::new()
+        } else {
+            None
+        };
+        let event_handle = event.as_ref().map_or(event_handle, |e| e.0 as HANDLE);
+
+        let mut handle = std::ptr::null_mut();
+        check("SimConnect_Open", unsafe { sys!(SimConnect_Open(
+            &mut handle,
+            name.as_ptr(),
+            window,
+            user_event,
+            event_handle,
+            config_index,
+        )) }?)?;
+
+        // a null handle would be closed by nothing, treat it as a failure
+        let handle = NonNull::new(handle).ok_or(Error::Hresult { call: "SimConnect_Open", hr: E_FAIL })?;
+        Ok(Self { handle, event })
+    }
+
+    pub(crate) fn from_raw(handle: NonNull<c_void>) -> Self {
+        Self { handle, event: None }
+    }
+
+    pub(crate) fn as_raw(&self) -> HANDLE {
+        self.handle.as_ptr()
+    }
+
+    pub(crate) fn close(self) -> Result<()> {
+        let mut this = ManuallyDrop::new(self);
+        let hr = unsafe { sys!(SimConnect_Close(this.as_raw())) };
+        // the event must outlive the handle, drop it after closing
+        drop(this.event.take());
+        check("SimConnect_Close", hr?)
+    }
+
+    pub(crate) fn into_raw(self) -> HANDLE {
+        // the event is leaked as SimConnect keeps signalling it
+        ManuallyDrop::new(self).as_raw()
+    }
+
+    pub(crate) fn last_send_id(&self) -> Result<u32> {
+        let mut send_id = 0;
+        check("SimConnect_GetLastSentPacketID", unsafe {
+            sys!(SimConnect_GetLastSentPacketID(self.as_raw(), &mut send_id))
+        }?)?;
+        Ok(send_id)
+    }
+
+    pub(crate) fn try_recv(&self, record: impl FnOnce(&[u8])) -> Result<Option<Recv>> {
+        let mut data: *mut SIMCONNECT_RECV = std::ptr::null_mut();
+        let mut cb_data: DWORD = 0;
+        let hr = unsafe { sys!(SimConnect_GetNextDispatch(self.as_raw(), &mut data, &mut cb_data)) }?;
+        match hr {
+            E_FAIL => return Ok(None),
+            hr if hr < 0 => return Err(Error::Hresult { call: "SimConnect_GetNextDispatch", hr }),
+            _ => {}
+        }
+
+        // SAFETY: SimConnect owns the buffer and keeps it valid until the
+        // next dispatch call, which can't happen while we borrow it
+        if !data.is_null() {
+            record(unsafe { std::slice::from_raw_parts(data as *const u8, cb_data as usize) });
+        }
+        Ok(Some(unsafe { Recv::from_raw(data, cb_data) }?))
+    }
+
+    pub(crate) fn wait(&self, timeout: Option<Duration>) {
+        match &self.event {
+            Some(event) => event.wait(timeout),
+            None => std::thread::sleep(timeout.map_or(POLL_INTERVAL, |t| t.min(POLL_INTERVAL))),
+        }
+    }
+
+    pub(crate) fn notify(&self, f: Arc<dyn Fn() + Send + Sync>) -> Result<Waiter> {
+        Waiter::spawn(self.event.as_ref().map(|event| event.0), f)
+    }
+
+    pub(crate) fn send(&self, request: &Request<'_>) -> Result<()> {
+        let h = self.as_raw();
+        let hr = unsafe {
+            match *request {
+                Request::MapClientEventToSimEvent { event_id, name } => {
+                    sys!(SimConnect_MapClientEventToSimEvent(h, event_id.get(), name.as_ptr()))
+                }
+                Request::TransmitClientEvent { object_id, event_id, data, group_id, flags } => {
+                    sys!(SimConnect_TransmitClientEvent(h, object_id, event_id.get(), data, group_id, flags.bits()))
+                }
+                #[cfg(sdk_at_least_0_24_3)]
+                Request::TransmitClientEventEx1 { object_id, event_id, group_id, flags, data: [d0, d1, d2, d3, d4] } => {
+                    sys!(SimConnect_TransmitClientEvent_EX1(h, object_id, event_id.get(), group_id, flags.bits(), d0, d1, d2, d3, d4))
+                }
+                Request::SetSystemEventState { event_id, on } => {
+                    let state = if on { SIMCONNECT_STATE_ON } else { SIMCONNECT_STATE_OFF };
+                    sys!(SimConnect_SetSystemEventState(h, event_id.get(), state))
+                }
+                Request::AddClientEventToNotificationGroup { group_id, event_id, maskable } => {
+                    sys!(SimConnect_AddClientEventToNotificationGroup(h, group_id.get(), event_id.get(), maskable as BOOL))
+                }
+                Request::RemoveClientEvent { group_id, event_id } => {
+                    sys!(SimConnect_RemoveClientEvent(h, group_id.get(), event_id.get()))
+                }
+                Request::SetNotificationGroupPriority { group_id, priority } => {
+                    sys!(SimConnect_SetNotificationGroupPriority(h, group_id.get(), priority))
+                }
+                Request::ClearNotificationGroup { group_id } => sys!(SimConnect_ClearNotificationGroup(h, group_id.get())),
+                Request::AddToDataDefinition { define_id, name, units, data_type, epsilon, datum_id } => {
+                    // an empty units string is sent as null, as the samples do for strings
+                    let units = if units.is_empty() { std::ptr::null() } else { units.as_ptr() };
+                    sys!(SimConnect_AddToDataDefinition(h, define_id.get(), name.as_ptr(), units, data_type.raw(),
+                        epsilon, datum_id))
+                }
+                Request::ClearDataDefinition { define_id } => sys!(SimConnect_ClearDataDefinition(h, define_id.get())),
+                Request::RequestDataOnSimObject { request_id, define_id, object_id, period, options } => {
+                    sys!(SimConnect_RequestDataOnSimObject(h, request_id.get(), define_id.get(), object_id,
+                        period.raw(), options.flags.bits(), options.origin, options.interval, options.limit))
+                }
+                Request::RequestDataOnSimObjectType { request_id, define_id, radius_meters, object_type } => {
+                    sys!(SimConnect_RequestDataOnSimObjectType(h, request_id.get(), define_id.get(), radius_meters,
+                        object_type.raw()))
+                }
+                Request::SetDataOnSimObject { define_id, object_id, flags, data } => {
+                    // SimConnect never writes through the pointer, it is only `*mut` in the header
+                    sys!(SimConnect_SetDataOnSimObject(h, define_id.get(), object_id, flags.bits(), 0, data.len() as DWORD,
+                        data.as_ptr() as *mut _))
+                }
+                Request::MapInputEventToClientEvent {
+                    group_id, definition, down_event_id, down_value, up_event_id, up_value, maskable,
+                } => {
+                    sys!(SimConnect_MapInputEventToClientEvent(h, group_id.get(), definition.as_ptr(), down_event_id,
+                        down_value, up_event_id, up_value, maskable as BOOL))
+                }
+                #[cfg(sdk_at_least_0_24_3)]
+                Request::MapInputEventToClientEventEx1 {
+                    group_id, definition, down_event_id, down_value, up_event_id, up_value, maskable,
+                } => {
+                    sys!(SimConnect_MapInputEventToClientEvent_EX1(h, group_id.get(), definition.as_ptr(), down_event_id,
+                        down_value, up_event_id, up_value, maskable as BOOL))
+                }
+                Request::SetInputGroupPriority { group_id, priority } => {
+                    sys!(SimConnect_SetInputGroupPriority(h, group_id.get(), priority))
+                }
+                Request::RemoveInputEvent { group_id, definition } => {
+                    sys!(SimConnect_RemoveInputEvent(h, group_id.get(), definition.as_ptr()))
+                }
+                Request::ClearInputGroup { group_id } => sys!(SimConnect_ClearInputGroup(h, group_id.get())),
+                Request::SetInputGroupState { group_id, on } => {
+                    let state = if on { SIMCONNECT_STATE_ON } else { SIMCONNECT_STATE_OFF };
+                    sys!(SimConnect_SetInputGroupState(h, group_id.get(), state as DWORD))
+                }
+                Request::RequestReservedKey { event_id, choices } => {
+                    let [choice1, choice2, choice3] = choices.map(|c| c.map_or(std::ptr::null(), |c| c.as_ptr()));
+                    sys!(SimConnect_RequestReservedKey(h, event_id.get(), choice1, choice2, choice3))
+                }
+                Request::SubscribeToSystemEvent { event_id, name } => {
+                    sys!(SimConnect_SubscribeToSystemEvent(h, event_id.get(), name.as_ptr()))
+                }
+                Request::UnsubscribeFromSystemEvent { event_id } => {
+                    sys!(SimConnect_UnsubscribeFromSystemEvent(h, event_id.get()))
+                }
+                Request::RequestSystemState { request_id, state } => {
+                    sys!(SimConnect_RequestSystemState(h, request_id.get(), state.as_ptr()))
+                }
+                Request::AiCreateNonAtcAircraft { container_title, tail_number, position, request_id } => {
+                    sys!(SimConnect_AICreateNonATCAircraft(h, container_title.as_ptr(), tail_number.as_ptr(),
+                        position.raw(), request_id.get()))
+                }
+                Request::AiCreateSimulatedObject { container_title, position, request_id } => {
+                    sys!(SimConnect_AICreateSimulatedObject(h, container_title.as_ptr(), position.raw(), request_id.get()))
+                }
+                Request::AiRemoveObject { object_id, request_id } => {
+                    sys!(SimConnect_AIRemoveObject(h, object_id, request_id.get()))
+                }
+            }
+        };
+        check(request.name(), hr?)
+    }
+}
+
+#[cfg(feature = "dynamic")]
+impl SimConnect {
+
+    /// Loads SimConnect.dll from `path` for the connections opened after,
+    /// instead of searching for it when the first connection is opened.
+    ///
+    /// Only available with the `dynamic` feature. Does nothing if
+    /// SimConnect.dll was already loaded, it stays loaded until the process
+    /// exits.
+    ///
+    /// ```no_run
+    /// use simconnect::SimConnect;
+    ///
+    /// SimConnect::load_library(r"C:\MSFS SDK\SimConnect SDK\lib\SimConnect.dll")?;
+    /// let sc = SimConnect::open("Example")?;
+    /// # Ok::<(), simconnect::Error>(())
+    /// ```
+    pub fn load_library(path: impl AsRef<OsStr>) -> Result<()> {
+        if LIBRARY.get().is_none() {
+            let library = unsafe { SimConnectLibrary::new(path) }.map_err(Error::Load)?;
+            let _ = LIBRARY.set(library);
+        }
+        Ok(())
+    }
+}
+
+impl Drop for Ffi {
+    fn drop(&mut self) {
+        // errors can't be reported from drop, use `close` to observe them
+        let _ = unsafe { sys!(SimConnect_Close(self.as_raw())) };
+    }
+}
+
+// an auto-reset Win32 event signalled by SimConnect when messages arrive
+#[derive(Debug)]
+struct WaitEvent(Event);
+
+impl WaitEvent {
+
+    fn new() -> Option<Self> {
+        let handle = unsafe { CreateEventW(std::ptr::null(), 0, 0, std::ptr::null()) };
+        // failing to create the event only costs us polling, don't fail open
+        (handle != 0).then_some(Self(handle))
+    }
+
+    fn wait(&self, timeout: Option<Duration>) {
+        // round up, so short timeouts don't turn into a busy loop
+        let ms = timeout.map_or(INFINITE, |t| {
+            t.as_micros().div_ceil(1000).min(INFINITE as u128 - 1) as u32
+        });
+        // timing out or failing just sends the caller back to check the queue
+        unsafe { WaitForSingleObject(self.0, ms) };
+    }
+}
+
+impl Drop for WaitEvent {
+    fn drop(&mut self) {
+        unsafe { CloseHandle(self.0) };
+    }
+}
+
+/// Background thread calling a function when SimConnect signals new messages.
+#[derive(Debug)]
+pub(crate) struct Waiter {
+    stop: Event,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl Waiter {
+
+    fn spawn(event: Option<Event>, f: Arc<dyn Fn() + Send + Sync>) -> Result<Self> {
+        let stop = unsafe { CreateEventW(std::ptr::null(), 1, 0, std::ptr::null()) };
+        if stop == 0 {
+            return Err(std::io::Error::last_os_error().into());
+        }
+        let mut waiter = Self { stop, thread: None };
+        let thread = std::thread::Builder::new()
+            .name("simconnect-waiter".into())
+            .spawn(move || Self::run(event, stop, &*f))?;
+        waiter.thread = Some(thread);
+        Ok(waiter)
+    }
+
+    fn run(event: Option<Event>, stop: Event, f: &(dyn Fn() + Send + Sync)) {
+        loop {
+            let signalled = match event {
+                Some(event) => {
+                    let handles = [event, stop];
+                    unsafe { WaitForMultipleObjects(2, handles.as_ptr(), 0, INFINITE) == WAIT_OBJECT_0 }
+                }
+                // nothing tells us about new messages, check every interval
+                None => unsafe {
+                    WaitForSingleObject(stop, POLL_INTERVAL.as_millis() as u32) == WAIT_TIMEOUT
+                },
+            };
+            if !signalled {
+                return;
+            }
+            f();
+        }
+    }
+}
+
+impl Drop for Waiter {
+    fn drop(&mut self) {
+        unsafe { SetEvent(self.stop) };
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+        unsafe { CloseHandle(self.stop) };
+    }
+}
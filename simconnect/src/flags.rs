@@ -0,0 +1,83 @@
+use bitflags::bitflags;
+use simconnect_sys::*;
+
+bitflags! {
+
+    /// Flags of a data request, see [`RequestOptions`](crate::RequestOptions)
+    /// (`SIMCONNECT_DATA_REQUEST_FLAG_*`).
+    #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+    pub struct DataRequestFlags: u32 {
+
+        /// Only send the data when it changed.
+        const CHANGED = SIMCONNECT_DATA_REQUEST_FLAG_CHANGED;
+
+        /// Send the data in tagged format, as datum id and value pairs.
+        const TAGGED = SIMCONNECT_DATA_REQUEST_FLAG_TAGGED;
+    }
+
+    /// Flags of [`SimConnect::set_data_on_sim_object`](crate::SimConnect::set_data_on_sim_object)
+    /// (`SIMCONNECT_DATA_SET_FLAG_*`).
+    #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+    pub struct DataSetFlags: u32 {
+
+        /// The data is in tagged format.
+        const TAGGED = SIMCONNECT_DATA_SET_FLAG_TAGGED;
+    }
+
+    /// Flags of a transmitted client event (`SIMCONNECT_EVENT_FLAG_*`).
+    #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+    pub struct EventFlags: u32 {
+
+        /// Repeat the event at the fast rate while it's transmitted.
+        const FAST_REPEAT_TIMER = SIMCONNECT_EVENT_FLAG_FAST_REPEAT_TIMER;
+
+        /// Repeat the event at the slow rate while it's transmitted.
+        const SLOW_REPEAT_TIMER = SIMCONNECT_EVENT_FLAG_SLOW_REPEAT_TIMER;
+
+        /// The group id is a `SIMCONNECT_GROUP_PRIORITY_*` value.
+        const GROUPID_IS_PRIORITY = SIMCONNECT_EVENT_FLAG_GROUPID_IS_PRIORITY;
+    }
+
+    /// Flags of an AI waypoint (`SIMCONNECT_WAYPOINT_*`).
+    #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+    pub struct WaypointFlags: u32 {
+        const SPEED_REQUESTED = SIMCONNECT_WAYPOINT_SPEED_REQUESTED;
+        const THROTTLE_REQUESTED = SIMCONNECT_WAYPOINT_THROTTLE_REQUESTED;
+        const COMPUTE_VERTICAL_SPEED = SIMCONNECT_WAYPOINT_COMPUTE_VERTICAL_SPEED;
+        const ALTITUDE_IS_AGL = SIMCONNECT_WAYPOINT_ALTITUDE_IS_AGL;
+        const ON_GROUND = SIMCONNECT_WAYPOINT_ON_GROUND;
+        const REVERSE = SIMCONNECT_WAYPOINT_REVERSE;
+        const WRAP_TO_FIRST = SIMCONNECT_WAYPOINT_WRAP_TO_FIRST;
+        const ALWAYS_BACKUP = SIMCONNECT_WAYPOINT_ALWAYS_BACKUP;
+        const KEEP_LAST_HEADING = SIMCONNECT_WAYPOINT_KEEP_LAST_HEADING;
+        const YIELD_TO_USER = SIMCONNECT_WAYPOINT_YIELD_TO_USER;
+        const CAN_REVERSE = SIMCONNECT_WAYPOINT_CAN_REVERSE;
+    }
+
+    /// Flags of a client data area (`SIMCONNECT_CREATE_CLIENT_DATA_FLAG_*`).
+    #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+    pub struct CreateClientDataFlags: u32 {
+
+        /// Only the client that created the area may write to it.
+        const READ_ONLY = SIMCONNECT_CREATE_CLIENT_DATA_FLAG_READ_ONLY;
+    }
+
+    /// Flags of a client data request (`SIMCONNECT_CLIENT_DATA_REQUEST_FLAG_*`).
+    #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+    pub struct ClientDataRequestFlags: u32 {
+
+        /// Only send the data when it changed.
+        const CHANGED = SIMCONNECT_CLIENT_DATA_REQUEST_FLAG_CHANGED;
+
+        /// Send the data in tagged format, as datum id and value pairs.
+        const TAGGED = SIMCONNECT_CLIENT_DATA_REQUEST_FLAG_TAGGED;
+    }
+
+    /// Flags of setting client data (`SIMCONNECT_CLIENT_DATA_SET_FLAG_*`).
+    #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+    pub struct ClientDataSetFlags: u32 {
+
+        /// The data is in tagged format.
+        const TAGGED = SIMCONNECT_CLIENT_DATA_SET_FLAG_TAGGED;
+    }
+}
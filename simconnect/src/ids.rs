@@ -0,0 +1,148 @@
+use std::cell::{Cell, RefCell};
+use std::fmt;
+
+use simconnect_sys::SIMCONNECT_UNUSED;
+
+use crate::connection::SimConnect;
+
+// ids below this are never allocated, leaving them free for fixed ids
+// created with `new`, e.g. from existing code using constants
+const FIRST_ID: u32 = 0x10000;
+
+/// Hands out ids for one kind of SimConnect id, reusing released ones.
+#[derive(Debug, Default)]
+pub(crate) struct IdAllocator {
+    next: Cell<u32>,
+    free: RefCell<Vec<u32>>,
+}
+
+impl IdAllocator {
+
+    fn alloc(&self) -> u32 {
+        if let Some(id) = self.free.borrow_mut().pop() {
+            return id;
+        }
+        // SIMCONNECT_UNUSED stands for no id, so ids stop right below it
+        let next = self.next.get();
+        let id = FIRST_ID.checked_add(next)
+            .filter(|id| *id < SIMCONNECT_UNUSED)
+            .expect("SimConnect ids exhausted");
+        self.next.set(next + 1);
+        id
+    }
+
+    fn release(&self, id: u32) {
+        // only take back ids we handed out, and only once
+        let allocated = id.checked_sub(FIRST_ID).is_some_and(|n| n < self.next.get());
+        let mut free = self.free.borrow_mut();
+        if allocated && !free.contains(&id) {
+            free.push(id);
+        }
+    }
+}
+
+macro_rules! ids {
+    ($($(#[$doc:meta])* $name:ident, $field:ident, $new:ident, $release:ident;)*) => {
+
+        /// Per connection allocators for every kind of id.
+        #[derive(Debug, Default)]
+        pub(crate) struct Ids {
+            $($field: IdAllocator,)*
+        }
+
+        $(
+            $(#[$doc])*
+            #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+            pub struct $name(u32);
+
+            impl $name {
+
+                /// Creates an id from a fixed value, for ids managed by the
+                /// caller rather than allocated by the connection.
+                pub const fn new(id: u32) -> Self {
+                    Self(id)
+                }
+
+                /// Returns the raw id.
+                pub const fn get(self) -> u32 {
+                    self.0
+                }
+            }
+
+            impl From<$name> for u32 {
+                fn from(id: $name) -> u32 {
+                    id.0
+                }
+            }
+
+            impl fmt::Display for $name {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    self.0.fmt(f)
+                }
+            }
+
+            impl SimConnect {
+
+                #[doc = concat!("Allocates a new [`", stringify!($name), "`], unique within this connection.")]
+                pub fn $new(&self) -> $name {
+                    $name(self.ids().$field.alloc())
+                }
+
+                #[doc = concat!("Returns an allocated [`", stringify!($name), "`] so it can be reused.")]
+                ///
+                /// Ids that weren't allocated by this connection are ignored.
+                pub fn $release(&self, id: $name) {
+                    self.ids().$field.release(id.0)
+                }
+            }
+        )*
+    };
+}
+
+ids! {
+    /// Identifies a data definition (`SIMCONNECT_DATA_DEFINITION_ID`).
+    DefineId, define, new_define_id, release_define_id;
+
+    /// Identifies a data request (`SIMCONNECT_DATA_REQUEST_ID`).
+    RequestId, request, new_request_id, release_request_id;
+
+    /// Identifies a client event (`SIMCONNECT_CLIENT_EVENT_ID`).
+    ClientEventId, client_event, new_client_event_id, release_client_event_id;
+
+    /// Identifies a notification group (`SIMCONNECT_NOTIFICATION_GROUP_ID`).
+    GroupId, group, new_group_id, release_group_id;
+
+    /// Identifies an input group (`SIMCONNECT_INPUT_GROUP_ID`).
+    InputGroupId, input_group, new_input_group_id, release_input_group_id;
+
+    /// Identifies a client data area (`SIMCONNECT_CLIENT_DATA_ID`).
+    ClientDataId, client_data, new_client_data_id, release_client_data_id;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn allocator_reuses_released_ids() {
+        let ids = IdAllocator::default();
+        let a = ids.alloc();
+        let b = ids.alloc();
+        assert_ne!(a, b);
+
+        ids.release(a);
+        ids.release(a);
+        ids.release(1);
+        assert_eq!(ids.alloc(), a);
+        assert!(![a, b].contains(&ids.alloc()));
+    }
+
+    #[test]
+    #[should_panic(expected = "SimConnect ids exhausted")]
+    fn allocator_stops_before_unused() {
+        let ids = IdAllocator::default();
+        ids.next.set(SIMCONNECT_UNUSED - FIRST_ID - 1);
+        assert_eq!(ids.alloc(), SIMCONNECT_UNUSED - 1);
+        ids.alloc();
+    }
+}
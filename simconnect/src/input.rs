@@ -0,0 +1,586 @@
+use std::ffi::CString;
+use std::fmt;
+use std::io;
+use std::path::Path;
+use std::str::FromStr;
+
+use bitflags::bitflags;
+use simconnect_sys::{SIMCONNECT_GROUP_PRIORITY_STANDARD, SIMCONNECT_UNUSED};
+
+use crate::connection::SimConnect;
+use crate::error::{Error, Result};
+use crate::ids::{ClientEventId, InputGroupId};
+use crate::request::Request;
+
+// longest key choice that fits szChoiceReserved[30] with its terminator
+const MAX_KEY_CHOICE_LEN: usize = 29;
+
+// names of the keys that aren't a single character or a function key
+const NAMED_KEYS: &[&str] = &[
+    "Backspace", "Tab", "Enter", "Pause", "CapsLock", "Esc", "Space", "PageUp", "PageDown", "End", "Home", "Left",
+    "Up", "Right", "Down", "Insert", "Delete", "NumLock", "ScrollLock", "Num0", "Num1", "Num2", "Num3", "Num4",
+    "Num5", "Num6", "Num7", "Num8", "Num9", "NumMultiply", "NumAdd", "NumSubtract", "NumDecimal", "NumDivide",
+    "NumEnter",
+];
+
+bitflags! {
+
+    /// Modifier keys held for a key combination.
+    #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+    pub struct KeyModifiers: u8 {
+        const SHIFT = 1 << 0;
+        const CTRL = 1 << 1;
+        const ALT = 1 << 2;
+    }
+}
+
+/// The key of a key combination.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Key {
+
+    /// A letter, digit or punctuation key, letters in uppercase.
+    Char(char),
+
+    /// A function key, `F1` to `F24`.
+    F(u8),
+
+    /// Any other key, e.g. `Space` or `Num0`.
+    Named(&'static str),
+}
+
+/// An axis of a joystick.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum JoystickAxis {
+    X,
+    Y,
+    Z,
+    Rx,
+    Ry,
+    Rz,
+    Slider,
+}
+
+/// An input of a joystick.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum JoystickInput {
+    Axis(JoystickAxis),
+    Button(u32),
+
+    /// The point of view hat.
+    Pov,
+}
+
+/// A key combination or joystick input, parsed from and displayed as the
+/// input definition strings of `SimConnect_MapInputEventToClientEvent`.
+///
+/// ```
+/// use simconnect::{InputDefinition, JoystickAxis, JoystickInput, Key, KeyModifiers};
+///
+/// let keys: InputDefinition = "ctrl+shift+u".parse()?;
+/// assert_eq!(keys, InputDefinition::Keys { modifiers: KeyModifiers::SHIFT | KeyModifiers::CTRL, key: Key::Char('U') });
+/// assert_eq!(keys.to_string(), "Shift+Ctrl+U");
+///
+/// let axis: InputDefinition = "joystick:0:XAxis".parse()?;
+/// assert_eq!(axis, InputDefinition::Joystick { index: 0, input: JoystickInput::Axis(JoystickAxis::X) });
+/// # Ok::<(), simconnect::ParseInputError>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum InputDefinition {
+    Keys {
+        modifiers: KeyModifiers,
+        key: Key,
+    },
+    Joystick {
+        index: u32,
+        input: JoystickInput,
+    },
+}
+
+/// An input definition or input config that failed to parse.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("{}{reason}: {text:?}", line.map_or(String::new(), |line| format!("line {line}: ")))]
+pub struct ParseInputError {
+
+    /// The line of the config, for errors from [`InputMapping::load`].
+    pub line: Option<usize>,
+    pub text: String,
+    pub reason: &'static str,
+}
+
+impl FromStr for InputDefinition {
+    type Err = ParseInputError;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let error = |reason| ParseInputError { line: None, text: s.to_string(), reason };
+        let mut parts = s.trim().split(':');
+        let first = parts.next().unwrap_or_default();
+        if first.eq_ignore_ascii_case("joystick") {
+            let index = parts.next().and_then(|index| index.parse().ok()).ok_or_else(|| error("invalid joystick number"))?;
+            let name = parts.next().ok_or_else(|| error("missing joystick input"))?.to_ascii_lowercase();
+            let input = match name.as_str() {
+                "xaxis" => JoystickInput::Axis(JoystickAxis::X),
+                "yaxis" => JoystickInput::Axis(JoystickAxis::Y),
+                "zaxis" => JoystickInput::Axis(JoystickAxis::Z),
+                "rxaxis" => JoystickInput::Axis(JoystickAxis::Rx),
+                "ryaxis" => JoystickInput::Axis(JoystickAxis::Ry),
+                "rzaxis" => JoystickInput::Axis(JoystickAxis::Rz),
+                "slider" => JoystickInput::Axis(JoystickAxis::Slider),
+                "pov" => JoystickInput::Pov,
+                "button" => {
+                    let button = parts.next().and_then(|button| button.parse().ok());
+                    JoystickInput::Button(button.ok_or_else(|| error("invalid button number"))?)
+                }
+                _ => return Err(error("unknown joystick input")),
+            };
+            if parts.next().is_some() {
+                return Err(error("unexpected text after the joystick input"));
+            }
+            return Ok(InputDefinition::Joystick { index, input });
+        }
+        if parts.next().is_some() {
+            return Err(error("expected a key combination or `joystick:<n>:<input>`"));
+        }
+
+        let mut modifiers = KeyModifiers::empty();
+        let mut key = None;
+        for part in first.split('+').map(str::trim) {
+            let modifier = match part.to_ascii_lowercase().as_str() {
+                "shift" => KeyModifiers::SHIFT,
+                "ctrl" | "control" => KeyModifiers::CTRL,
+                "alt" => KeyModifiers::ALT,
+                _ if key.is_some() => return Err(error("more than one key")),
+                _ => {
+                    key = Some(parse_key(part).ok_or_else(|| error("unknown key"))?);
+                    continue;
+                }
+            };
+            if modifiers.contains(modifier) {
+                return Err(error("repeated modifier"));
+            }
+            modifiers |= modifier;
+        }
+        let key = key.ok_or_else(|| error("missing key"))?;
+        Ok(InputDefinition::Keys { modifiers, key })
+    }
+}
+
+fn parse_key(s: &str) -> Option<Key> {
+    let mut chars = s.chars();
+    if let (Some(c), None) = (chars.next(), chars.next()) {
+        return c.is_ascii_graphic().then(|| Key::Char(c.to_ascii_uppercase()));
+    }
+    if let Some(n) = s.strip_prefix(['F', 'f']).and_then(|n| n.parse().ok()) {
+        return (1..=24).contains(&n).then_some(Key::F(n));
+    }
+    NAMED_KEYS.iter().find(|name| name.eq_ignore_ascii_case(s)).map(|name| Key::Named(name))
+}
+
+impl fmt::Display for InputDefinition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            InputDefinition::Keys { modifiers, key } => {
+                for (modifier, name) in [(KeyModifiers::SHIFT, "Shift"), (KeyModifiers::CTRL, "Ctrl"), (KeyModifiers::ALT, "Alt")] {
+                    if modifiers.contains(modifier) {
+                        write!(f, "{}+", name)?;
+                    }
+                }
+                match key {
+                    Key::Char(c) => write!(f, "{}", c),
+                    Key::F(n) => write!(f, "F{}", n),
+                    Key::Named(name) => f.write_str(name),
+                }
+            }
+            InputDefinition::Joystick { index, input } => {
+                write!(f, "joystick:{}:", index)?;
+                match input {
+                    JoystickInput::Axis(JoystickAxis::X) => f.write_str("XAxis"),
+                    JoystickInput::Axis(JoystickAxis::Y) => f.write_str("YAxis"),
+                    JoystickInput::Axis(JoystickAxis::Z) => f.write_str("ZAxis"),
+                    JoystickInput::Axis(JoystickAxis::Rx) => f.write_str("RxAxis"),
+                    JoystickInput::Axis(JoystickAxis::Ry) => f.write_str("RyAxis"),
+                    JoystickInput::Axis(JoystickAxis::Rz) => f.write_str("RzAxis"),
+                    JoystickInput::Axis(JoystickAxis::Slider) => f.write_str("Slider"),
+                    JoystickInput::Button(button) => write!(f, "button:{}", button),
+                    JoystickInput::Pov => f.write_str("POV"),
+                }
+            }
+        }
+    }
+}
+
+/// The client events an input sends when it is pressed and released.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InputEvents {
+
+    /// The client event and value sent when the input is pressed or moved.
+    pub down: Option<(ClientEventId, u32)>,
+
+    /// The client event and value sent when the input is released.
+    pub up: Option<(ClientEventId, u32)>,
+
+    /// Whether the input is hidden from lower priority input groups and the
+    /// simulator.
+    pub maskable: bool,
+}
+
+impl InputEvents {
+
+    /// Sends `event_id` with 0 when the input is pressed.
+    pub fn down(event_id: ClientEventId) -> Self {
+        Self { down: Some((event_id, 0)), up: None, maskable: false }
+    }
+
+    /// Sends `down` when the input is pressed and `up` when it is released.
+    pub fn down_up(down: (ClientEventId, u32), up: (ClientEventId, u32)) -> Self {
+        Self { down: Some(down), up: Some(up), maskable: false }
+    }
+}
+
+/// Inputs to map to client events in one input group, see
+/// [`SimConnect::map_inputs`].
+///
+/// ```no_run
+/// use simconnect::{InputEvents, InputMapping, SimConnect};
+///
+/// let sc = SimConnect::open("Example")?;
+/// let brakes = sc.new_client_event_id();
+/// sc.map_client_event_to_sim_event(brakes, "BRAKES")?;
+///
+/// let mapping = InputMapping::new()
+///     .bind("shift+b".parse()?, InputEvents::down(brakes))
+///     .load_file("controls.cfg", |name| match name {
+///         "Brakes" => Some(InputEvents::down(brakes)),
+///         _ => None,
+///     })?;
+/// let inputs = sc.map_inputs(&mapping)?;
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InputMapping {
+    inputs: Vec<(InputDefinition, InputEvents)>,
+    priority: u32,
+    on: bool,
+}
+
+impl Default for InputMapping {
+
+    fn default() -> Self {
+        Self { inputs: Vec::new(), priority: SIMCONNECT_GROUP_PRIORITY_STANDARD, on: true }
+    }
+}
+
+impl InputMapping {
+
+    /// An empty mapping at `SIMCONNECT_GROUP_PRIORITY_STANDARD`, turned on
+    /// once mapped.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Maps `definition` to `events`.
+    pub fn bind(mut self, definition: InputDefinition, events: InputEvents) -> Self {
+        self.inputs.push((definition, events));
+        self
+    }
+
+    /// Sets the priority of the input group, one of the
+    /// `SIMCONNECT_GROUP_PRIORITY_*` values.
+    pub fn priority(mut self, priority: u32) -> Self {
+        self.priority = priority;
+        self
+    }
+
+    /// Sets whether the input group is turned on once mapped.
+    pub fn state(mut self, on: bool) -> Self {
+        self.on = on;
+        self
+    }
+
+    /// Every input with the events it is mapped to.
+    pub fn inputs(&self) -> &[(InputDefinition, InputEvents)] {
+        &self.inputs
+    }
+
+    /// Binds the inputs of a config of `name = definition` lines, with the
+    /// events `events` returns for each name.
+    ///
+    /// Names may be bound more than once, blank lines and lines starting with
+    /// `#` or `;` are skipped.
+    pub fn load(
+        mut self,
+        config: &str,
+        mut events: impl FnMut(&str) -> Option<InputEvents>,
+    ) -> std::result::Result<Self, ParseInputError> {
+        for (i, line) in config.lines().enumerate().map(|(i, line)| (i + 1, line.trim())) {
+            if line.is_empty() || line.starts_with(['#', ';']) {
+                continue;
+            }
+            let error = |reason| ParseInputError { line: Some(i), text: line.to_string(), reason };
+            let (name, definition) = line.split_once('=').ok_or_else(|| error("expected `name = definition`"))?;
+            let events = events(name.trim()).ok_or_else(|| error("unknown name"))?;
+            let definition = definition.parse().map_err(|e: ParseInputError| ParseInputError { line: Some(i), ..e })?;
+            self.inputs.push((definition, events));
+        }
+        Ok(self)
+    }
+
+    /// Binds the inputs of the config file at `path`, see [`InputMapping::load`].
+    pub fn load_file(
+        self,
+        path: impl AsRef<Path>,
+        events: impl FnMut(&str) -> Option<InputEvents>,
+    ) -> io::Result<Self> {
+        let config = std::fs::read_to_string(path)?;
+        self.load(&config, events).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    }
+}
+
+/// An input group mapped from an [`InputMapping`], cleared when dropped.
+#[derive(Debug)]
+#[must_use = "the inputs are unmapped when the input group is dropped"]
+pub struct InputGroup<'a> {
+    sc: &'a SimConnect,
+    group_id: InputGroupId,
+}
+
+impl SimConnect {
+
+    /// Maps the input definition `definition` to client events in the input
+    /// group `group_id`, `down` sent when it is pressed and `up` when it is
+    /// released.
+    pub fn map_input_event_to_client_event(
+        &self,
+        group_id: InputGroupId,
+        definition: &str,
+        down: Option<(ClientEventId, u32)>,
+        up: Option<(ClientEventId, u32)>,
+        maskable: bool,
+    ) -> Result<()> {
+        let definition = CString::new(definition)?;
+        let ([down_event_id, down_value], [up_event_id, up_value]) = (raw_event(down), raw_event(up));
+        self.send(Request::MapInputEventToClientEvent {
+            group_id, definition: &definition, down_event_id, down_value, up_event_id, up_value, maskable,
+        })
+    }
+
+    /// Like [`SimConnect::map_input_event_to_client_event`], with
+    /// `SimConnect_MapInputEventToClientEvent_EX1`.
+    #[cfg(sdk_at_least_0_24_3)]
+    pub fn map_input_event_to_client_event_ex1(
+        &self,
+        group_id: InputGroupId,
+        definition: &str,
+        down: Option<(ClientEventId, u32)>,
+        up: Option<(ClientEventId, u32)>,
+        maskable: bool,
+    ) -> Result<()> {
+        let definition = CString::new(definition)?;
+        let ([down_event_id, down_value], [up_event_id, up_value]) = (raw_event(down), raw_event(up));
+        self.send(Request::MapInputEventToClientEventEx1 {
+            group_id, definition: &definition, down_event_id, down_value, up_event_id, up_value, maskable,
+        })
+    }
+
+    /// Sets the priority of the input group `group_id`, see the
+    /// `SIMCONNECT_GROUP_PRIORITY_*` constants.
+    pub fn set_input_group_priority(&self, group_id: InputGroupId, priority: u32) -> Result<()> {
+        self.send(Request::SetInputGroupPriority { group_id, priority })
+    }
+
+    /// Removes the input definition `definition` from the input group `group_id`.
+    pub fn remove_input_event(&self, group_id: InputGroupId, definition: &str) -> Result<()> {
+        let definition = CString::new(definition)?;
+        self.send(Request::RemoveInputEvent { group_id, definition: &definition })
+    }
+
+    /// Removes every input from the input group `group_id`.
+    pub fn clear_input_group(&self, group_id: InputGroupId) -> Result<()> {
+        self.send(Request::ClearInputGroup { group_id })
+    }
+
+    /// Turns the input group `group_id` on or off, input groups start off.
+    pub fn set_input_group_state(&self, group_id: InputGroupId, on: bool) -> Result<()> {
+        self.send(Request::SetInputGroupState { group_id, on })
+    }
+
+    /// Asks the simulator to reserve one of the key combinations `choices`
+    /// for `event_id`, in order of preference, answered by a
+    /// `Recv::ReservedKey` naming the one that was free.
+    ///
+    /// `AsyncSimConnect::reserve_key`, with the `async` feature, waits for
+    /// the answer and maps the reserved key.
+    ///
+    /// Returns [`Error::InvalidArgument`] unless `choices` holds one to three
+    /// keys, and [`Error::StringTooLong`] for a key longer than 29 bytes.
+    pub fn request_reserved_key(&self, event_id: ClientEventId, choices: &[&str]) -> Result<()> {
+        if !(1..=3).contains(&choices.len()) {
+            return Err(Error::InvalidArgument { name: "choices", reason: "must hold one to three keys" });
+        }
+        if let Some(choice) = choices.iter().find(|choice| choice.len() > MAX_KEY_CHOICE_LEN) {
+            return Err(Error::StringTooLong { len: choice.len(), max: MAX_KEY_CHOICE_LEN });
+        }
+        let choices = choices.iter().map(|choice| CString::new(*choice)).collect::<std::result::Result<Vec<_>, _>>()?;
+        let mut c_choices = [None; 3];
+        for (c_choice, choice) in c_choices.iter_mut().zip(&choices) {
+            *c_choice = Some(choice.as_c_str());
+        }
+        self.send(Request::RequestReservedKey { event_id, choices: c_choices })
+    }
+
+    /// Maps every input of `mapping` in a newly allocated input group, then
+    /// sets its priority and state.
+    ///
+    /// Inputs are mapped with `SimConnect_MapInputEventToClientEvent_EX1`,
+    /// falling back to `SimConnect_MapInputEventToClientEvent` where it is
+    /// unavailable.
+    pub fn map_inputs(&self, mapping: &InputMapping) -> Result<InputGroup<'_>> {
+        // dropping the group clears and releases it on failure
+        let group = InputGroup { sc: self, group_id: self.new_input_group_id() };
+        for (definition, events) in &mapping.inputs {
+            self.map_input(group.group_id, &definition.to_string(), *events)?;
+        }
+        self.set_input_group_priority(group.group_id, mapping.priority)?;
+        self.set_input_group_state(group.group_id, mapping.on)?;
+        Ok(group)
+    }
+
+    // maps with the _EX1 function where it is available
+    pub(crate) fn map_input(&self, group_id: InputGroupId, definition: &str, events: InputEvents) -> Result<()> {
+        let InputEvents { down, up, maskable } = events;
+        #[cfg(sdk_at_least_0_24_3)]
+        match self.map_input_event_to_client_event_ex1(group_id, definition, down, up, maskable) {
+            Err(Error::Unavailable(_)) => {}
+            result => return result,
+        }
+        self.map_input_event_to_client_event(group_id, definition, down, up, maskable)
+    }
+}
+
+// a client event as its raw id and value, SIMCONNECT_UNUSED for none
+fn raw_event(event: Option<(ClientEventId, u32)>) -> [u32; 2] {
+    event.map_or([SIMCONNECT_UNUSED, 0], |(event_id, value)| [event_id.get(), value])
+}
+
+impl InputGroup<'_> {
+
+    /// The input group the inputs are mapped in.
+    pub fn group_id(&self) -> InputGroupId {
+        self.group_id
+    }
+
+    /// Turns the input group on or off.
+    pub fn set_state(&self, on: bool) -> Result<()> {
+        self.sc.set_input_group_state(self.group_id, on)
+    }
+
+    /// Sets the priority of the input group.
+    pub fn set_priority(&self, priority: u32) -> Result<()> {
+        self.sc.set_input_group_priority(self.group_id, priority)
+    }
+
+    /// Removes `definition` from the input group.
+    pub fn remove(&self, definition: &InputDefinition) -> Result<()> {
+        self.sc.remove_input_event(self.group_id, &definition.to_string())
+    }
+}
+
+impl Drop for InputGroup<'_> {
+
+    fn drop(&mut self) {
+        let _ = self.sc.clear_input_group(self.group_id);
+        self.sc.release_input_group_id(self.group_id);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::{next_matching, MockServer, Request, TIMEOUT};
+    use crate::recv::Recv;
+
+    #[test]
+    fn parse() {
+        for (definition, canonical) in [
+            ("z", "Z"),
+            ("ctrl+shift+u", "Shift+Ctrl+U"),
+            ("Alt+f12", "Alt+F12"),
+            ("shift+space", "Shift+Space"),
+            ("joystick:0:slider", "joystick:0:Slider"),
+            ("JOYSTICK:1:rzaxis", "joystick:1:RzAxis"),
+            ("joystick:0:button:3", "joystick:0:button:3"),
+            ("joystick:2:pov", "joystick:2:POV"),
+        ] {
+            let parsed: InputDefinition = definition.parse().unwrap();
+            assert_eq!(parsed.to_string(), canonical);
+            assert_eq!(canonical.parse::<InputDefinition>(), Ok(parsed));
+        }
+
+        for (definition, reason) in [
+            ("", "unknown key"),
+            ("ctrl+", "unknown key"),
+            ("ctrl+ctrl+a", "repeated modifier"),
+            ("a+b", "more than one key"),
+            ("shift", "missing key"),
+            ("F25", "unknown key"),
+            ("joystick:x:XAxis", "invalid joystick number"),
+            ("joystick:0:button", "invalid button number"),
+            ("joystick:0:wheel", "unknown joystick input"),
+        ] {
+            assert_eq!(definition.parse::<InputDefinition>().unwrap_err().reason, reason, "{definition}");
+        }
+    }
+
+    #[test]
+    fn load() {
+        let gear = InputEvents::down(ClientEventId::new(1));
+        let config = "# controls\nGear = g\n\nGear = joystick:0:button:1\n";
+        let mapping = InputMapping::new().load(config, |name| (name == "Gear").then_some(gear)).unwrap();
+        assert_eq!(mapping.inputs(), &[
+            ("G".parse().unwrap(), gear),
+            ("joystick:0:button:1".parse().unwrap(), gear),
+        ]);
+
+        let error = InputMapping::new().load("Gear = g\nFlaps = f", |name| (name == "Gear").then_some(gear)).unwrap_err();
+        assert_eq!(error.to_string(), "line 2: unknown name: \"Flaps = f\"");
+        let error = InputMapping::new().load("Gear = ctrl+", |_| Some(gear)).unwrap_err();
+        assert_eq!((error.line, error.reason), (Some(1), "unknown key"));
+    }
+
+    #[test]
+    fn map_inputs() {
+        let server = MockServer::start().unwrap();
+        let sc = server.connect("Test").unwrap();
+
+        let (down, up) = (sc.new_client_event_id(), sc.new_client_event_id());
+        let mapping = InputMapping::new().bind("ctrl+b".parse().unwrap(), InputEvents::down_up((down, 1), (up, 0)));
+        let inputs = sc.map_inputs(&mapping).unwrap();
+        server.wait_for(TIMEOUT, |r| matches!(r.request, Request::SetInputGroupState { on: true, .. })).unwrap();
+
+        // mapped with the _EX1 function where the sdk has it
+        #[cfg(sdk_at_least_0_24_3)]
+        use Request::MapInputEventToClientEventEx1 as MapInputEvent;
+        #[cfg(not(sdk_at_least_0_24_3))]
+        use Request::MapInputEventToClientEvent as MapInputEvent;
+        assert!(server.requests().iter().any(|r| r.request == MapInputEvent {
+            group_id: inputs.group_id(),
+            definition: "Ctrl+B".to_string(),
+            down_event_id: down.get(),
+            down_value: 1,
+            up_event_id: up.get(),
+            up_value: 0,
+            maskable: false,
+        }));
+
+        server.send_input("B+control", true);
+        server.send_input("ctrl+b", false);
+        for (event_id, data) in [(down, 1), (up, 0)] {
+            let Recv::Event(event) = next_matching(&sc, |r| matches!(r, Recv::Event(_))) else { unreachable!() };
+            assert_eq!((event.group_id.map(|id| id.get()), event.event_id, event.data),
+                (Some(inputs.group_id().get()), event_id, data));
+        }
+
+        let group_id = inputs.group_id();
+        drop(inputs);
+        server.wait_for(TIMEOUT, |r| r.request == Request::ClearInputGroup { group_id }).unwrap();
+    }
+}
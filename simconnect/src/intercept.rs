@@ -0,0 +1,224 @@
+use std::fmt;
+
+use simconnect_sys::SIMCONNECT_GROUP_PRIORITY_HIGHEST_MASKABLE;
+
+use crate::connection::SimConnect;
+use crate::error::Result;
+use crate::flags::EventFlags;
+use crate::ids::{ClientEventId, GroupId};
+use crate::recv::Recv;
+use crate::types::OBJECT_ID_USER;
+
+/// What an interception handler does with an intercepted event.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InterceptAction {
+
+    /// Let the event through with its value.
+    Pass,
+
+    /// Let the event through with another value.
+    Modify(u32),
+
+    /// Transmit another client event instead, e.g. one mapped with
+    /// [`SimConnect::map_events`].
+    Replace {
+        event_id: ClientEventId,
+        data: u32,
+    },
+
+    /// Swallow the event, so only clients of a higher priority saw it.
+    Swallow,
+}
+
+/// Optional parameters of [`SimConnect::intercept_with`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InterceptOptions {
+
+    /// Priority of the notification group, one of the
+    /// `SIMCONNECT_GROUP_PRIORITY_*` values.
+    pub priority: u32,
+
+    /// Whether the events are masked, so lower priority clients and the
+    /// simulator only see what the handler lets through. Only groups with a
+    /// priority up to `SIMCONNECT_GROUP_PRIORITY_HIGHEST_MASKABLE` can mask.
+    pub maskable: bool,
+}
+
+impl Default for InterceptOptions {
+
+    fn default() -> Self {
+        Self { priority: SIMCONNECT_GROUP_PRIORITY_HIGHEST_MASKABLE, maskable: true }
+    }
+}
+
+/// Simulation events captured in a notification group of their own and
+/// handled by a closure, released when dropped.
+///
+/// Created with [`SimConnect::intercept`], received messages are handed to
+/// [`Interceptor::handle`] which calls the closure for the intercepted events
+/// and transmits what it returns to the user aircraft, at the group's
+/// priority so it isn't intercepted again.
+///
+/// Without masking the event reaches the simulator regardless, so `Pass` and
+/// `Swallow` do nothing and `Modify` and `Replace` transmit a second event.
+#[must_use = "the events are no longer intercepted when the interceptor is dropped"]
+pub struct Interceptor<'a, F> {
+    sc: &'a SimConnect,
+    group_id: GroupId,
+    options: InterceptOptions,
+    events: Vec<(String, ClientEventId)>,
+    handler: F,
+}
+
+impl SimConnect {
+
+    /// Intercepts the simulation events `names` at the highest maskable
+    /// priority, masking them so the simulator only sees what `handler`
+    /// lets through.
+    ///
+    /// ```no_run
+    /// use simconnect::{InterceptAction, SimConnect};
+    ///
+    /// let sc = SimConnect::open("Example")?;
+    ///
+    /// // limits the throttles to 80%
+    /// let mut limiter = sc.intercept(&["THROTTLE_SET", "THROTTLE_FULL"], |name, value| match name {
+    ///     "THROTTLE_FULL" => InterceptAction::Swallow,
+    ///     _ => InterceptAction::Modify(value.min(13106)),
+    /// })?;
+    /// for recv in sc.iter() {
+    ///     limiter.handle(&recv?)?;
+    /// }
+    /// # Ok::<(), simconnect::Error>(())
+    /// ```
+    pub fn intercept<F>(&self, names: &[&str], handler: F) -> Result<Interceptor<'_, F>>
+    where
+        F: FnMut(&str, u32) -> InterceptAction,
+    {
+        self.intercept_with(names, InterceptOptions::default(), handler)
+    }
+
+    /// Intercepts the simulation events `names` in a newly allocated
+    /// notification group, with the priority and masking given by `options`.
+    pub fn intercept_with<F>(&self, names: &[&str], options: InterceptOptions, handler: F) -> Result<Interceptor<'_, F>>
+    where
+        F: FnMut(&str, u32) -> InterceptAction,
+    {
+        // dropping the interceptor clears and releases the group on failure
+        let mut interceptor = Interceptor { sc: self, group_id: self.new_group_id(), options, events: Vec::new(), handler };
+        for name in names {
+            let event_id = self.new_client_event_id();
+            if let Err(e) = self.map_client_event_to_sim_event(event_id, name) {
+                self.release_client_event_id(event_id);
+                return Err(e);
+            }
+            interceptor.events.push((name.to_string(), event_id));
+            self.add_client_event_to_notification_group(interceptor.group_id, event_id, options.maskable)?;
+        }
+        self.set_notification_group_priority(interceptor.group_id, options.priority)?;
+        Ok(interceptor)
+    }
+}
+
+impl<F: FnMut(&str, u32) -> InterceptAction> Interceptor<'_, F> {
+
+    /// The notification group the events are intercepted in.
+    pub fn group_id(&self) -> GroupId {
+        self.group_id
+    }
+
+    /// The client event id the event `name` is intercepted as.
+    pub fn event_id(&self, name: &str) -> Option<ClientEventId> {
+        self.events.iter().find(|(n, _)| n.eq_ignore_ascii_case(name)).map(|(_, event_id)| *event_id)
+    }
+
+    /// Calls the handler if `recv` is an intercepted event, and transmits
+    /// the event it lets through.
+    ///
+    /// Returns whether `recv` was intercepted.
+    pub fn handle(&mut self, recv: &Recv) -> Result<bool> {
+        let Recv::Event(event) = recv else {
+            return Ok(false);
+        };
+        if event.group_id != Some(self.group_id) {
+            return Ok(false);
+        }
+        let Some((name, event_id)) = self.events.iter().find(|(_, event_id)| *event_id == event.event_id) else {
+            return Ok(false);
+        };
+
+        let (event_id, data) = match (self.handler)(name, event.data) {
+            InterceptAction::Pass if self.options.maskable => (*event_id, event.data),
+            InterceptAction::Modify(data) => (*event_id, data),
+            InterceptAction::Replace { event_id, data } => (event_id, data),
+            InterceptAction::Pass | InterceptAction::Swallow => return Ok(true),
+        };
+        self.sc.transmit_client_event_with_priority(OBJECT_ID_USER, event_id, data, self.options.priority,
+            EventFlags::empty())?;
+        Ok(true)
+    }
+}
+
+impl<F> fmt::Debug for Interceptor<'_, F> {
+
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Interceptor")
+            .field("group_id", &self.group_id)
+            .field("options", &self.options)
+            .field("events", &self.events)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<F> Drop for Interceptor<'_, F> {
+
+    fn drop(&mut self) {
+        // client events can't be unmapped, so their ids aren't handed back
+        let _ = self.sc.clear_notification_group(self.group_id);
+        self.sc.release_group_id(self.group_id);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+    use crate::mock::{next_matching, MockServer, Request, TIMEOUT};
+
+    #[test]
+    fn limit_throttle() {
+        let server = MockServer::start().unwrap();
+        let sc = server.connect("Interceptor").unwrap();
+        let observer = server.connect("Observer").unwrap();
+        let is_event = |r: &Recv| matches!(r, Recv::Event(_));
+
+        // the observer stands in for the simulator, at the default priority
+        let group_id = observer.new_group_id();
+        let event_id = observer.new_client_event_id();
+        observer.map_client_event_to_sim_event(event_id, "THROTTLE_SET").unwrap();
+        observer.add_client_event_to_notification_group(group_id, event_id, false).unwrap();
+
+        let mut limiter = sc.intercept(&["THROTTLE_SET"], |_, value| match value {
+            0 => InterceptAction::Swallow,
+            value => InterceptAction::Modify(value.min(1000)),
+        }).unwrap();
+        server.wait_for(TIMEOUT, |r| matches!(r.request, Request::SetNotificationGroupPriority { .. })).unwrap();
+        server.wait_for(TIMEOUT, |r| matches!(r.request, Request::AddClientEventToNotificationGroup { .. }
+            if r.client == 1)).unwrap();
+
+        // masked, so only the modified event reaches the observer
+        server.send_event("THROTTLE_SET", 5000);
+        assert!(limiter.handle(&next_matching(&sc, is_event)).unwrap());
+        let Recv::Event(event) = next_matching(&observer, is_event) else { unreachable!() };
+        assert_eq!((event.event_id, event.data), (event_id, 1000));
+
+        server.send_event("THROTTLE_SET", 0);
+        assert!(limiter.handle(&next_matching(&sc, is_event)).unwrap());
+        assert_eq!(observer.recv_timeout(Duration::from_millis(200)).unwrap(), None);
+
+        let group_id = limiter.group_id();
+        drop(limiter);
+        server.wait_for(TIMEOUT, |r| r.request == Request::ClearNotificationGroup { group_id }).unwrap();
+    }
+}
@@ -0,0 +1,283 @@
+//! Catalog of the key events (simulation events) documented in the SDK, to be
+//! mapped to client events with `SimConnect_MapClientEventToSimEvent`.
+//!
+//! ```
+//! use simconnect::key_events::{self, KeyEvent};
+//!
+//! assert_eq!(key_events::THROTTLE_SET.range, Some((0, 16383)));
+//! assert_eq!(KeyEvent::find("gear_toggle"), Some(&key_events::GEAR_TOGGLE));
+//! ```
+//!
+//! The catalog covers the commonly used events and is not exhaustive, names
+//! missing from it can still be mapped and only cause a warning.
+
+/// A key event, with the meaning of its parameter if it takes one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct KeyEvent {
+    pub name: &'static str,
+    pub description: &'static str,
+
+    /// What the event's data is, `None` for events without a parameter.
+    pub parameter: Option<&'static str>,
+
+    /// The inclusive range of the parameter, where it has one.
+    pub range: Option<(i32, i32)>,
+}
+
+impl KeyEvent {
+
+    /// Returns the catalogued event named `name`, ignoring case as SimConnect does.
+    pub fn find(name: &str) -> Option<&'static KeyEvent> {
+        ALL.iter().find(|event| event.name.eq_ignore_ascii_case(name))
+    }
+
+    /// Returns whether `value` is a valid parameter, always true for events
+    /// without a range.
+    pub fn accepts(&self, value: i32) -> bool {
+        self.range.is_none_or(|(min, max)| (min..=max).contains(&value))
+    }
+}
+
+/// Returns whether `name` is in the catalog, ignoring case.
+///
+/// Usable in constants, `#[derive(SimEvents)]` checks event names with it at
+/// compile time and warns about unknown ones.
+pub const fn is_known(name: &str) -> bool {
+    let mut i = 0;
+    while i < ALL.len() {
+        if ALL[i].name.eq_ignore_ascii_case(name) {
+            return true;
+        }
+        i += 1;
+    }
+    false
+}
+
+macro_rules! key_events {
+    ($($name:ident => $description:literal $(, $parameter:literal $([$min:expr, $max:expr])?)?;)*) => {
+
+        $(
+            #[doc = concat!("`", stringify!($name), "`: ", $description, ".")]
+            pub const $name: KeyEvent = KeyEvent {
+                name: stringify!($name),
+                description: $description,
+                parameter: key_events!(@parameter $($parameter)?),
+                range: key_events!(@range $($($min, $max)?)?),
+            };
+        )*
+
+        /// Every catalogued event.
+        pub const ALL: &[KeyEvent] = &[$($name,)*];
+    };
+    (@parameter) => { None };
+    (@parameter $parameter:literal) => { Some($parameter) };
+    (@range) => { None };
+    (@range $min:expr, $max:expr) => { Some(($min, $max)) };
+}
+
+key_events! {
+
+    // engine
+    THROTTLE_FULL => "Set throttles max";
+    THROTTLE_INCR => "Increment throttles";
+    THROTTLE_INCR_SMALL => "Increment throttles small";
+    THROTTLE_DECR => "Decrement throttles";
+    THROTTLE_DECR_SMALL => "Decrease throttles small";
+    THROTTLE_CUT => "Set throttles to idle";
+    THROTTLE_SET => "Set throttles exactly", "position" [0, 16383];
+    AXIS_THROTTLE_SET => "Set throttles from an axis", "position" [-16383, 16383];
+    THROTTLE1_SET => "Set throttle 1 exactly", "position" [0, 16383];
+    THROTTLE2_SET => "Set throttle 2 exactly", "position" [0, 16383];
+    THROTTLE3_SET => "Set throttle 3 exactly", "position" [0, 16383];
+    THROTTLE4_SET => "Set throttle 4 exactly", "position" [0, 16383];
+    AXIS_THROTTLE1_SET => "Set throttle 1 from an axis", "position" [-16383, 16383];
+    AXIS_THROTTLE2_SET => "Set throttle 2 from an axis", "position" [-16383, 16383];
+    AXIS_THROTTLE3_SET => "Set throttle 3 from an axis", "position" [-16383, 16383];
+    AXIS_THROTTLE4_SET => "Set throttle 4 from an axis", "position" [-16383, 16383];
+    MIXTURE_RICH => "Set mixtures to max rich";
+    MIXTURE_LEAN => "Set mixtures to max lean";
+    MIXTURE_INCR => "Increment mixtures";
+    MIXTURE_DECR => "Decrement mixtures";
+    MIXTURE_SET => "Set mixtures exactly", "position" [0, 16383];
+    AXIS_MIXTURE_SET => "Set mixtures from an axis", "position" [-16383, 16383];
+    PROP_PITCH_INCR => "Increment prop pitch";
+    PROP_PITCH_DECR => "Decrement prop pitch";
+    PROP_PITCH_SET => "Set prop pitch exactly", "position" [0, 16383];
+    AXIS_PROPELLER_SET => "Set prop pitch from an axis", "position" [-16383, 16383];
+    MAGNETO => "Select magnetos of the selected engine";
+    MAGNETO_OFF => "Set engine magnetos off";
+    MAGNETO_RIGHT => "Toggle right magnetos";
+    MAGNETO_LEFT => "Toggle left magnetos";
+    MAGNETO_BOTH => "Set engine magnetos on";
+    MAGNETO_START => "Set engine magnetos to start";
+    ENGINE_AUTO_START => "Trigger the auto-start of all engines";
+    ENGINE_AUTO_SHUTDOWN => "Trigger the auto-shutdown of all engines";
+    FUEL_PUMP => "Toggle the electric fuel pumps";
+
+    // flight controls
+    AXIS_ELEVATOR_SET => "Set elevator position", "position" [-16383, 16383];
+    ELEVATOR_UP => "Increment elevator up";
+    ELEVATOR_DOWN => "Increment elevator down";
+    AXIS_AILERONS_SET => "Set aileron position", "position" [-16383, 16383];
+    AILERONS_LEFT => "Increment ailerons left";
+    AILERONS_RIGHT => "Increment ailerons right";
+    AXIS_RUDDER_SET => "Set rudder position", "position" [-16383, 16383];
+    RUDDER_LEFT => "Increment rudder left";
+    RUDDER_RIGHT => "Increment rudder right";
+    RUDDER_CENTER => "Center the rudder";
+    CENTER_AILER_RUDDER => "Center the ailerons and rudder";
+    ELEV_TRIM_UP => "Increment elevator trim up";
+    ELEV_TRIM_DN => "Increment elevator trim down";
+    ELEVATOR_TRIM_SET => "Set elevator trim position", "position" [-16383, 16383];
+    AXIS_ELEV_TRIM_SET => "Set elevator trim from an axis", "position" [-16383, 16383];
+    AILERON_TRIM_LEFT => "Increment aileron trim left";
+    AILERON_TRIM_RIGHT => "Increment aileron trim right";
+    RUDDER_TRIM_LEFT => "Increment rudder trim left";
+    RUDDER_TRIM_RIGHT => "Increment rudder trim right";
+    FLAPS_UP => "Retract flaps fully";
+    FLAPS_1 => "Set flaps to the first extension position";
+    FLAPS_2 => "Set flaps to the second extension position";
+    FLAPS_3 => "Set flaps to the third extension position";
+    FLAPS_DOWN => "Extend flaps fully";
+    FLAPS_INCR => "Increment flap handle position";
+    FLAPS_DECR => "Decrement flap handle position";
+    FLAPS_SET => "Set flap handle to the closest position", "position" [0, 16383];
+    AXIS_FLAPS_SET => "Set flap handle from an axis", "position" [-16383, 16383];
+    SPOILERS_ON => "Set spoiler handle to fully extended";
+    SPOILERS_OFF => "Set spoiler handle to fully retracted";
+    SPOILERS_TOGGLE => "Toggle spoilers";
+    SPOILERS_ARM_TOGGLE => "Toggle arming of the auto-spoilers";
+    SPOILERS_SET => "Set spoiler handle position", "position" [0, 16383];
+    AXIS_SPOILER_SET => "Set spoiler handle from an axis", "position" [-16383, 16383];
+
+    // brakes and gear
+    BRAKES => "Increment brake pressure";
+    BRAKES_LEFT => "Increment left brake pressure";
+    BRAKES_RIGHT => "Increment right brake pressure";
+    AXIS_LEFT_BRAKE_SET => "Set left brake position", "position" [-16383, 16383];
+    AXIS_RIGHT_BRAKE_SET => "Set right brake position", "position" [-16383, 16383];
+    PARKING_BRAKES => "Toggle the parking brake";
+    GEAR_UP => "Set gear handle up";
+    GEAR_DOWN => "Set gear handle down";
+    GEAR_TOGGLE => "Toggle gear handle";
+    GEAR_SET => "Set gear handle position", "0 for up, 1 for down" [0, 1];
+
+    // autopilot
+    AP_MASTER => "Toggle the autopilot";
+    AUTOPILOT_ON => "Turn the autopilot on";
+    AUTOPILOT_OFF => "Turn the autopilot off";
+    AP_HDG_HOLD => "Toggle heading hold mode";
+    AP_HDG_HOLD_ON => "Turn heading hold mode on";
+    AP_HDG_HOLD_OFF => "Turn heading hold mode off";
+    AP_ALT_HOLD => "Toggle altitude hold mode";
+    AP_ALT_HOLD_ON => "Turn altitude hold mode on";
+    AP_ALT_HOLD_OFF => "Turn altitude hold mode off";
+    AP_NAV1_HOLD => "Toggle NAV 1 hold mode";
+    AP_APR_HOLD => "Toggle approach hold mode";
+    AP_BC_HOLD => "Toggle back course mode";
+    AP_WING_LEVELER => "Toggle wing leveler mode";
+    AP_AIRSPEED_HOLD => "Toggle airspeed hold mode";
+    AP_MACH_HOLD => "Toggle mach hold mode";
+    YAW_DAMPER_TOGGLE => "Toggle the yaw damper";
+    FLIGHT_LEVEL_CHANGE => "Toggle flight level change mode";
+    AUTO_THROTTLE_ARM => "Toggle arming of the autothrottle";
+    AUTO_THROTTLE_TO_GA => "Toggle the autothrottle takeoff/go-around mode";
+    HEADING_BUG_INC => "Increment the heading bug";
+    HEADING_BUG_DEC => "Decrement the heading bug";
+    HEADING_BUG_SET => "Set the heading bug", "degrees" [0, 359];
+    AP_ALT_VAR_INC => "Increment the reference altitude";
+    AP_ALT_VAR_DEC => "Decrement the reference altitude";
+    AP_ALT_VAR_SET_ENGLISH => "Set the reference altitude", "feet";
+    AP_VS_VAR_INC => "Increment the reference vertical speed";
+    AP_VS_VAR_DEC => "Decrement the reference vertical speed";
+    AP_VS_VAR_SET_ENGLISH => "Set the reference vertical speed", "feet per minute";
+    AP_SPD_VAR_INC => "Increment the reference airspeed";
+    AP_SPD_VAR_DEC => "Decrement the reference airspeed";
+    AP_SPD_VAR_SET => "Set the reference airspeed", "knots";
+
+    // radios
+    COM_RADIO_SET => "Set COM 1 frequency", "BCD16 encoded MHz, e.g. 0x2345 for 123.45";
+    COM_RADIO_SET_HZ => "Set COM 1 frequency", "Hz";
+    COM_STBY_RADIO_SET => "Set COM 1 standby frequency", "BCD16 encoded MHz, e.g. 0x2345 for 123.45";
+    COM_STBY_RADIO_SET_HZ => "Set COM 1 standby frequency", "Hz";
+    COM_STBY_RADIO_SWAP => "Swap COM 1 frequency with standby";
+    COM2_RADIO_SET => "Set COM 2 frequency", "BCD16 encoded MHz, e.g. 0x2345 for 123.45";
+    COM2_RADIO_SET_HZ => "Set COM 2 frequency", "Hz";
+    COM2_STBY_RADIO_SET => "Set COM 2 standby frequency", "BCD16 encoded MHz, e.g. 0x2345 for 123.45";
+    COM2_RADIO_SWAP => "Swap COM 2 frequency with standby";
+    NAV1_RADIO_SET => "Set NAV 1 frequency", "BCD16 encoded MHz, e.g. 0x1130 for 111.30";
+    NAV1_STBY_SET => "Set NAV 1 standby frequency", "BCD16 encoded MHz, e.g. 0x1130 for 111.30";
+    NAV1_RADIO_SWAP => "Swap NAV 1 frequency with standby";
+    NAV2_RADIO_SET => "Set NAV 2 frequency", "BCD16 encoded MHz, e.g. 0x1130 for 111.30";
+    NAV2_STBY_SET => "Set NAV 2 standby frequency", "BCD16 encoded MHz, e.g. 0x1130 for 111.30";
+    NAV2_RADIO_SWAP => "Swap NAV 2 frequency with standby";
+    ADF_COMPLETE_SET => "Set ADF frequency", "BCD32 encoded Hz";
+    XPNDR_SET => "Set the transponder code", "BCO16 encoded code, e.g. 0x1200";
+    KOHLSMAN_INC => "Increment the altimeter setting";
+    KOHLSMAN_DEC => "Decrement the altimeter setting";
+    KOHLSMAN_SET => "Set the altimeter setting", "millibars * 16";
+    BAROMETRIC => "Sync the altimeter setting to the sea level pressure";
+
+    // lights
+    LANDING_LIGHTS_TOGGLE => "Toggle the landing lights";
+    LANDING_LIGHTS_ON => "Turn the landing lights on";
+    LANDING_LIGHTS_OFF => "Turn the landing lights off";
+    STROBES_TOGGLE => "Toggle the strobe lights";
+    STROBES_ON => "Turn the strobe lights on";
+    STROBES_OFF => "Turn the strobe lights off";
+    TOGGLE_BEACON_LIGHTS => "Toggle the beacon lights";
+    TOGGLE_NAV_LIGHTS => "Toggle the navigation lights";
+    TOGGLE_TAXI_LIGHTS => "Toggle the taxi lights";
+    TOGGLE_LOGO_LIGHTS => "Toggle the logo lights";
+    TOGGLE_WING_LIGHTS => "Toggle the wing lights";
+    TOGGLE_RECOGNITION_LIGHTS => "Toggle the recognition lights";
+    PANEL_LIGHTS_TOGGLE => "Toggle the panel lights";
+    ALL_LIGHTS_TOGGLE => "Toggle all lights";
+
+    // electrical and anti-ice
+    TOGGLE_MASTER_BATTERY => "Toggle the master battery switch";
+    TOGGLE_MASTER_ALTERNATOR => "Toggle the master alternator switch";
+    TOGGLE_MASTER_BATTERY_ALTERNATOR => "Toggle the master battery and alternator switches";
+    TOGGLE_AVIONICS_MASTER => "Toggle the avionics master switch";
+    PITOT_HEAT_TOGGLE => "Toggle pitot heat";
+    PITOT_HEAT_ON => "Turn pitot heat on";
+    PITOT_HEAT_OFF => "Turn pitot heat off";
+
+    // simulation
+    PAUSE_TOGGLE => "Toggle pause";
+    PAUSE_ON => "Pause the simulation";
+    PAUSE_OFF => "Unpause the simulation";
+    PAUSE_SET => "Set the pause state", "1 to pause, 0 to unpause" [0, 1];
+    SIM_RATE_INCR => "Increase the simulation rate";
+    SIM_RATE_DECR => "Decrease the simulation rate";
+    SLEW_TOGGLE => "Toggle slew mode";
+    SLEW_ON => "Turn slew mode on";
+    SLEW_OFF => "Turn slew mode off";
+    FREEZE_LATITUDE_LONGITUDE_TOGGLE => "Toggle freezing of the latitude and longitude";
+    FREEZE_ALTITUDE_TOGGLE => "Toggle freezing of the altitude";
+    FREEZE_ATTITUDE_TOGGLE => "Toggle freezing of the attitude";
+    SITUATION_RESET => "Reset the current flight";
+    SMOKE_TOGGLE => "Toggle the smoke system";
+    SMOKE_ON => "Turn the smoke system on";
+    SMOKE_OFF => "Turn the smoke system off";
+    TOGGLE_PUSHBACK => "Toggle pushback";
+    TOW_PLANE_RELEASE => "Release the tow rope";
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn catalog() {
+        for (i, event) in ALL.iter().enumerate() {
+            assert!(ALL[..i].iter().all(|other| other.name != event.name), "{} is listed twice", event.name);
+            if let Some((min, max)) = event.range {
+                assert!(event.parameter.is_some() && min < max, "{} has an invalid range", event.name);
+            }
+        }
+        assert_eq!(KeyEvent::find("axis_elevator_set"), Some(&AXIS_ELEVATOR_SET));
+        assert!(THROTTLE_SET.accepts(16383) && !THROTTLE_SET.accepts(-1));
+        assert!(is_known("Brakes") && !is_known("BRAKE"));
+    }
+}
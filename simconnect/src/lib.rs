@@ -0,0 +1,89 @@
+//! Safe Rust bindings for SimConnect, built on top of [`simconnect_sys`].
+//!
+//! ```no_run
+//! use simconnect::SimConnect;
+//!
+//! // the connection is closed when `sc` is dropped
+//! let sc = SimConnect::open("Example")?;
+//! # Ok::<(), simconnect::Error>(())
+//! ```
+
+mod ai;
+mod connection;
+mod data;
+mod dispatch;
+mod error;
+mod events;
+mod facilities;
+mod flags;
+#[cfg(windows)]
+mod ffi;
+mod ids;
+mod input;
+mod intercept;
+pub mod key_events;
+#[cfg(any(test, feature = "mock"))]
+pub mod mock;
+mod net;
+mod record;
+mod recv;
+mod request;
+mod sim_data;
+mod sim_events;
+#[cfg(feature = "async")]
+mod stream;
+mod string;
+mod system_event;
+mod types;
+
+pub use connection::{OpenOptions, SimConnect};
+pub use data::RequestOptions;
+pub use dispatch::{Iter, TryIter};
+pub use error::{Error, Exception, ExceptionKind, Result, UnknownValue};
+pub use facilities::{
+    Airport, ControllerItem, FacilityMinimal, HardwareVersion, Icao, InputEventDescriptor,
+    InputEventType, JetwayData, Ndb, Vor, Waypoint,
+};
+pub use flags::{
+    ClientDataRequestFlags, ClientDataSetFlags, CreateClientDataFlags, DataRequestFlags, DataSetFlags, EventFlags,
+    WaypointFlags,
+};
+pub use net::Protocol;
+pub use ids::{ClientDataId, ClientEventId, DefineId, GroupId, InputGroupId, RequestId};
+pub use intercept::{InterceptAction, InterceptOptions, Interceptor};
+pub use input::{
+    InputDefinition, InputEvents, InputGroup, InputMapping, JoystickAxis, JoystickInput, Key, KeyModifiers,
+    ParseInputError,
+};
+pub use record::{Entry, Recording};
+pub use recv::{
+    DecodeError, Event, FacilityData, InputEventValue, List, Open, RaceResult, Reader, Recv,
+    SimObjectData, SystemState, Version,
+};
+pub use sim_data::{decode_tagged, DataField, DataValue, Datum, SimData};
+pub use sim_events::{EventMap, SimEvents};
+#[cfg(feature = "async")]
+pub use stream::{AsyncSimConnect, Messages, ReservedKey, Response};
+pub use string::{
+    insert_string, retrieve_string, FixedString, String128, String256, String260, String32, String64,
+    String8,
+};
+pub use system_event::{SystemEvent, SystemEventData, SystemEventSubscription};
+pub use types::{
+    ClientDataPeriod, DataType, FacilityListType, Guid, InitPosition, LatLonAlt, Pbh, Period, RecvId, SimObjectType,
+    Xyz, OBJECT_ID_USER,
+};
+
+/// Derives [`SimData`] for a struct, see the trait for an example.
+#[cfg(feature = "derive")]
+pub use simconnect_derive::SimData;
+
+/// Derives [`SimEvents`] for a fieldless enum, see the trait for an example.
+#[cfg(feature = "derive")]
+pub use simconnect_derive::SimEvents;
+
+// lets the derive macros refer to `::simconnect` from within this crate
+extern crate self as simconnect;
+
+/// Raw FFI bindings, re-exported for anything not yet covered by this crate.
+pub use simconnect_sys as sys;
@@ -0,0 +1,1453 @@
+//! An in-process SimConnect server for testing clients without the simulator.
+//!
+//! [`MockServer`] listens on localhost and speaks the same network protocol
+//! as [`SimConnect::connect`], so application code runs unchanged against it.
+//! It keeps a store of simulation variables and objects, answers data, system
+//! state and AI requests from it, and records every request so tests can
+//! assert on what a client sent.
+//!
+//! ```
+//! use simconnect::mock::{MockServer, Request};
+//! use simconnect::{Recv, OBJECT_ID_USER};
+//! use std::time::Duration;
+//!
+//! let server = MockServer::start()?;
+//! server.set_simvar(OBJECT_ID_USER, "PLANE ALTITUDE", 5000.0);
+//!
+//! let sc = server.connect("Test")?;
+//! let event_id = sc.new_client_event_id();
+//! sc.subscribe_to_system_event(event_id, "Pause")?;
+//!
+//! // requests are handled on a background thread, wait for it before acting
+//! server.wait_for(Duration::from_secs(5), |r| matches!(r.request, Request::SubscribeToSystemEvent { .. }));
+//! server.send_system_event("Pause", 1);
+//! for recv in sc.iter() {
+//!     if let Recv::Event(event) = recv? {
+//!         assert_eq!(event.data, 1);
+//!         break;
+//!     }
+//! }
+//! # Ok::<(), simconnect::Error>(())
+//! ```
+//!
+//! Values are stored in whatever units the test sets them in, units given in
+//! data definitions are recorded but not converted. Radius limits of data
+//! requests by type are ignored.
+//!
+//! Simulation events notify notification groups in order of priority, a
+//! maskable event stops at the first group in the maskable range it is in.
+//! Events transmitted with `EventFlags::GROUPID_IS_PRIORITY` only notify
+//! groups of a lower priority than the one they were transmitted at.
+//!
+//! Reserved key requests get the first choice that isn't bound with
+//! [`MockServer::bind_key`] or reserved by a client, without the prefix the
+//! simulator may add to it.
+
+use std::collections::{BTreeMap, HashMap};
+use std::io::{self, Write};
+use std::net::{Shutdown, SocketAddr, TcpListener, TcpStream};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Condvar, Mutex, MutexGuard, PoisonError};
+use std::thread::JoinHandle;
+use std::time::{Duration, Instant};
+
+use simconnect_sys::*;
+
+use crate::connection::SimConnect;
+use crate::data::RequestOptions;
+use crate::error::{ExceptionKind, Result};
+use crate::flags::{DataRequestFlags, DataSetFlags, EventFlags};
+use crate::ids::{ClientEventId, DefineId, GroupId, InputGroupId, RequestId};
+use crate::input::InputDefinition;
+use crate::net::{function, read_packet};
+use crate::recv::{DecodeError, Reader};
+use crate::sim_data::{DataValue, Datum};
+use crate::types::{DataType, InitPosition, LatLonAlt, Period, SimObjectType, Xyz, OBJECT_ID_USER};
+
+/// The object id of the user aircraft, which [`OBJECT_ID_USER`] refers to.
+pub const USER_OBJECT_ID: u32 = 1;
+
+// how often the accept loop checks whether the server was dropped
+const ACCEPT_INTERVAL: Duration = Duration::from_millis(5);
+
+/// A value in the simulation variable store.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+
+    /// Any numeric variable, converted to the requested type when sent.
+    Number(f64),
+    String(String),
+    LatLonAlt(LatLonAlt),
+    Xyz(Xyz),
+    InitPosition(InitPosition),
+}
+
+impl From<f64> for Value {
+    fn from(v: f64) -> Self {
+        Value::Number(v)
+    }
+}
+
+impl From<f32> for Value {
+    fn from(v: f32) -> Self {
+        Value::Number(v.into())
+    }
+}
+
+impl From<i32> for Value {
+    fn from(v: i32) -> Self {
+        Value::Number(v.into())
+    }
+}
+
+impl From<u32> for Value {
+    fn from(v: u32) -> Self {
+        Value::Number(v.into())
+    }
+}
+
+impl From<bool> for Value {
+    fn from(v: bool) -> Self {
+        Value::Number(if v { 1.0 } else { 0.0 })
+    }
+}
+
+impl From<&str> for Value {
+    fn from(v: &str) -> Self {
+        Value::String(v.to_string())
+    }
+}
+
+impl From<String> for Value {
+    fn from(v: String) -> Self {
+        Value::String(v)
+    }
+}
+
+impl From<LatLonAlt> for Value {
+    fn from(v: LatLonAlt) -> Self {
+        Value::LatLonAlt(v)
+    }
+}
+
+impl From<Xyz> for Value {
+    fn from(v: Xyz) -> Self {
+        Value::Xyz(v)
+    }
+}
+
+impl From<InitPosition> for Value {
+    fn from(v: InitPosition) -> Self {
+        Value::InitPosition(v)
+    }
+}
+
+/// A request received by a [`MockServer`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Received {
+
+    /// Numbers clients in the order they connected, starting at 0.
+    pub client: usize,
+    pub send_id: u32,
+    pub request: Request,
+}
+
+/// A decoded request, named after the SimConnect function that sent it.
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum Request {
+    Open {
+        name: String,
+    },
+    MapClientEventToSimEvent {
+        event_id: ClientEventId,
+        name: String,
+    },
+    TransmitClientEvent {
+        object_id: u32,
+        event_id: ClientEventId,
+        data: u32,
+        group_id: u32,
+        flags: EventFlags,
+    },
+    #[cfg(sdk_at_least_0_24_3)]
+    TransmitClientEventEx1 {
+        object_id: u32,
+        event_id: ClientEventId,
+        group_id: u32,
+        flags: EventFlags,
+        data: [u32; 5],
+    },
+    SetSystemEventState {
+        event_id: ClientEventId,
+        on: bool,
+    },
+    AddClientEventToNotificationGroup {
+        group_id: GroupId,
+        event_id: ClientEventId,
+        maskable: bool,
+    },
+    RemoveClientEvent {
+        group_id: GroupId,
+        event_id: ClientEventId,
+    },
+    SetNotificationGroupPriority {
+        group_id: GroupId,
+        priority: u32,
+    },
+    ClearNotificationGroup {
+        group_id: GroupId,
+    },
+    AddToDataDefinition {
+        define_id: DefineId,
+        name: String,
+        units: String,
+        data_type: DataType,
+        epsilon: f32,
+        datum_id: u32,
+    },
+    ClearDataDefinition {
+        define_id: DefineId,
+    },
+    RequestDataOnSimObject {
+        request_id: RequestId,
+        define_id: DefineId,
+        object_id: u32,
+        period: Period,
+        options: RequestOptions,
+    },
+    RequestDataOnSimObjectType {
+        request_id: RequestId,
+        define_id: DefineId,
+        radius_meters: u32,
+        object_type: SimObjectType,
+    },
+    SetDataOnSimObject {
+        define_id: DefineId,
+        object_id: u32,
+        flags: DataSetFlags,
+        data: Vec<u8>,
+    },
+    MapInputEventToClientEvent {
+        group_id: InputGroupId,
+        definition: String,
+        down_event_id: u32,
+        down_value: u32,
+        up_event_id: u32,
+        up_value: u32,
+        maskable: bool,
+    },
+    #[cfg(sdk_at_least_0_24_3)]
+    MapInputEventToClientEventEx1 {
+        group_id: InputGroupId,
+        definition: String,
+        down_event_id: u32,
+        down_value: u32,
+        up_event_id: u32,
+        up_value: u32,
+        maskable: bool,
+    },
+    SetInputGroupPriority {
+        group_id: InputGroupId,
+        priority: u32,
+    },
+    RemoveInputEvent {
+        group_id: InputGroupId,
+        definition: String,
+    },
+    ClearInputGroup {
+        group_id: InputGroupId,
+    },
+    SetInputGroupState {
+        group_id: InputGroupId,
+        on: bool,
+    },
+    RequestReservedKey {
+        event_id: ClientEventId,
+
+        // the choices that were given, in order
+        choices: Vec<String>,
+    },
+    SubscribeToSystemEvent {
+        event_id: ClientEventId,
+        name: String,
+    },
+    UnsubscribeFromSystemEvent {
+        event_id: ClientEventId,
+    },
+    RequestSystemState {
+        request_id: RequestId,
+        state: String,
+    },
+    AiCreateNonAtcAircraft {
+        container_title: String,
+        tail_number: String,
+        position: InitPosition,
+        request_id: RequestId,
+    },
+    AiCreateSimulatedObject {
+        container_title: String,
+        position: InitPosition,
+        request_id: RequestId,
+    },
+    AiRemoveObject {
+        object_id: u32,
+        request_id: RequestId,
+    },
+
+    /// A request the mock doesn't decode, or one that failed to decode.
+    Other {
+        function: u32,
+        data: Vec<u8>,
+    },
+}
+
+impl Request {
+
+    fn decode(id: u32, data: &[u8]) -> Self {
+        let mut r = Reader::new(id, data);
+        Self::read(id, &mut r).unwrap_or_else(|_| Request::Other { function: id, data: data.to_vec() })
+    }
+
+    fn read(id: u32, r: &mut Reader<'_>) -> Result<Self, DecodeError> {
+        let event_id = |r: &mut Reader<'_>| r.u32().map(ClientEventId::new);
+        let group_id = |r: &mut Reader<'_>| r.u32().map(GroupId::new);
+        let input_group_id = |r: &mut Reader<'_>| r.u32().map(InputGroupId::new);
+        let define_id = |r: &mut Reader<'_>| r.u32().map(DefineId::new);
+        let request_id = |r: &mut Reader<'_>| r.u32().map(RequestId::new);
+
+        Ok(match id {
+            function::OPEN => Request::Open { name: r.string(256)? },
+            function::MAP_CLIENT_EVENT_TO_SIM_EVENT => Request::MapClientEventToSimEvent {
+                event_id: event_id(r)?,
+                name: r.string(256)?,
+            },
+            function::TRANSMIT_CLIENT_EVENT => Request::TransmitClientEvent {
+                object_id: r.u32()?,
+                event_id: event_id(r)?,
+                data: r.u32()?,
+                group_id: r.u32()?,
+                flags: EventFlags::from_bits_retain(r.u32()?),
+            },
+            #[cfg(sdk_at_least_0_24_3)]
+            function::TRANSMIT_CLIENT_EVENT_EX1 => Request::TransmitClientEventEx1 {
+                object_id: r.u32()?,
+                event_id: event_id(r)?,
+                group_id: r.u32()?,
+                flags: EventFlags::from_bits_retain(r.u32()?),
+                data: [r.u32()?, r.u32()?, r.u32()?, r.u32()?, r.u32()?],
+            },
+            function::SET_SYSTEM_EVENT_STATE => Request::SetSystemEventState {
+                event_id: event_id(r)?,
+                on: r.u32()? != 0,
+            },
+            function::ADD_CLIENT_EVENT_TO_NOTIFICATION_GROUP => Request::AddClientEventToNotificationGroup {
+                group_id: group_id(r)?,
+                event_id: event_id(r)?,
+                maskable: r.u32()? != 0,
+            },
+            function::REMOVE_CLIENT_EVENT => Request::RemoveClientEvent {
+                group_id: group_id(r)?,
+                event_id: event_id(r)?,
+            },
+            function::SET_NOTIFICATION_GROUP_PRIORITY => Request::SetNotificationGroupPriority {
+                group_id: group_id(r)?,
+                priority: r.u32()?,
+            },
+            function::CLEAR_NOTIFICATION_GROUP => Request::ClearNotificationGroup { group_id: group_id(r)? },
+            function::ADD_TO_DATA_DEFINITION => Request::AddToDataDefinition {
+                define_id: define_id(r)?,
+                name: r.string(256)?,
+                units: r.string(256)?,
+                data_type: {
+                    let raw = r.u32()?;
+                    DataType::try_from(raw).map_err(|_| r.invalid("DatumType", raw))?
+                },
+                epsilon: r.f32()?,
+                datum_id: r.u32()?,
+            },
+            function::CLEAR_DATA_DEFINITION => Request::ClearDataDefinition { define_id: define_id(r)? },
+            function::REQUEST_DATA_ON_SIM_OBJECT => Request::RequestDataOnSimObject {
+                request_id: request_id(r)?,
+                define_id: define_id(r)?,
+                object_id: r.u32()?,
+                period: {
+                    let raw = r.u32()?;
+                    Period::try_from(raw).map_err(|_| r.invalid("Period", raw))?
+                },
+                options: RequestOptions {
+                    flags: DataRequestFlags::from_bits_retain(r.u32()?),
+                    origin: r.u32()?,
+                    interval: r.u32()?,
+                    limit: r.u32()?,
+                },
+            },
+            function::REQUEST_DATA_ON_SIM_OBJECT_TYPE => Request::RequestDataOnSimObjectType {
+                request_id: request_id(r)?,
+                define_id: define_id(r)?,
+                radius_meters: r.u32()?,
+                object_type: {
+                    let raw = r.u32()?;
+                    SimObjectType::try_from(raw).map_err(|_| r.invalid("type", raw))?
+                },
+            },
+            function::SET_DATA_ON_SIM_OBJECT => {
+                let define_id = define_id(r)?;
+                let object_id = r.u32()?;
+                let flags = DataSetFlags::from_bits_retain(r.u32()?);
+                let _array_count = r.u32()?;
+                let len = r.u32()? as usize;
+                let data = r.take(len)?.to_vec();
+                Request::SetDataOnSimObject { define_id, object_id, flags, data }
+            }
+            function::MAP_INPUT_EVENT_TO_CLIENT_EVENT => Request::MapInputEventToClientEvent {
+                group_id: input_group_id(r)?,
+                definition: r.string(256)?,
+                down_event_id: r.u32()?,
+                down_value: r.u32()?,
+                up_event_id: r.u32()?,
+                up_value: r.u32()?,
+                maskable: r.u32()? != 0,
+            },
+            #[cfg(sdk_at_least_0_24_3)]
+            function::MAP_INPUT_EVENT_TO_CLIENT_EVENT_EX1 => Request::MapInputEventToClientEventEx1 {
+                group_id: input_group_id(r)?,
+                definition: r.string(256)?,
+                down_event_id: r.u32()?,
+                down_value: r.u32()?,
+                up_event_id: r.u32()?,
+                up_value: r.u32()?,
+                maskable: r.u32()? != 0,
+            },
+            function::SET_INPUT_GROUP_PRIORITY => Request::SetInputGroupPriority {
+                group_id: input_group_id(r)?,
+                priority: r.u32()?,
+            },
+            function::REMOVE_INPUT_EVENT => Request::RemoveInputEvent {
+                group_id: input_group_id(r)?,
+                definition: r.string(256)?,
+            },
+            function::CLEAR_INPUT_GROUP => Request::ClearInputGroup { group_id: input_group_id(r)? },
+            function::SET_INPUT_GROUP_STATE => Request::SetInputGroupState {
+                group_id: input_group_id(r)?,
+                on: r.u32()? != 0,
+            },
+            function::REQUEST_RESERVED_KEY => Request::RequestReservedKey {
+                event_id: event_id(r)?,
+                choices: [r.string(30)?, r.string(30)?, r.string(30)?].into_iter().filter(|c| !c.is_empty()).collect(),
+            },
+            function::SUBSCRIBE_TO_SYSTEM_EVENT => Request::SubscribeToSystemEvent {
+                event_id: event_id(r)?,
+                name: r.string(256)?,
+            },
+            function::UNSUBSCRIBE_FROM_SYSTEM_EVENT => Request::UnsubscribeFromSystemEvent { event_id: event_id(r)? },
+            function::REQUEST_SYSTEM_STATE => Request::RequestSystemState {
+                request_id: request_id(r)?,
+                state: r.string(256)?,
+            },
+            function::AI_CREATE_NON_ATC_AIRCRAFT => Request::AiCreateNonAtcAircraft {
+                container_title: r.string(256)?,
+                tail_number: r.string(12)?,
+                position: InitPosition::read(r)?,
+                request_id: request_id(r)?,
+            },
+            function::AI_CREATE_SIMULATED_OBJECT => Request::AiCreateSimulatedObject {
+                container_title: r.string(256)?,
+                position: InitPosition::read(r)?,
+                request_id: request_id(r)?,
+            },
+            function::AI_REMOVE_OBJECT => Request::AiRemoveObject {
+                object_id: r.u32()?,
+                request_id: request_id(r)?,
+            },
+            _ => return Err(r.invalid("function", id)),
+        })
+    }
+}
+
+/// A SimConnect server on localhost for tests, see the [module docs](self).
+///
+/// Every client connection is served by its own thread. The server stops
+/// and disconnects its clients when dropped.
+#[derive(Debug)]
+pub struct MockServer {
+    addr: SocketAddr,
+    shared: Arc<Shared>,
+    accept: Option<JoinHandle<()>>,
+}
+
+#[derive(Debug, Default)]
+struct Shared {
+    sim: Mutex<Sim>,
+    received: Condvar,
+    stop: AtomicBool,
+    threads: Mutex<Vec<JoinHandle<()>>>,
+}
+
+#[derive(Debug)]
+struct Sim {
+    clients: Vec<Client>,
+
+    // keyed by object id and upper case variable name
+    simvars: HashMap<(u32, String), Value>,
+    objects: BTreeMap<u32, SimObjectType>,
+    next_object_id: u32,
+    system_states: HashMap<String, (u32, f32, String)>,
+    requests: Vec<Received>,
+    fail_next: Option<ExceptionKind>,
+
+    // keys bound in the simulator or reserved by a client
+    taken_keys: Vec<String>,
+}
+
+impl Default for Sim {
+    fn default() -> Self {
+        let system_states = [
+            ("aircraftloaded", (0, 0.0, "")),
+            ("dialogmode", (0, 0.0, "")),
+            ("flightloaded", (0, 0.0, "")),
+            ("flightplan", (0, 0.0, "")),
+            ("sim", (1, 0.0, "")),
+        ];
+        Self {
+            clients: Vec::new(),
+            simvars: HashMap::new(),
+            objects: BTreeMap::from([(USER_OBJECT_ID, SimObjectType::Aircraft)]),
+            next_object_id: USER_OBJECT_ID + 1,
+            system_states: system_states.into_iter()
+                .map(|(name, (i, f, s))| (name.to_string(), (i, f, s.to_string())))
+                .collect(),
+            requests: Vec::new(),
+            fail_next: None,
+            taken_keys: Vec::new(),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Client {
+    stream: TcpStream,
+    protocol: u32,
+    connected: bool,
+    definitions: HashMap<DefineId, Vec<Field>>,
+    data_requests: Vec<DataRequest>,
+
+    // client event id to the name it was mapped to, and the groups it is in
+    events: HashMap<ClientEventId, String>,
+    groups: HashMap<GroupId, Group>,
+
+    // client event id to the system event name and whether it is on
+    subscriptions: HashMap<ClientEventId, (String, bool)>,
+
+    // input groups, off until turned on like in the simulator
+    input_groups: HashMap<InputGroupId, InputGroup>,
+}
+
+#[derive(Debug, Default)]
+struct InputGroup {
+    on: bool,
+
+    // definition, down event id and value, up event id and value
+    inputs: Vec<(String, u32, u32, u32, u32)>,
+}
+
+#[derive(Debug)]
+struct Group {
+    priority: u32,
+
+    // client event ids and whether they are maskable
+    events: Vec<(ClientEventId, bool)>,
+}
+
+impl Default for Group {
+
+    fn default() -> Self {
+        Self { priority: SIMCONNECT_GROUP_PRIORITY_DEFAULT, events: Vec::new() }
+    }
+}
+
+#[derive(Debug)]
+struct Field {
+    name: String,
+    data_type: DataType,
+    datum_id: u32,
+}
+
+#[derive(Debug)]
+struct DataRequest {
+    request_id: RequestId,
+    define_id: DefineId,
+    object_id: u32,
+    options: RequestOptions,
+    periods: u32,
+    sent: u32,
+
+    // what was last sent for each field, for SIMCONNECT_DATA_REQUEST_FLAG_CHANGED
+    last: Vec<Option<Vec<u8>>>,
+}
+
+impl DataRequest {
+
+    fn new(request_id: RequestId, define_id: DefineId, object_id: u32, options: RequestOptions) -> Self {
+        Self { request_id, define_id, object_id, options, periods: 0, sent: 0, last: Vec::new() }
+    }
+}
+
+impl MockServer {
+
+    /// Starts a server on an unused port of 127.0.0.1.
+    pub fn start() -> io::Result<Self> {
+        let listener = TcpListener::bind("127.0.0.1:0")?;
+        listener.set_nonblocking(true)?;
+        let addr = listener.local_addr()?;
+        let shared = Arc::new(Shared::default());
+        let accept = {
+            let shared = shared.clone();
+            std::thread::Builder::new()
+                .name("simconnect-mock".into())
+                .spawn(move || accept(listener, &shared))?
+        };
+        Ok(Self { addr, shared, accept: Some(accept) })
+    }
+
+    /// Returns the address the server is listening on.
+    pub fn addr(&self) -> SocketAddr {
+        self.addr
+    }
+
+    /// Connects a new client to the server, see [`SimConnect::connect`].
+    pub fn connect(&self, name: &str) -> Result<SimConnect> {
+        SimConnect::connect(self.addr, name)
+    }
+
+    /// Sets the simulation variable `name` of `object_id`.
+    ///
+    /// Names are case insensitive and include any index, e.g.
+    /// `"GENERAL ENG RPM:1"`.
+    pub fn set_simvar(&self, object_id: u32, name: &str, value: impl Into<Value>) {
+        self.shared.lock().simvars.insert(simvar_key(object_id, name), value.into());
+    }
+
+    /// Returns the simulation variable `name` of `object_id`, if it was set
+    /// by the test or a client.
+    pub fn simvar(&self, object_id: u32, name: &str) -> Option<Value> {
+        self.shared.lock().simvars.get(&simvar_key(object_id, name)).cloned()
+    }
+
+    /// Sets the response to a system state request for `name`.
+    pub fn set_system_state(&self, name: &str, integer: u32, float: f32, string: &str) {
+        self.shared.lock().system_states.insert(name.to_ascii_lowercase(), (integer, float, string.to_string()));
+    }
+
+    /// Adds an object of `object_type`, returning its id.
+    ///
+    /// Clients subscribed to the `ObjectAdded` system event are notified.
+    pub fn add_object(&self, object_type: SimObjectType) -> u32 {
+        self.shared.lock().add_object(object_type)
+    }
+
+    /// Removes the object `object_id` along with its variables.
+    ///
+    /// Clients subscribed to the `ObjectRemoved` system event are notified.
+    pub fn remove_object(&self, object_id: u32) {
+        self.shared.lock().remove_object(object_id);
+    }
+
+    /// Returns every object and its type, starting with the user aircraft.
+    pub fn objects(&self) -> Vec<(u32, SimObjectType)> {
+        self.shared.lock().objects.iter().map(|(id, t)| (*id, *t)).collect()
+    }
+
+    /// Sends the system event `name` to every client subscribed to it.
+    pub fn send_system_event(&self, name: &str, data: u32) {
+        let mut sim = self.shared.lock();
+        for client in &mut sim.clients {
+            client.system_event(name, |event_id| event(SIMCONNECT_RECV_EVENT_UNKNOWN_GROUP, event_id, data));
+        }
+    }
+
+    /// Triggers the simulation event `name`, as if it happened in the
+    /// simulator, notifying every client with a client event mapped to it in
+    /// a notification group.
+    pub fn send_event(&self, name: &str, data: u32) {
+        self.shared.lock().sim_event(name, &[data], None);
+    }
+
+    /// Presses (`down`) or releases the key combination or joystick button
+    /// `definition`, notifying every client with it mapped in an input group
+    /// that is on. Input group priorities and masking are ignored.
+    pub fn send_input(&self, definition: &str, down: bool) {
+        let mut sim = self.shared.lock();
+        for client in &mut sim.clients {
+            let events: Vec<_> = client.input_groups.iter()
+                .filter(|(_, group)| group.on)
+                .flat_map(|(group_id, group)| group.inputs.iter()
+                    .filter(|input| same_input(&input.0, definition))
+                    .map(move |&(_, down_event_id, down_value, up_event_id, up_value)| match down {
+                        true => (*group_id, down_event_id, down_value),
+                        false => (*group_id, up_event_id, up_value),
+                    }))
+                .filter(|(_, event_id, _)| *event_id != SIMCONNECT_UNUSED)
+                .collect();
+            for (group_id, event_id, data) in events {
+                client.send(SIMCONNECT_RECV_ID_EVENT, &event(group_id.get(), ClientEventId::new(event_id), data));
+            }
+        }
+    }
+
+    /// Sends an exception for the request with `send_id` to every client.
+    pub fn send_exception(&self, kind: ExceptionKind, send_id: u32, index: u32) {
+        let mut sim = self.shared.lock();
+        for client in &mut sim.clients {
+            client.send(SIMCONNECT_RECV_ID_EXCEPTION, &exception(kind, send_id, index));
+        }
+    }
+
+    /// Marks the key combination `definition` as bound in the simulator, so
+    /// it can't be reserved with `SimConnect_RequestReservedKey`.
+    pub fn bind_key(&self, definition: &str) {
+        self.shared.lock().taken_keys.push(definition.to_string());
+    }
+
+    /// Answers the next request from any client with an exception of `kind`,
+    /// instead of handling it.
+    pub fn fail_next(&self, kind: ExceptionKind) {
+        self.shared.lock().fail_next = Some(kind);
+    }
+
+    /// Advances the simulation by one period, sending data for every active
+    /// periodic data request.
+    ///
+    /// Each tick counts as one period of any length, so requests for every
+    /// visual frame, sim frame and second are all answered.
+    pub fn tick(&self) {
+        let mut sim = self.shared.lock();
+        let Sim { clients, simvars, .. } = &mut *sim;
+        for client in clients {
+            client.tick(simvars);
+        }
+    }
+
+    /// Sends `SIMCONNECT_RECV_ID_QUIT` to every client, as the simulator does
+    /// when it is closing.
+    pub fn quit(&self) {
+        let mut sim = self.shared.lock();
+        for client in &mut sim.clients {
+            client.send(SIMCONNECT_RECV_ID_QUIT, &[]);
+        }
+    }
+
+    /// Drops every client connection without warning, as if the simulator
+    /// crashed.
+    pub fn disconnect(&self) {
+        let mut sim = self.shared.lock();
+        for client in &mut sim.clients {
+            client.disconnect();
+        }
+    }
+
+    /// Returns every request received so far, in order.
+    pub fn requests(&self) -> Vec<Received> {
+        self.shared.lock().requests.clone()
+    }
+
+    /// Forgets the requests received so far.
+    pub fn clear_requests(&self) {
+        self.shared.lock().requests.clear();
+    }
+
+    /// Waits up to `timeout` for a request matching `f`, returning the first
+    /// one received, including those received before the call.
+    pub fn wait_for(&self, timeout: Duration, mut f: impl FnMut(&Received) -> bool) -> Option<Received> {
+        let deadline = Instant::now() + timeout;
+        let mut sim = self.shared.lock();
+        loop {
+            if let Some(received) = sim.requests.iter().find(|r| f(r)) {
+                return Some(received.clone());
+            }
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            if remaining.is_zero() {
+                return None;
+            }
+            sim = self.shared.received.wait_timeout(sim, remaining)
+                .unwrap_or_else(PoisonError::into_inner)
+                .0;
+        }
+    }
+}
+
+impl Drop for MockServer {
+    fn drop(&mut self) {
+        // stop accepting first, so no client connects after the others are dropped
+        self.shared.stop.store(true, Ordering::Relaxed);
+        if let Some(accept) = self.accept.take() {
+            let _ = accept.join();
+        }
+        self.disconnect();
+        let threads = std::mem::take(&mut *self.shared.threads.lock().unwrap_or_else(PoisonError::into_inner));
+        for thread in threads {
+            let _ = thread.join();
+        }
+    }
+}
+
+impl Shared {
+
+    fn lock(&self) -> MutexGuard<'_, Sim> {
+        self.sim.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+fn accept(listener: TcpListener, shared: &Arc<Shared>) {
+    while !shared.stop.load(Ordering::Relaxed) {
+        let stream = match listener.accept() {
+            Ok((stream, _)) => stream,
+            Err(e) if e.kind() == io::ErrorKind::WouldBlock => {
+                std::thread::sleep(ACCEPT_INTERVAL);
+                continue;
+            }
+            Err(_) => return,
+        };
+        // a client that can't be set up is dropped, it sees the connection close
+        let Ok(thread) = serve(stream, shared.clone()) else { continue };
+        shared.threads.lock().unwrap_or_else(PoisonError::into_inner).push(thread);
+    }
+}
+
+fn serve(stream: TcpStream, shared: Arc<Shared>) -> io::Result<JoinHandle<()>> {
+    stream.set_nonblocking(false)?;
+    stream.set_nodelay(true)?;
+    let mut reader = stream.try_clone()?;
+    let client = {
+        let mut sim = shared.lock();
+        sim.clients.push(Client::new(stream));
+        sim.clients.len() - 1
+    };
+    std::thread::Builder::new()
+        .name(format!("simconnect-mock-{}", client))
+        .spawn(move || {
+            // the connection ends on hang up or garbage, either way the client is done
+            while let Ok(packet) = read_packet(&mut reader) {
+                shared.lock().handle(client, &packet);
+                shared.received.notify_all();
+            }
+            shared.lock().clients[client].connected = false;
+        })
+}
+
+impl Sim {
+
+    fn handle(&mut self, client: usize, packet: &[u8]) {
+        let mut r = Reader::new(0, packet);
+        let (Ok(_size), Ok(protocol), Ok(function), Ok(send_id)) = (r.u32(), r.u32(), r.u32(), r.u32()) else {
+            return;
+        };
+        let function = function & !0xf000_0000;
+        let request = Request::decode(function, r.rest());
+        self.requests.push(Received { client, send_id, request: request.clone() });
+
+        if let Request::Open { .. } = request {
+            self.clients[client].protocol = protocol;
+        }
+        if let Some(kind) = self.fail_next.take() {
+            self.clients[client].send(SIMCONNECT_RECV_ID_EXCEPTION, &exception(kind, send_id, 0));
+            return;
+        }
+        if let Err((kind, index)) = self.apply(client, request) {
+            self.clients[client].send(SIMCONNECT_RECV_ID_EXCEPTION, &exception(kind, send_id, index));
+        }
+    }
+
+    // handles a request, returning the exception and parameter index it fails with
+    fn apply(&mut self, client: usize, request: Request) -> Result<(), (ExceptionKind, u32)> {
+        let Sim { clients, simvars, objects, system_states, taken_keys, .. } = self;
+        let c = &mut clients[client];
+        match request {
+            Request::Open { .. } => {
+                let version = if c.protocol >= 5 { [11, 0, 62651, 3] } else { [10, 0, 61637, 0] };
+                let mut p = Vec::new();
+                put_string(&mut p, "Mock SimConnect", 256);
+                put_u32s(&mut p, &[11, 0, 0, 0]);
+                put_u32s(&mut p, &version);
+                put_u32s(&mut p, &[0, 0]);
+                c.send(SIMCONNECT_RECV_ID_OPEN, &p);
+            }
+            Request::MapClientEventToSimEvent { event_id, name } => {
+                c.events.insert(event_id, name);
+            }
+            Request::TransmitClientEvent { event_id, data, group_id, flags, .. } => {
+                let name = c.events.get(&event_id).cloned().ok_or((ExceptionKind::UnrecognizedId, 2))?;
+
+                // events transmitted at a priority only notify lower priority groups
+                let priority = flags.contains(EventFlags::GROUPID_IS_PRIORITY).then_some(group_id);
+                self.sim_event(&name, &[data], priority);
+            }
+            #[cfg(sdk_at_least_0_24_3)]
+            Request::TransmitClientEventEx1 { event_id, group_id, flags, data, .. } => {
+                let name = c.events.get(&event_id).cloned().ok_or((ExceptionKind::UnrecognizedId, 2))?;
+                let priority = flags.contains(EventFlags::GROUPID_IS_PRIORITY).then_some(group_id);
+                self.sim_event(&name, &data, priority);
+            }
+            Request::SetSystemEventState { event_id, on } => {
+                let subscription = c.subscriptions.get_mut(&event_id).ok_or((ExceptionKind::UnrecognizedId, 1))?;
+                subscription.1 = on;
+            }
+            Request::AddClientEventToNotificationGroup { group_id, event_id, maskable } => {
+                c.groups.entry(group_id).or_default().events.push((event_id, maskable));
+            }
+            Request::RemoveClientEvent { group_id, event_id } => {
+                let group = c.groups.get_mut(&group_id).ok_or((ExceptionKind::UnrecognizedId, 1))?;
+                group.events.retain(|(id, _)| *id != event_id);
+            }
+            Request::SetNotificationGroupPriority { group_id, priority } => {
+                c.groups.entry(group_id).or_default().priority = priority;
+            }
+            Request::ClearNotificationGroup { group_id } => {
+                c.groups.remove(&group_id);
+            }
+            Request::AddToDataDefinition { define_id, name, data_type, datum_id, .. } => {
+                c.definitions.entry(define_id).or_default().push(Field { name, data_type, datum_id });
+            }
+            Request::ClearDataDefinition { define_id } => {
+                c.definitions.remove(&define_id).ok_or((ExceptionKind::UnrecognizedId, 1))?;
+            }
+            Request::RequestDataOnSimObject { request_id, define_id, object_id, period, options } => {
+                if !c.definitions.contains_key(&define_id) {
+                    return Err((ExceptionKind::UnrecognizedId, 2));
+                }
+                c.data_requests.retain(|r| r.request_id != request_id);
+                let mut request = DataRequest::new(request_id, define_id, object_id_or_user(object_id), options);
+                match period {
+                    Period::Never => {}
+                    Period::Once => c.send_data(&mut request, simvars, SIMCONNECT_RECV_ID_SIMOBJECT_DATA, 1, 1, true),
+                    _ => c.data_requests.push(request),
+                }
+            }
+            Request::RequestDataOnSimObjectType { request_id, define_id, object_type, .. } => {
+                if !c.definitions.contains_key(&define_id) {
+                    return Err((ExceptionKind::UnrecognizedId, 2));
+                }
+                let matching: Vec<_> = objects.iter()
+                    .filter(|(id, t)| match object_type {
+                        SimObjectType::User => **id == USER_OBJECT_ID,
+                        SimObjectType::All => true,
+                        object_type => **t == object_type,
+                    })
+                    .map(|(id, _)| *id)
+                    .collect();
+                let out_of = matching.len() as u32;
+                for (n, object_id) in matching.into_iter().enumerate() {
+                    let mut request = DataRequest::new(request_id, define_id, object_id, RequestOptions::default());
+                    let id = SIMCONNECT_RECV_ID_SIMOBJECT_DATA_BYTYPE;
+                    c.send_data(&mut request, simvars, id, n as u32 + 1, out_of, true);
+                }
+            }
+            Request::SetDataOnSimObject { define_id, object_id, flags, data } => {
+                let fields = c.definitions.get(&define_id).ok_or((ExceptionKind::UnrecognizedId, 1))?;
+                let object_id = object_id_or_user(object_id);
+                let mut r = Reader::new(SIMCONNECT_RECV_ID_SIMOBJECT_DATA as u32, &data);
+                let mut set = |field: &Field, r: &mut Reader<'_>| -> Result<(), (ExceptionKind, u32)> {
+                    let datum = r.datum(field.data_type).map_err(|_| (ExceptionKind::DataError, 0))?;
+                    if let Some(value) = value(datum) {
+                        simvars.insert(simvar_key(object_id, &field.name), value);
+                    }
+                    Ok(())
+                };
+                if flags.contains(DataSetFlags::TAGGED) {
+                    while r.position() < data.len() {
+                        let datum_id = r.u32().map_err(|_| (ExceptionKind::DataError, 0))?;
+                        let field = fields.iter().find(|f| f.datum_id == datum_id).ok_or((ExceptionKind::DatumId, 0))?;
+                        set(field, &mut r)?;
+                    }
+                } else {
+                    for field in fields {
+                        set(field, &mut r)?;
+                    }
+                }
+            }
+            Request::MapInputEventToClientEvent { group_id, definition, down_event_id, down_value, up_event_id, up_value, .. } => {
+                let group = c.input_groups.entry(group_id).or_default();
+                group.inputs.push((definition, down_event_id, down_value, up_event_id, up_value));
+            }
+            #[cfg(sdk_at_least_0_24_3)]
+            Request::MapInputEventToClientEventEx1 {
+                group_id, definition, down_event_id, down_value, up_event_id, up_value, ..
+            } => {
+                let group = c.input_groups.entry(group_id).or_default();
+                group.inputs.push((definition, down_event_id, down_value, up_event_id, up_value));
+            }
+            Request::RemoveInputEvent { group_id, definition } => {
+                let group = c.input_groups.get_mut(&group_id).ok_or((ExceptionKind::UnrecognizedId, 1))?;
+                group.inputs.retain(|input| !same_input(&input.0, &definition));
+            }
+            Request::ClearInputGroup { group_id } => {
+                c.input_groups.remove(&group_id);
+            }
+            Request::SetInputGroupState { group_id, on } => {
+                c.input_groups.get_mut(&group_id).ok_or((ExceptionKind::UnrecognizedId, 1))?.on = on;
+            }
+            Request::RequestReservedKey { choices, .. } => {
+                let choice = choices.into_iter().find(|choice| !taken_keys.iter().any(|key| same_input(key, choice)))
+                    .ok_or((ExceptionKind::Error, 2))?;
+                // named like the simulator does, in upper case
+                let key = choice.parse::<InputDefinition>().map_or_else(|_| choice.to_ascii_uppercase(), |d| d.to_string());
+                taken_keys.push(key.clone());
+                let mut p = Vec::new();
+                put_string(&mut p, &choice, 30);
+                put_string(&mut p, &key, 50);
+                c.send(SIMCONNECT_RECV_ID_RESERVED_KEY, &p);
+            }
+            Request::SubscribeToSystemEvent { event_id, name } => {
+                c.subscriptions.insert(event_id, (name, true));
+            }
+            Request::UnsubscribeFromSystemEvent { event_id } => {
+                c.subscriptions.remove(&event_id).ok_or((ExceptionKind::UnrecognizedId, 1))?;
+            }
+            Request::RequestSystemState { request_id, state } => {
+                let (integer, float, string) = system_states.get(&state.to_ascii_lowercase())
+                    .ok_or((ExceptionKind::NameUnrecognized, 2))?;
+                let mut p = Vec::new();
+                put_u32s(&mut p, &[request_id.get(), *integer]);
+                p.extend_from_slice(&float.to_le_bytes());
+                put_string(&mut p, string, MAX_PATH as usize);
+                c.send(SIMCONNECT_RECV_ID_SYSTEM_STATE, &p);
+            }
+            Request::AiCreateNonAtcAircraft { container_title, tail_number, position, request_id } => {
+                let object_id = self.create_object(SimObjectType::Aircraft, &container_title, &position);
+                self.simvars.insert(simvar_key(object_id, "ATC ID"), tail_number.into());
+                self.assigned_object_id(client, request_id, object_id);
+            }
+            Request::AiCreateSimulatedObject { container_title, position, request_id } => {
+                let object_id = self.create_object(SimObjectType::Ground, &container_title, &position);
+                self.assigned_object_id(client, request_id, object_id);
+            }
+            Request::AiRemoveObject { object_id, .. } => {
+                if object_id == USER_OBJECT_ID || !objects.contains_key(&object_id) {
+                    return Err((ExceptionKind::UnrecognizedId, 1));
+                }
+                self.remove_object(object_id);
+            }
+            Request::SetInputGroupPriority { .. } | Request::Other { .. } => {}
+        }
+        Ok(())
+    }
+
+    fn assigned_object_id(&mut self, client: usize, request_id: RequestId, object_id: u32) {
+        let mut p = Vec::new();
+        put_u32s(&mut p, &[request_id.get(), object_id]);
+        self.clients[client].send(SIMCONNECT_RECV_ID_ASSIGNED_OBJECT_ID, &p);
+    }
+
+    fn add_object(&mut self, object_type: SimObjectType) -> u32 {
+        let object_id = self.next_object_id;
+        self.next_object_id += 1;
+        self.objects.insert(object_id, object_type);
+        self.object_event("ObjectAdded", object_id, object_type);
+        object_id
+    }
+
+    fn create_object(&mut self, object_type: SimObjectType, title: &str, position: &InitPosition) -> u32 {
+        let object_id = self.add_object(object_type);
+        let simvars = [
+            ("TITLE", Value::from(title)),
+            ("PLANE LATITUDE", position.latitude.into()),
+            ("PLANE LONGITUDE", position.longitude.into()),
+            ("PLANE ALTITUDE", position.altitude.into()),
+            ("PLANE PITCH DEGREES", position.pitch.into()),
+            ("PLANE BANK DEGREES", position.bank.into()),
+            ("PLANE HEADING DEGREES TRUE", position.heading.into()),
+            ("SIM ON GROUND", position.on_ground.into()),
+            ("AIRSPEED INDICATED", position.airspeed.into()),
+        ];
+        for (name, value) in simvars {
+            self.simvars.insert(simvar_key(object_id, name), value);
+        }
+        object_id
+    }
+
+    fn remove_object(&mut self, object_id: u32) {
+        if let Some(object_type) = self.objects.remove(&object_id) {
+            self.simvars.retain(|(id, _), _| *id != object_id);
+            self.object_event("ObjectRemoved", object_id, object_type);
+        }
+    }
+
+    fn object_event(&mut self, name: &str, object_id: u32, object_type: SimObjectType) {
+        for client in &mut self.clients {
+            client.system_event_with(name, SIMCONNECT_RECV_ID_EVENT_OBJECT_ADDREMOVE, |event_id| {
+                let mut p = event(SIMCONNECT_RECV_EVENT_UNKNOWN_GROUP, event_id, object_id);
+                put_u32s(&mut p, &[object_type.raw() as u32]);
+                p
+            });
+        }
+    }
+
+    // notifies the groups below `priority` with a client event mapped to
+    // `name`, highest priority first until a group masks the event. the five
+    // parameters of an _EX1 call are sent as SIMCONNECT_RECV_EVENT_EX1
+    fn sim_event(&mut self, name: &str, data: &[u32], priority: Option<u32>) {
+        let mut targets = Vec::new();
+        for (client, c) in self.clients.iter().enumerate() {
+            for (event_id, _) in c.events.iter().filter(|(_, n)| n.eq_ignore_ascii_case(name)) {
+                // like the simulator, events outside of any group aren't sent
+                for (group_id, group) in &c.groups {
+                    if priority.is_some_and(|priority| group.priority <= priority) {
+                        continue;
+                    }
+                    if let Some((_, maskable)) = group.events.iter().find(|(id, _)| id == event_id) {
+                        targets.push((group.priority, client, *group_id, *event_id, *maskable));
+                    }
+                }
+            }
+        }
+        targets.sort_by_key(|(priority, client, group_id, ..)| (*priority, *client, *group_id));
+
+        #[cfg(sdk_at_least_0_24_3)]
+        let recv_id = if data.len() > 1 { SIMCONNECT_RECV_ID_EVENT_EX1 } else { SIMCONNECT_RECV_ID_EVENT };
+        #[cfg(not(sdk_at_least_0_24_3))]
+        let recv_id = SIMCONNECT_RECV_ID_EVENT;
+
+        // groups of the same priority as the masking one are still notified
+        let mut masked = None;
+        for (priority, client, group_id, event_id, maskable) in targets {
+            if masked.is_some_and(|masked| priority > masked) {
+                break;
+            }
+            let mut p = Vec::new();
+            put_u32s(&mut p, &[group_id.get(), event_id.get()]);
+            put_u32s(&mut p, data);
+            self.clients[client].send(recv_id, &p);
+            if maskable && priority <= SIMCONNECT_GROUP_PRIORITY_HIGHEST_MASKABLE {
+                masked = Some(priority);
+            }
+        }
+    }
+}
+
+impl Client {
+
+    fn new(stream: TcpStream) -> Self {
+        Self {
+            stream,
+            protocol: 0,
+            connected: true,
+            definitions: HashMap::new(),
+            data_requests: Vec::new(),
+            events: HashMap::new(),
+            groups: HashMap::new(),
+            subscriptions: HashMap::new(),
+            input_groups: HashMap::new(),
+        }
+    }
+
+    fn send(&mut self, id: SIMCONNECT_RECV_ID, payload: &[u8]) {
+        if !self.connected {
+            return;
+        }
+        let mut packet = Vec::with_capacity(12 + payload.len());
+        put_u32s(&mut packet, &[12 + payload.len() as u32, self.protocol, id as u32]);
+        packet.extend_from_slice(payload);
+        // a client that went away just stops receiving
+        if self.stream.write_all(&packet).is_err() {
+            self.disconnect();
+        }
+    }
+
+    fn disconnect(&mut self) {
+        self.connected = false;
+        let _ = self.stream.shutdown(Shutdown::Both);
+    }
+
+    fn system_event(&mut self, name: &str, f: impl Fn(ClientEventId) -> Vec<u8>) {
+        self.system_event_with(name, SIMCONNECT_RECV_ID_EVENT, f);
+    }
+
+    fn system_event_with(&mut self, name: &str, id: SIMCONNECT_RECV_ID, f: impl Fn(ClientEventId) -> Vec<u8>) {
+        let events: Vec<_> = self.subscriptions.iter()
+            .filter(|(_, (n, on))| *on && n.eq_ignore_ascii_case(name))
+            .map(|(event_id, _)| *event_id)
+            .collect();
+        for event_id in events {
+            self.send(id, &f(event_id));
+        }
+    }
+
+    fn tick(&mut self, simvars: &HashMap<(u32, String), Value>) {
+        let mut requests = std::mem::take(&mut self.data_requests);
+        requests.retain_mut(|request| {
+            request.periods += 1;
+            let RequestOptions { origin, interval, limit, .. } = request.options;
+            let due = request.periods > origin && (request.periods - origin - 1) % (interval + 1) == 0;
+            if due {
+                self.send_data(request, simvars, SIMCONNECT_RECV_ID_SIMOBJECT_DATA, 1, 1, false);
+            }
+            limit == 0 || request.sent < limit
+        });
+        self.data_requests = requests;
+    }
+
+    // sends the data for `request`, unless nothing changed and it was only
+    // asked for changes
+    fn send_data(
+        &mut self,
+        request: &mut DataRequest,
+        simvars: &HashMap<(u32, String), Value>,
+        id: SIMCONNECT_RECV_ID,
+        entry_number: u32,
+        out_of: u32,
+        always: bool,
+    ) {
+        let Some(fields) = self.definitions.get(&request.define_id) else { return };
+        let flags = request.options.flags;
+        let tagged = flags.contains(DataRequestFlags::TAGGED);
+        let changed_only = !always && flags.contains(DataRequestFlags::CHANGED);
+
+        let values: Vec<_> = fields.iter()
+            .map(|field| encode(simvars.get(&simvar_key(request.object_id, &field.name)), field.data_type))
+            .collect();
+        request.last.resize(values.len(), None);
+        let changed: Vec<_> = values.iter().zip(&request.last).map(|(v, last)| last.as_ref() != Some(v)).collect();
+        if changed_only && !changed.contains(&true) {
+            return;
+        }
+
+        let mut data = Vec::new();
+        let mut count = 0;
+        for ((field, value), changed) in fields.iter().zip(&values).zip(&changed) {
+            if tagged && changed_only && !changed {
+                continue;
+            }
+            if tagged {
+                put_u32s(&mut data, &[field.datum_id]);
+            }
+            data.extend_from_slice(value);
+            count += 1;
+        }
+        let count = if tagged { count } else { fields.len() as u32 };
+
+        let mut p = Vec::new();
+        put_u32s(&mut p, &[
+            request.request_id.get(),
+            request.object_id,
+            request.define_id.get(),
+            flags.bits(),
+            entry_number,
+            out_of,
+            count,
+        ]);
+        p.extend_from_slice(&data);
+        request.last = values.into_iter().map(Some).collect();
+        request.sent += 1;
+        self.send(id, &p);
+    }
+}
+
+// compares input definitions as parsed, so "ctrl+a" is the same as "A+Ctrl"
+fn same_input(a: &str, b: &str) -> bool {
+    match (a.parse::<InputDefinition>(), b.parse::<InputDefinition>()) {
+        (Ok(a), Ok(b)) => a == b,
+        _ => a.eq_ignore_ascii_case(b),
+    }
+}
+
+fn event(group_id: u32, event_id: ClientEventId, data: u32) -> Vec<u8> {
+    let mut p = Vec::new();
+    put_u32s(&mut p, &[group_id, event_id.get(), data]);
+    p
+}
+
+fn exception(kind: ExceptionKind, send_id: u32, index: u32) -> Vec<u8> {
+    let mut p = Vec::new();
+    put_u32s(&mut p, &[kind as u32, send_id, index]);
+    p
+}
+
+fn put_u32s(p: &mut Vec<u8>, vs: &[u32]) {
+    for v in vs {
+        p.extend_from_slice(&v.to_le_bytes());
+    }
+}
+
+// a fixed size, nul padded field, cut short to keep the terminator
+fn put_string(p: &mut Vec<u8>, s: &str, len: usize) {
+    let n = s.len().min(len - 1);
+    p.extend_from_slice(&s.as_bytes()[..n]);
+    p.resize(p.len() + len - n, 0);
+}
+
+// encodes a variable as `data_type`, unset or mismatched variables are zero
+fn encode(value: Option<&Value>, data_type: DataType) -> Vec<u8> {
+    let number = match value {
+        Some(Value::Number(v)) => *v,
+        _ => 0.0,
+    };
+    let string = match value {
+        Some(Value::String(s)) => s.as_str(),
+        _ => "",
+    };
+    let mut out = Vec::new();
+    match data_type {
+        DataType::Int32 => (number as i32).write(&mut out),
+        DataType::Int64 => (number as i64).write(&mut out),
+        DataType::Float32 => (number as f32).write(&mut out),
+        DataType::Float64 => number.write(&mut out),
+        DataType::StringV => {
+            out.extend_from_slice(string.as_bytes());
+            out.push(0);
+        }
+        DataType::LatLonAlt => match value {
+            Some(Value::LatLonAlt(v)) => v.write(&mut out),
+            _ => LatLonAlt::default().write(&mut out),
+        },
+        DataType::Xyz => match value {
+            Some(Value::Xyz(v)) => v.write(&mut out),
+            _ => Xyz::default().write(&mut out),
+        },
+        DataType::InitPosition => match value {
+            Some(Value::InitPosition(v)) => v.write(&mut out),
+            _ => InitPosition::default().write(&mut out),
+        },
+        // fixed size strings, and structures the store can't hold
+        _ => {
+            let len = data_type.size().unwrap_or(0);
+            put_string(&mut out, string, len);
+        }
+    }
+    out
+}
+
+fn value(datum: Datum) -> Option<Value> {
+    Some(match datum {
+        Datum::Int32(v) => v.into(),
+        Datum::Int64(v) => Value::Number(v as f64),
+        Datum::Float32(v) => v.into(),
+        Datum::Float64(v) => v.into(),
+        Datum::String(v) => v.into(),
+        Datum::LatLonAlt(v) => v.into(),
+        Datum::Xyz(v) => v.into(),
+        Datum::InitPosition(v) => v.into(),
+        Datum::Bytes(_) => return None,
+    })
+}
+
+fn simvar_key(object_id: u32, name: &str) -> (u32, String) {
+    (object_id_or_user(object_id), name.to_ascii_uppercase())
+}
+
+fn object_id_or_user(object_id: u32) -> u32 {
+    match object_id {
+        OBJECT_ID_USER => USER_OBJECT_ID,
+        id => id,
+    }
+}
+
+// how long tests wait for a message or a request
+#[cfg(test)]
+pub(crate) const TIMEOUT: Duration = Duration::from_secs(5);
+
+// receives messages until one matches `f`, panicking if none arrives in time
+#[cfg(test)]
+pub(crate) fn next_matching(sc: &SimConnect, mut f: impl FnMut(&crate::Recv) -> bool) -> crate::Recv {
+    loop {
+        match sc.recv_timeout(TIMEOUT).unwrap() {
+            Some(recv) if f(&recv) => return recv,
+            Some(_) => {}
+            None => panic!("timed out waiting for a message"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Recv;
+    #[cfg(feature = "derive")]
+    use crate::SimData;
+
+    #[cfg(feature = "derive")]
+    #[derive(Debug, PartialEq, SimData)]
+    struct Aircraft {
+        #[simvar(name = "TITLE")]
+        title: String,
+        #[simvar(name = "PLANE ALTITUDE", units = "feet")]
+        altitude: f64,
+        #[simvar(name = "SIM ON GROUND")]
+        on_ground: bool,
+    }
+
+    #[cfg(feature = "derive")]
+    #[test]
+    fn data() {
+        let server = MockServer::start().unwrap();
+        server.set_simvar(OBJECT_ID_USER, "title", "Cessna 172");
+        server.set_simvar(USER_OBJECT_ID, "PLANE ALTITUDE", 5000.0);
+
+        let sc = server.connect("Test").unwrap();
+        let Recv::Open(open) = next_matching(&sc, |_| true) else { panic!("expected open") };
+        assert_eq!(open.application_name, "Mock SimConnect");
+
+        let define_id = sc.define::<Aircraft>().unwrap();
+        let request_id = sc.new_request_id();
+        sc.request_data_on_sim_object(request_id, define_id, OBJECT_ID_USER, Period::Once).unwrap();
+        let Recv::SimObjectData(data) = next_matching(&sc, |r| matches!(r, Recv::SimObjectData(_))) else { unreachable!() };
+        let aircraft = Aircraft::from_recv(&data).unwrap();
+        assert_eq!(aircraft, Aircraft { title: "Cessna 172".into(), altitude: 5000.0, on_ground: false });
+
+        // writes land in the store
+        let aircraft = Aircraft { altitude: 6000.0, on_ground: true, ..aircraft };
+        sc.set_data(define_id, OBJECT_ID_USER, &aircraft).unwrap();
+        server.wait_for(TIMEOUT, |r| matches!(r.request, Request::SetDataOnSimObject { .. })).unwrap();
+        assert_eq!(server.simvar(OBJECT_ID_USER, "PLANE ALTITUDE"), Some(Value::Number(6000.0)));
+
+        // periodic requests only send changes when asked to
+        let options = RequestOptions { flags: DataRequestFlags::CHANGED, ..Default::default() };
+        sc.request_data_on_sim_object_with(request_id, define_id, OBJECT_ID_USER, Period::SimFrame, options).unwrap();
+        server.wait_for(TIMEOUT, |r| matches!(r.request, Request::RequestDataOnSimObject { period: Period::SimFrame, .. }))
+            .unwrap();
+        server.tick();
+        server.tick();
+        server.set_simvar(OBJECT_ID_USER, "PLANE ALTITUDE", 7000.0);
+        server.tick();
+        server.quit();
+        let mut altitudes = Vec::new();
+        for recv in sc.iter() {
+            if let Recv::SimObjectData(data) = recv.unwrap() {
+                altitudes.push(Aircraft::from_recv(&data).unwrap().altitude);
+            }
+        }
+        assert_eq!(altitudes, [6000.0, 7000.0]);
+    }
+
+    #[test]
+    fn events_and_exceptions() {
+        let server = MockServer::start().unwrap();
+        let sc = server.connect("Test").unwrap();
+
+        let pause = sc.new_client_event_id();
+        let brakes = sc.new_client_event_id();
+        let group_id = sc.new_group_id();
+        sc.subscribe_to_system_event(pause, "Pause").unwrap();
+        sc.map_client_event_to_sim_event(brakes, "BRAKES").unwrap();
+        sc.add_client_event_to_notification_group(group_id, brakes, false).unwrap();
+        server.wait_for(TIMEOUT, |r| matches!(r.request, Request::AddClientEventToNotificationGroup { .. })).unwrap();
+
+        server.send_system_event("pause", 1);
+        let Recv::Event(event) = next_matching(&sc, |r| matches!(r, Recv::Event(_))) else { unreachable!() };
+        assert_eq!((event.event_id, event.group_id, event.data), (pause, None, 1));
+
+        // transmitted events come back through the notification group
+        sc.transmit_client_event(OBJECT_ID_USER, brakes, 0, group_id, EventFlags::empty()).unwrap();
+        let Recv::Event(event) = next_matching(&sc, |r| matches!(r, Recv::Event(_))) else { unreachable!() };
+        assert_eq!((event.event_id, event.group_id), (brakes, Some(group_id)));
+
+        server.fail_next(ExceptionKind::NameUnrecognized);
+        sc.map_client_event_to_sim_event(sc.new_client_event_id(), "BRAKEZ").unwrap();
+        let Recv::Exception(e) = next_matching(&sc, |r| matches!(r, Recv::Exception(_))) else { unreachable!() };
+        assert_eq!(e.kind(), Some(ExceptionKind::NameUnrecognized));
+        assert!(e.call().unwrap().starts_with("SimConnect_MapClientEventToSimEvent("));
+
+        server.disconnect();
+        assert!(matches!(sc.recv(), Err(crate::Error::Closed)));
+        assert_eq!(server.requests().iter().filter(|r| r.client == 0).count(), 6);
+    }
+}
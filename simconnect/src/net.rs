@@ -0,0 +1,626 @@
+use std::cell::Cell;
+use std::ffi::CStr;
+use std::fmt;
+use std::io::{self, Read, Write};
+use std::net::{Shutdown, TcpStream, ToSocketAddrs};
+use std::path::Path;
+use std::sync::{Arc, Condvar, Mutex, MutexGuard, PoisonError};
+use std::thread::JoinHandle;
+use std::time::Duration;
+
+use simconnect_sys::SIMCONNECT_OPEN_CONFIGINDEX_LOCAL;
+
+use crate::error::{Error, Result};
+use crate::recv::{Recv, HEADER_SIZE};
+use crate::request::Request;
+use crate::types::InitPosition;
+
+// size of the header in front of every packet sent to the server
+const SEND_HEADER_SIZE: usize = 16;
+
+// anything larger is a corrupt stream rather than a real packet
+const MAX_PACKET_SIZE: usize = 16 << 20;
+
+// ids of the SimConnect functions, sent in the header of each request
+pub(crate) mod function {
+    pub(crate) const OPEN: u32 = 0x01;
+    pub(crate) const MAP_CLIENT_EVENT_TO_SIM_EVENT: u32 = 0x04;
+    pub(crate) const TRANSMIT_CLIENT_EVENT: u32 = 0x05;
+    pub(crate) const SET_SYSTEM_EVENT_STATE: u32 = 0x06;
+    pub(crate) const ADD_CLIENT_EVENT_TO_NOTIFICATION_GROUP: u32 = 0x07;
+    pub(crate) const REMOVE_CLIENT_EVENT: u32 = 0x08;
+    pub(crate) const SET_NOTIFICATION_GROUP_PRIORITY: u32 = 0x09;
+    pub(crate) const CLEAR_NOTIFICATION_GROUP: u32 = 0x0a;
+    pub(crate) const ADD_TO_DATA_DEFINITION: u32 = 0x0c;
+    pub(crate) const CLEAR_DATA_DEFINITION: u32 = 0x0d;
+    pub(crate) const REQUEST_DATA_ON_SIM_OBJECT: u32 = 0x0e;
+    pub(crate) const REQUEST_DATA_ON_SIM_OBJECT_TYPE: u32 = 0x0f;
+    pub(crate) const SET_DATA_ON_SIM_OBJECT: u32 = 0x10;
+    pub(crate) const MAP_INPUT_EVENT_TO_CLIENT_EVENT: u32 = 0x11;
+    pub(crate) const SET_INPUT_GROUP_PRIORITY: u32 = 0x12;
+    pub(crate) const REMOVE_INPUT_EVENT: u32 = 0x13;
+    pub(crate) const CLEAR_INPUT_GROUP: u32 = 0x14;
+    pub(crate) const SET_INPUT_GROUP_STATE: u32 = 0x15;
+    pub(crate) const REQUEST_RESERVED_KEY: u32 = 0x16;
+    pub(crate) const SUBSCRIBE_TO_SYSTEM_EVENT: u32 = 0x17;
+    pub(crate) const UNSUBSCRIBE_FROM_SYSTEM_EVENT: u32 = 0x18;
+    pub(crate) const AI_CREATE_NON_ATC_AIRCRAFT: u32 = 0x29;
+    pub(crate) const AI_CREATE_SIMULATED_OBJECT: u32 = 0x2a;
+    pub(crate) const AI_REMOVE_OBJECT: u32 = 0x2c;
+    pub(crate) const REQUEST_SYSTEM_STATE: u32 = 0x35;
+
+    // added by msfs, numbered on in the order SimConnect.h declares them
+    #[cfg(sdk_at_least_0_24_3)]
+    pub(crate) const TRANSMIT_CLIENT_EVENT_EX1: u32 = 0x44;
+    #[cfg(sdk_at_least_0_24_3)]
+    pub(crate) const MAP_INPUT_EVENT_TO_CLIENT_EVENT_EX1: u32 = 0x4d;
+}
+
+/// Version of the SimConnect network protocol spoken by a connection made
+/// with [`OpenOptions::connect`](crate::OpenOptions::connect).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Protocol {
+
+    /// Protocol 4, spoken by FSX SP2 and Prepar3D.
+    FsxSp2,
+
+    /// Protocol 5, spoken by Microsoft Flight Simulator.
+    #[default]
+    Msfs,
+}
+
+impl Protocol {
+
+    fn version(self) -> u32 {
+        match self {
+            Protocol::FsxSp2 => 4,
+            Protocol::Msfs => 5,
+        }
+    }
+
+    // the simulator tag and SimConnect version sent with SimConnect_Open
+    fn open_info(self) -> ([u8; 4], [u32; 4]) {
+        match self {
+            Protocol::FsxSp2 => ([0, b'X', b'S', b'F'], [10, 0, 61637, 0]),
+            Protocol::Msfs => ([0, b'H', b'K', 0], [11, 0, 62651, 3]),
+        }
+    }
+}
+
+/// A connection made over TCP, speaking the SimConnect protocol directly.
+///
+/// Packets are read by a background thread into a queue, so receiving never
+/// blocks on the socket.
+pub(crate) struct Net {
+    stream: TcpStream,
+    protocol: Protocol,
+    send_id: Cell<u32>,
+    shared: Arc<Shared>,
+    reader: Option<JoinHandle<()>>,
+}
+
+#[derive(Default)]
+struct Shared {
+    state: Mutex<State>,
+    ready: Condvar,
+}
+
+#[derive(Default)]
+struct State {
+    packets: std::collections::VecDeque<Vec<u8>>,
+    closed: bool,
+
+    // why the connection closed, taken by the first receive that sees it
+    error: Option<io::Error>,
+    notify: Option<Arc<dyn Fn() + Send + Sync>>,
+}
+
+impl Net {
+
+    pub(crate) fn connect(addr: impl ToSocketAddrs, name: &str, protocol: Protocol) -> Result<Self> {
+        let name = std::ffi::CString::new(name)?;
+        let stream = TcpStream::connect(addr)?;
+        // requests are small and latency matters more than throughput
+        stream.set_nodelay(true)?;
+
+        let shared = Arc::new(Shared::default());
+        let reader = {
+            let stream = stream.try_clone()?;
+            let shared = shared.clone();
+            std::thread::Builder::new()
+                .name("simconnect-reader".into())
+                .spawn(move || read_packets(stream, &shared))?
+        };
+        let net = Self { stream, protocol, send_id: Cell::new(0), shared, reader: Some(reader) };
+
+        let (tag, version) = protocol.open_info();
+        let mut p = Packet::default();
+        p.string(&name, 256)?;
+        p.u32(0);
+        p.bytes(&tag);
+        for v in version {
+            p.u32(v);
+        }
+        net.send_packet(function::OPEN, p)?;
+        Ok(net)
+    }
+
+    pub(crate) fn close(mut self) -> Result<()> {
+        self.shutdown();
+        Ok(())
+    }
+
+    fn shutdown(&mut self) {
+        // the reader sees the end of the stream and stops
+        let _ = self.stream.shutdown(Shutdown::Both);
+        if let Some(reader) = self.reader.take() {
+            let _ = reader.join();
+        }
+    }
+
+    pub(crate) fn last_send_id(&self) -> Result<u32> {
+        Ok(self.send_id.get())
+    }
+
+    pub(crate) fn try_recv(&self, record: impl FnOnce(&[u8])) -> Result<Option<Recv>> {
+        let mut state = self.shared.lock();
+        match state.packets.pop_front() {
+            Some(packet) => {
+                drop(state);
+                record(&packet);
+                Ok(Some(Recv::decode(&packet)?))
+            }
+            None if state.closed => Err(state.error.take().map_or(Error::Closed, Error::Io)),
+            None => Ok(None),
+        }
+    }
+
+    pub(crate) fn wait(&self, timeout: Option<Duration>) {
+        let state = self.shared.lock();
+        let waiting = |s: &mut State| s.packets.is_empty() && !s.closed;
+        // spurious wakeups and poisoning just send the caller back to check the queue
+        match timeout {
+            Some(timeout) => drop(self.shared.ready.wait_timeout_while(state, timeout, waiting)),
+            None => drop(self.shared.ready.wait_while(state, waiting)),
+        }
+    }
+
+    pub(crate) fn notify(&self, f: Arc<dyn Fn() + Send + Sync>) -> Notifier {
+        self.shared.lock().notify = Some(f);
+        Notifier(self.shared.clone())
+    }
+
+    pub(crate) fn send(&self, request: &Request<'_>) -> Result<()> {
+        let mut p = Packet::default();
+        let id = match *request {
+            Request::MapClientEventToSimEvent { event_id, name } => {
+                p.u32(event_id.get());
+                p.string(name, 256)?;
+                function::MAP_CLIENT_EVENT_TO_SIM_EVENT
+            }
+            Request::TransmitClientEvent { object_id, event_id, data, group_id, flags } => {
+                p.u32s(&[object_id, event_id.get(), data, group_id, flags.bits()]);
+                function::TRANSMIT_CLIENT_EVENT
+            }
+            #[cfg(sdk_at_least_0_24_3)]
+            Request::TransmitClientEventEx1 { object_id, event_id, group_id, flags, data } => {
+                p.u32s(&[object_id, event_id.get(), group_id, flags.bits()]);
+                p.u32s(&data);
+                function::TRANSMIT_CLIENT_EVENT_EX1
+            }
+            Request::SetSystemEventState { event_id, on } => {
+                p.u32s(&[event_id.get(), on as u32]);
+                function::SET_SYSTEM_EVENT_STATE
+            }
+            Request::AddClientEventToNotificationGroup { group_id, event_id, maskable } => {
+                p.u32s(&[group_id.get(), event_id.get(), maskable as u32]);
+                function::ADD_CLIENT_EVENT_TO_NOTIFICATION_GROUP
+            }
+            Request::RemoveClientEvent { group_id, event_id } => {
+                p.u32s(&[group_id.get(), event_id.get()]);
+                function::REMOVE_CLIENT_EVENT
+            }
+            Request::SetNotificationGroupPriority { group_id, priority } => {
+                p.u32s(&[group_id.get(), priority]);
+                function::SET_NOTIFICATION_GROUP_PRIORITY
+            }
+            Request::ClearNotificationGroup { group_id } => {
+                p.u32(group_id.get());
+                function::CLEAR_NOTIFICATION_GROUP
+            }
+            Request::AddToDataDefinition { define_id, name, units, data_type, epsilon, datum_id } => {
+                p.u32(define_id.get());
+                p.string(name, 256)?;
+                p.string(units, 256)?;
+                p.u32(data_type.raw() as u32);
+                p.f32(epsilon);
+                p.u32(datum_id);
+                function::ADD_TO_DATA_DEFINITION
+            }
+            Request::ClearDataDefinition { define_id } => {
+                p.u32(define_id.get());
+                function::CLEAR_DATA_DEFINITION
+            }
+            Request::RequestDataOnSimObject { request_id, define_id, object_id, period, options } => {
+                p.u32s(&[request_id.get(), define_id.get(), object_id, period.raw() as u32, options.flags.bits(),
+                    options.origin, options.interval, options.limit]);
+                function::REQUEST_DATA_ON_SIM_OBJECT
+            }
+            Request::RequestDataOnSimObjectType { request_id, define_id, radius_meters, object_type } => {
+                p.u32s(&[request_id.get(), define_id.get(), radius_meters, object_type.raw() as u32]);
+                function::REQUEST_DATA_ON_SIM_OBJECT_TYPE
+            }
+            Request::SetDataOnSimObject { define_id, object_id, flags, data } => {
+                // an array count of 0 is one element, as with SimConnect_SetDataOnSimObject
+                p.u32s(&[define_id.get(), object_id, flags.bits(), 0, data.len() as u32]);
+                p.bytes(data);
+                function::SET_DATA_ON_SIM_OBJECT
+            }
+            Request::SubscribeToSystemEvent { event_id, name } => {
+                p.u32(event_id.get());
+                p.string(name, 256)?;
+                function::SUBSCRIBE_TO_SYSTEM_EVENT
+            }
+            Request::UnsubscribeFromSystemEvent { event_id } => {
+                p.u32(event_id.get());
+                function::UNSUBSCRIBE_FROM_SYSTEM_EVENT
+            }
+            Request::MapInputEventToClientEvent {
+                group_id, definition, down_event_id, down_value, up_event_id, up_value, maskable,
+            } => {
+                p.u32(group_id.get());
+                p.string(definition, 256)?;
+                p.u32s(&[down_event_id, down_value, up_event_id, up_value, maskable as u32]);
+                function::MAP_INPUT_EVENT_TO_CLIENT_EVENT
+            }
+            #[cfg(sdk_at_least_0_24_3)]
+            Request::MapInputEventToClientEventEx1 {
+                group_id, definition, down_event_id, down_value, up_event_id, up_value, maskable,
+            } => {
+                p.u32(group_id.get());
+                p.string(definition, 256)?;
+                p.u32s(&[down_event_id, down_value, up_event_id, up_value, maskable as u32]);
+                function::MAP_INPUT_EVENT_TO_CLIENT_EVENT_EX1
+            }
+            Request::SetInputGroupPriority { group_id, priority } => {
+                p.u32s(&[group_id.get(), priority]);
+                function::SET_INPUT_GROUP_PRIORITY
+            }
+            Request::RemoveInputEvent { group_id, definition } => {
+                p.u32(group_id.get());
+                p.string(definition, 256)?;
+                function::REMOVE_INPUT_EVENT
+            }
+            Request::ClearInputGroup { group_id } => {
+                p.u32(group_id.get());
+                function::CLEAR_INPUT_GROUP
+            }
+            Request::SetInputGroupState { group_id, on } => {
+                p.u32s(&[group_id.get(), on as u32]);
+                function::SET_INPUT_GROUP_STATE
+            }
+            Request::RequestReservedKey { event_id, choices } => {
+                p.u32(event_id.get());
+                for choice in choices {
+                    p.string(choice.unwrap_or_default(), 30)?;
+                }
+                function::REQUEST_RESERVED_KEY
+            }
+            Request::AiCreateNonAtcAircraft { container_title, tail_number, position, request_id } => {
+                p.string(container_title, 256)?;
+                p.string(tail_number, 12)?;
+                p.init_position(position);
+                p.u32(request_id.get());
+                function::AI_CREATE_NON_ATC_AIRCRAFT
+            }
+            Request::AiCreateSimulatedObject { container_title, position, request_id } => {
+                p.string(container_title, 256)?;
+                p.init_position(position);
+                p.u32(request_id.get());
+                function::AI_CREATE_SIMULATED_OBJECT
+            }
+            Request::AiRemoveObject { object_id, request_id } => {
+                p.u32s(&[object_id, request_id.get()]);
+                function::AI_REMOVE_OBJECT
+            }
+            Request::RequestSystemState { request_id, state } => {
+                p.u32(request_id.get());
+                p.string(state, 256)?;
+                function::REQUEST_SYSTEM_STATE
+            }
+        };
+        self.send_packet(id, p)
+    }
+
+    fn send_packet(&self, id: u32, p: Packet) -> Result<()> {
+        let send_id = self.send_id.get() + 1;
+        let mut packet = p.0;
+        let size = packet.len() as u32;
+        packet[..SEND_HEADER_SIZE].copy_from_slice(&[
+            size.to_le_bytes(),
+            self.protocol.version().to_le_bytes(),
+            (0xf000_0000 | id).to_le_bytes(),
+            send_id.to_le_bytes(),
+        ].concat());
+        (&self.stream).write_all(&packet)?;
+        self.send_id.set(send_id);
+        Ok(())
+    }
+}
+
+impl Drop for Net {
+    fn drop(&mut self) {
+        self.shutdown();
+    }
+}
+
+impl fmt::Debug for Net {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Net")
+            .field("peer", &self.stream.peer_addr().ok())
+            .field("protocol", &self.protocol)
+            .field("send_id", &self.send_id.get())
+            .finish_non_exhaustive()
+    }
+}
+
+impl Shared {
+
+    fn lock(&self) -> MutexGuard<'_, State> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn push(&self, packet: Option<Vec<u8>>, error: Option<io::Error>) {
+        let mut state = self.lock();
+        match packet {
+            Some(packet) => state.packets.push_back(packet),
+            None => {
+                state.closed = true;
+                state.error = error;
+            }
+        }
+        let notify = state.notify.clone();
+        drop(state);
+
+        self.ready.notify_all();
+        if let Some(notify) = notify {
+            notify();
+        }
+    }
+}
+
+fn read_packets(mut stream: TcpStream, shared: &Shared) {
+    loop {
+        match read_packet(&mut stream) {
+            Ok(packet) => shared.push(Some(packet), None),
+            // the server closing the connection after Quit is the normal way out
+            Err(e) if e.kind() == io::ErrorKind::UnexpectedEof => return shared.push(None, None),
+            Err(e) => return shared.push(None, Some(e)),
+        }
+    }
+}
+
+pub(crate) fn read_packet(stream: &mut impl Read) -> io::Result<Vec<u8>> {
+    let mut size = [0; 4];
+    stream.read_exact(&mut size)?;
+    let len = u32::from_le_bytes(size) as usize;
+    if !(HEADER_SIZE..=MAX_PACKET_SIZE).contains(&len) {
+        return Err(io::Error::new(io::ErrorKind::InvalidData, format!("invalid packet size {}", len)));
+    }
+    let mut packet = vec![0; len];
+    packet[..4].copy_from_slice(&size);
+    stream.read_exact(&mut packet[4..])?;
+    Ok(packet)
+}
+
+/// Keeps calling the function given to [`Net::notify`] until dropped.
+pub(crate) struct Notifier(Arc<Shared>);
+
+impl Drop for Notifier {
+    fn drop(&mut self) {
+        self.0.lock().notify = None;
+    }
+}
+
+impl fmt::Debug for Notifier {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Notifier").finish_non_exhaustive()
+    }
+}
+
+// a packet being built, starting with room for the header
+struct Packet(Vec<u8>);
+
+impl Default for Packet {
+    fn default() -> Self {
+        Self(vec![0; SEND_HEADER_SIZE])
+    }
+}
+
+impl Packet {
+
+    fn u32(&mut self, v: u32) {
+        self.0.extend_from_slice(&v.to_le_bytes());
+    }
+
+    fn u32s(&mut self, vs: &[u32]) {
+        for v in vs {
+            self.u32(*v);
+        }
+    }
+
+    fn f32(&mut self, v: f32) {
+        self.0.extend_from_slice(&v.to_le_bytes());
+    }
+
+    fn f64(&mut self, v: f64) {
+        self.0.extend_from_slice(&v.to_le_bytes());
+    }
+
+    fn bytes(&mut self, bytes: &[u8]) {
+        self.0.extend_from_slice(bytes);
+    }
+
+    // a fixed size, nul padded field, failing like FixedString when the
+    // string and its terminator don't fit
+    fn string(&mut self, s: &CStr, len: usize) -> Result<()> {
+        let bytes = s.to_bytes();
+        if bytes.len() >= len {
+            return Err(Error::StringTooLong { len: bytes.len(), max: len - 1 });
+        }
+        self.0.extend_from_slice(bytes);
+        self.0.resize(self.0.len() + len - bytes.len(), 0);
+        Ok(())
+    }
+
+    fn init_position(&mut self, position: &InitPosition) {
+        for v in [position.latitude, position.longitude, position.altitude, position.pitch, position.bank,
+            position.heading] {
+            self.f64(v);
+        }
+        self.u32s(&[position.on_ground as u32, position.airspeed]);
+    }
+}
+
+/// Finds the server address for `config_index` in `SimConnect.cfg`, looked
+/// for next to the executable and then in the working directory like
+/// SimConnect.dll does.
+#[cfg_attr(windows, allow(dead_code))]
+pub(crate) fn config_address(config_index: u32) -> Result<String> {
+    if config_index == SIMCONNECT_OPEN_CONFIGINDEX_LOCAL {
+        return Err(config_error("a local connection needs SimConnect.dll"));
+    }
+    let exe_dir = std::env::current_exe().ok().and_then(|exe| exe.parent().map(Path::to_path_buf));
+    let cfg = exe_dir.into_iter()
+        .chain(std::env::current_dir().ok())
+        .find_map(|dir| std::fs::read_to_string(dir.join("SimConnect.cfg")).ok())
+        .ok_or_else(|| config_error("SimConnect.cfg not found"))?;
+    parse_config(&cfg, config_index)
+}
+
+fn parse_config(cfg: &str, config_index: u32) -> Result<String> {
+    let section = match config_index {
+        0 => "simconnect".to_string(),
+        n => format!("simconnect.{}", n),
+    };
+
+    let mut in_section = false;
+    let (mut protocol, mut address, mut port) = (None, None, None);
+    for line in cfg.lines().map(str::trim) {
+        if let Some(name) = line.strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+            in_section = name.trim().eq_ignore_ascii_case(&section);
+        } else if let Some((key, value)) = line.split_once('=').filter(|_| in_section) {
+            let value = Some(value.trim().to_string());
+            match key.trim().to_ascii_lowercase().as_str() {
+                "protocol" => protocol = value,
+                "address" => address = value,
+                "port" => port = value,
+                _ => {}
+            }
+        }
+    }
+
+    match protocol.as_deref() {
+        None => {}
+        Some(p) if p.eq_ignore_ascii_case("ipv4") || p.eq_ignore_ascii_case("ipv6") => {}
+        Some(p) => return Err(config_error(&format!("protocol {} is not supported without SimConnect.dll", p))),
+    }
+    let address = address.ok_or_else(|| config_error(&format!("no Address in [{}]", section)))?;
+    let port = port.ok_or_else(|| config_error(&format!("no Port in [{}]", section)))?;
+    // IPv6 addresses need brackets to be followed by a port
+    Ok(match address.contains(':') {
+        true => format!("[{}]:{}", address, port),
+        false => format!("{}:{}", address, port),
+    })
+}
+
+fn config_error(msg: &str) -> Error {
+    io::Error::new(io::ErrorKind::NotFound, msg).into()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::TcpListener;
+
+    use super::*;
+    use crate::ids::ClientEventId;
+    use crate::recv::Event;
+
+    #[test]
+    fn packet_layout() {
+        let name = std::ffi::CString::new("brakes").unwrap();
+        let mut p = Packet::default();
+        p.u32(7);
+        p.string(&name, 8).unwrap();
+        assert_eq!(&p.0[SEND_HEADER_SIZE..], b"\x07\0\0\0brakes\0\0");
+
+        // strings are never cut short, the terminator has to fit too
+        let mut p = Packet::default();
+        assert!(matches!(p.string(&name, 6), Err(Error::StringTooLong { len: 6, max: 5 })));
+    }
+
+    #[test]
+    fn config() {
+        let cfg = "[SimConnect]\nProtocol=IPv4\nAddress=192.168.1.10\nPort=500\n\n\
+            [SimConnect.1]\nProtocol=IPv6\nAddress=::1\nPort=501\n\n\
+            [SimConnect.2]\nProtocol=Pipe\nAddress=.\nPort=Custom/SimConnect\n";
+        assert_eq!(parse_config(cfg, 0).unwrap(), "192.168.1.10:500");
+        assert_eq!(parse_config(cfg, 1).unwrap(), "[::1]:501");
+        assert!(parse_config(cfg, 2).is_err());
+        assert!(parse_config(cfg, 3).is_err());
+    }
+
+    #[test]
+    fn connect() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        let server = std::thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let open = read_packet(&mut stream).unwrap();
+            let event_id = ClientEventId::new(3);
+            let name = std::ffi::CString::new("brakes").unwrap();
+            let mut map = Packet::default();
+            map.u32(event_id.get());
+            map.string(&name, 256).unwrap();
+            let map_len = map.0.len();
+            let map = {
+                let mut request = vec![0; map_len];
+                stream.read_exact(&mut request).unwrap();
+                request
+            };
+
+            // an event for the client, then hang up
+            let event: Vec<u8> = [24, 6, 4, 1, 3, 42].iter().flat_map(|v: &u32| v.to_le_bytes()).collect();
+            stream.write_all(&event).unwrap();
+            (open, map)
+        });
+
+        let net = Net::connect(addr, "Test", Protocol::Msfs).unwrap();
+        let name = std::ffi::CString::new("brakes").unwrap();
+        net.send(&Request::MapClientEventToSimEvent { event_id: ClientEventId::new(3), name: &name }).unwrap();
+        assert_eq!(net.last_send_id().unwrap(), 2);
+
+        // size, protocol, function and send id, then the arguments
+        let (open, map) = server.join().unwrap();
+        let header = |p: &[u8]| -> Vec<u32> {
+            p[..20].chunks(4).map(|b| u32::from_le_bytes(b.try_into().unwrap())).collect()
+        };
+        assert_eq!(header(&open)[..4], [16 + 256 + 4 + 4 + 16, 5, 0xf000_0001, 1]);
+        assert_eq!(&open[16..21], b"Test\0");
+        assert_eq!(header(&map), [16 + 4 + 256, 5, 0xf000_0004, 2, 3]);
+        assert_eq!(&map[20..27], b"brakes\0");
+
+        net.wait(Some(Duration::from_secs(5)));
+        let recv = net.try_recv(|_| {}).unwrap();
+        assert_eq!(recv, Some(Recv::Event(Event {
+            group_id: Some(crate::ids::GroupId::new(1)),
+            event_id: ClientEventId::new(3),
+            data: 42,
+        })));
+
+        // the server hung up
+        net.wait(Some(Duration::from_secs(5)));
+        assert!(matches!(net.try_recv(|_| {}), Err(Error::Closed)));
+    }
+}
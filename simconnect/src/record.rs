@@ -0,0 +1,474 @@
+use std::cell::{Cell, RefCell};
+use std::collections::VecDeque;
+use std::fmt;
+use std::fs::File;
+use std::io::{self, BufReader, BufWriter, Read, Write};
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::thread::JoinHandle;
+use std::time::{Duration, Instant};
+
+use crate::connection::SimConnect;
+use crate::error::{Error, Result};
+
+// start of every recording, followed by the format version
+const MAGIC: &[u8; 8] = b"SCRECORD";
+const VERSION: u32 = 1;
+
+const RECEIVED: u8 = 0;
+const SENT: u8 = 1;
+
+/// One entry of a [`Recording`], timed from the start of the recording.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Entry {
+
+    /// A raw `SIMCONNECT_RECV` packet, decoded with [`Recv::decode`](crate::Recv::decode).
+    Received {
+        time: Duration,
+        packet: Vec<u8>,
+    },
+
+    /// A request sent by the client, described as in
+    /// [`SimConnect::sent_call`].
+    Sent {
+        time: Duration,
+        send_id: u32,
+        call: String,
+    },
+}
+
+impl Entry {
+
+    /// Returns when the entry was recorded, from the start of the recording.
+    pub fn time(&self) -> Duration {
+        match self {
+            Entry::Received { time, .. } | Entry::Sent { time, .. } => *time,
+        }
+    }
+
+    fn write(&self, w: &mut impl Write) -> io::Result<()> {
+        let micros = |time: &Duration| u64::try_from(time.as_micros()).unwrap_or(u64::MAX).to_le_bytes();
+        match self {
+            Entry::Received { time, packet } => {
+                w.write_all(&[RECEIVED])?;
+                w.write_all(&micros(time))?;
+                w.write_all(&(packet.len() as u32).to_le_bytes())?;
+                w.write_all(packet)
+            }
+            Entry::Sent { time, send_id, call } => {
+                w.write_all(&[SENT])?;
+                w.write_all(&micros(time))?;
+                w.write_all(&send_id.to_le_bytes())?;
+                w.write_all(&(call.len() as u32).to_le_bytes())?;
+                w.write_all(call.as_bytes())
+            }
+        }
+    }
+
+    // returns `None` at a clean end of the recording
+    fn read(r: &mut impl Read) -> io::Result<Option<Self>> {
+        let mut kind = [0];
+        if r.read(&mut kind)? == 0 {
+            return Ok(None);
+        }
+        let time = Duration::from_micros(read_u64(r)?);
+        let entry = match kind[0] {
+            RECEIVED => Entry::Received { time, packet: read_bytes(r)? },
+            SENT => {
+                let send_id = read_u32(r)?;
+                let call = String::from_utf8(read_bytes(r)?)
+                    .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+                Entry::Sent { time, send_id, call }
+            }
+            kind => return Err(invalid_data(&format!("unknown entry kind {}", kind))),
+        };
+        Ok(Some(entry))
+    }
+}
+
+/// A recorded SimConnect session, every packet received along with the
+/// requests that produced them.
+///
+/// Recordings are made with [`SimConnect::start_recording`] and fed back
+/// through the usual receive APIs by [`SimConnect::replay`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Recording {
+    entries: Vec<Entry>,
+}
+
+impl Recording {
+
+    /// Reads a recording written by [`SimConnect::start_recording`].
+    ///
+    /// A recording cut short, e.g. by a crash while recording, is read up to
+    /// its last complete entry.
+    pub fn read(r: impl Read) -> Result<Self> {
+        let mut r = BufReader::new(r);
+        let mut magic = [0; 8];
+        r.read_exact(&mut magic)?;
+        if &magic != MAGIC {
+            return Err(invalid_data("not a SimConnect recording").into());
+        }
+        let version = read_u32(&mut r)?;
+        if version != VERSION {
+            return Err(invalid_data(&format!("unsupported recording version {}", version)).into());
+        }
+
+        let mut entries = Vec::new();
+        loop {
+            match Entry::read(&mut r) {
+                Ok(Some(entry)) => entries.push(entry),
+                Ok(None) => break,
+                Err(e) if e.kind() == io::ErrorKind::UnexpectedEof => break,
+                Err(e) => return Err(e.into()),
+            }
+        }
+        Ok(Self { entries })
+    }
+
+    /// Reads the recording at `path`.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        Self::read(File::open(path)?)
+    }
+
+    /// Returns the recorded entries, in the order they happened.
+    pub fn entries(&self) -> &[Entry] {
+        &self.entries
+    }
+
+    /// Returns the time from the start of the recording to its last entry.
+    pub fn duration(&self) -> Duration {
+        self.entries.last().map_or(Duration::ZERO, Entry::time)
+    }
+}
+
+impl FromIterator<Entry> for Recording {
+    fn from_iter<I: IntoIterator<Item = Entry>>(iter: I) -> Self {
+        Self { entries: iter.into_iter().collect() }
+    }
+}
+
+/// Writes entries as they happen, see [`SimConnect::start_recording`].
+pub(crate) struct Recorder {
+    out: BufWriter<Box<dyn Write + Send>>,
+    start: Instant,
+
+    // the first write error, recording stops after it
+    error: Option<io::Error>,
+}
+
+impl Recorder {
+
+    pub(crate) fn new(out: Box<dyn Write + Send>) -> Result<Self> {
+        let mut out = BufWriter::new(out);
+        out.write_all(MAGIC)?;
+        out.write_all(&VERSION.to_le_bytes())?;
+        Ok(Self { out, start: Instant::now(), error: None })
+    }
+
+    pub(crate) fn received(&mut self, packet: &[u8]) {
+        self.write(Entry::Received { time: self.start.elapsed(), packet: packet.to_vec() });
+    }
+
+    pub(crate) fn sent(&mut self, send_id: u32, call: &str) {
+        self.write(Entry::Sent { time: self.start.elapsed(), send_id, call: call.to_string() });
+    }
+
+    fn write(&mut self, entry: Entry) {
+        if self.error.is_none() {
+            self.error = entry.write(&mut self.out).err();
+        }
+    }
+
+    pub(crate) fn finish(mut self) -> Result<()> {
+        match self.error.take() {
+            Some(e) => Err(e.into()),
+            None => Ok(self.out.flush()?),
+        }
+    }
+}
+
+impl fmt::Debug for Recorder {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Recorder")
+            .field("start", &self.start)
+            .field("error", &self.error)
+            .finish_non_exhaustive()
+    }
+}
+
+/// A connection replaying a [`Recording`], see [`SimConnect::replay`].
+#[derive(Debug)]
+pub(crate) struct Replay {
+    entries: RefCell<VecDeque<Entry>>,
+    start: Instant,
+    speed: f64,
+    send_id: Cell<u32>,
+}
+
+impl Replay {
+
+    pub(crate) fn new(recording: Recording, speed: f64) -> Result<Self> {
+        if speed.is_nan() || speed <= 0.0 {
+            return Err(Error::InvalidArgument { name: "speed", reason: "must be positive" });
+        }
+
+        // checked once here so `due` can't overflow while receiving, entries
+        // recorded later are due later
+        let start = Instant::now();
+        let last = recording.entries.iter().map(Entry::time).max().unwrap_or_default();
+        let last_due = Duration::try_from_secs_f64(last.as_secs_f64() / speed).ok()
+            .and_then(|time| start.checked_add(time));
+        if last_due.is_none() {
+            return Err(Error::InvalidArgument { name: "speed", reason: "is too small for the length of the recording" });
+        }
+
+        Ok(Self {
+            entries: RefCell::new(recording.entries.into()),
+            start,
+            speed,
+            send_id: Cell::new(0),
+        })
+    }
+
+    pub(crate) fn last_send_id(&self) -> Result<u32> {
+        Ok(self.send_id.get())
+    }
+
+    // when an entry recorded at `time` is due
+    fn due(&self, time: Duration) -> Instant {
+        self.start + time.div_f64(self.speed)
+    }
+
+    /// Returns the next entry if it is due, ending with [`Error::Closed`]
+    /// once every entry was replayed.
+    pub(crate) fn next(&self) -> Result<Option<Entry>> {
+        let mut entries = self.entries.borrow_mut();
+        let Some(entry) = entries.front() else {
+            return Err(Error::Closed);
+        };
+        if self.due(entry.time()) > Instant::now() {
+            return Ok(None);
+        }
+        let entry = entries.pop_front();
+        if let Some(Entry::Sent { send_id, .. }) = &entry {
+            self.send_id.set(*send_id);
+        }
+        Ok(entry)
+    }
+
+    pub(crate) fn wait(&self, timeout: Option<Duration>) {
+        let Some(due) = self.entries.borrow().front().map(|e| self.due(e.time())) else {
+            return;
+        };
+        let mut remaining = due.saturating_duration_since(Instant::now());
+        if let Some(timeout) = timeout {
+            remaining = remaining.min(timeout);
+        }
+        std::thread::sleep(remaining);
+    }
+
+    pub(crate) fn notify(&self, f: Arc<dyn Fn() + Send + Sync>) -> Result<ReplayNotifier> {
+        let due: Vec<_> = self.entries.borrow().iter().map(|e| self.due(e.time())).collect();
+        let stop = Arc::new(AtomicBool::new(false));
+        let thread = {
+            let stop = stop.clone();
+            std::thread::Builder::new()
+                .name("simconnect-replay".into())
+                .spawn(move || {
+                    // once more at the end, for the replay closing
+                    for due in due.into_iter().chain([Instant::now()]) {
+                        while !stop.load(Ordering::Relaxed) && Instant::now() < due {
+                            std::thread::park_timeout(due.saturating_duration_since(Instant::now()));
+                        }
+                        if stop.load(Ordering::Relaxed) {
+                            return;
+                        }
+                        f();
+                    }
+                })?
+        };
+        Ok(ReplayNotifier { stop, thread: Some(thread) })
+    }
+}
+
+/// Calls the function given to [`Replay::notify`] as entries become due.
+#[derive(Debug)]
+pub(crate) struct ReplayNotifier {
+    stop: Arc<AtomicBool>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl Drop for ReplayNotifier {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::Relaxed);
+        if let Some(thread) = self.thread.take() {
+            thread.thread().unpark();
+            let _ = thread.join();
+        }
+    }
+}
+
+impl SimConnect {
+
+    /// Starts recording every packet received and every request sent on
+    /// this connection to `out`, replacing any recording in progress.
+    ///
+    /// ```no_run
+    /// use std::fs::File;
+    /// use simconnect::SimConnect;
+    ///
+    /// let sc = SimConnect::open("Example")?;
+    /// sc.start_recording(File::create("session.screc")?)?;
+    /// // ...
+    /// sc.stop_recording()?;
+    /// # Ok::<(), simconnect::Error>(())
+    /// ```
+    ///
+    /// Writes are buffered, a failed write stops the recording and is
+    /// returned by [`SimConnect::stop_recording`].
+    pub fn start_recording(&self, out: impl Write + Send + 'static) -> Result<()> {
+        let recorder = Recorder::new(Box::new(out))?;
+        // the previous recording is flushed, its errors can't be reported here
+        if let Some(previous) = self.recorder().replace(recorder) {
+            let _ = previous.finish();
+        }
+        Ok(())
+    }
+
+    /// Stops recording, flushing the recording and returning the first error
+    /// writing it. Does nothing if the connection isn't being recorded.
+    pub fn stop_recording(&self) -> Result<()> {
+        match self.recorder().take() {
+            Some(recorder) => recorder.finish(),
+            None => Ok(()),
+        }
+    }
+
+    /// Returns a connection replaying `recording`, receiving its packets as
+    /// if they came from the simulator.
+    ///
+    /// Packets are released at their recorded times divided by `speed`, 1.0
+    /// replays in real time and `f64::INFINITY` as fast as they are received.
+    /// Once every packet has been received the connection reports
+    /// [`Error::Closed`].
+    ///
+    /// Requests sent on the connection are accepted and discarded, recorded
+    /// requests are added to the send record as they are replayed so
+    /// exceptions still name the call that caused them.
+    ///
+    /// ```no_run
+    /// use simconnect::{Recording, SimConnect};
+    ///
+    /// let sc = SimConnect::replay(Recording::load("session.screc")?, 10.0)?;
+    /// for recv in sc.iter() {
+    ///     println!("{:?}", recv?);
+    /// }
+    /// # Ok::<(), simconnect::Error>(())
+    /// ```
+    ///
+    /// Returns [`Error::InvalidArgument`] if `speed` isn't positive, or is so
+    /// small the last packet would never be due.
+    pub fn replay(recording: Recording, speed: f64) -> Result<Self> {
+        Ok(Self::with_replay(Replay::new(recording, speed)?))
+    }
+}
+
+fn read_u32(r: &mut impl Read) -> io::Result<u32> {
+    let mut buf = [0; 4];
+    r.read_exact(&mut buf)?;
+    Ok(u32::from_le_bytes(buf))
+}
+
+fn read_u64(r: &mut impl Read) -> io::Result<u64> {
+    let mut buf = [0; 8];
+    r.read_exact(&mut buf)?;
+    Ok(u64::from_le_bytes(buf))
+}
+
+fn read_bytes(r: &mut impl Read) -> io::Result<Vec<u8>> {
+    let len = read_u32(r)? as usize;
+    let mut bytes = Vec::new();
+    // read through `take` so a corrupt length can't allocate gigabytes up front
+    r.take(len as u64).read_to_end(&mut bytes)?;
+    if bytes.len() != len {
+        return Err(io::ErrorKind::UnexpectedEof.into());
+    }
+    Ok(bytes)
+}
+
+fn invalid_data(msg: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, msg)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use super::*;
+    use crate::recv::Recv;
+
+    // a `Write` that can be read back after the recorder is done with it
+    #[derive(Clone, Default)]
+    struct Shared(Arc<Mutex<Vec<u8>>>);
+
+    impl Write for Shared {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0.lock().unwrap().write(buf)
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    fn packet(words: &[u32]) -> Vec<u8> {
+        words.iter().flat_map(|w| w.to_le_bytes()).collect()
+    }
+
+    #[test]
+    fn round_trip() {
+        let out = Shared::default();
+        let mut recorder = Recorder::new(Box::new(out.clone())).unwrap();
+        recorder.sent(2, "SimConnect_MapClientEventToSimEvent(1, \"brakez\")");
+        recorder.received(&packet(&[24, 6, 1, 7, 2, 3]));
+        recorder.finish().unwrap();
+
+        let bytes = out.0.lock().unwrap().clone();
+        let recording = Recording::read(&bytes[..]).unwrap();
+        assert_eq!(recording.entries().len(), 2);
+        assert!(matches!(&recording.entries()[0], Entry::Sent { send_id: 2, .. }));
+
+        // a truncated recording keeps its complete entries
+        let recording = Recording::read(&bytes[..bytes.len() - 1]).unwrap();
+        assert_eq!(recording.entries().len(), 1);
+        assert!(Recording::read(&b"not a recording"[..]).is_err());
+    }
+
+    #[test]
+    fn replay() {
+        let recording: Recording = [
+            Entry::Sent { time: Duration::ZERO, send_id: 2, call: "SimConnect_Test(1)".into() },
+            Entry::Received { time: Duration::from_millis(10), packet: packet(&[24, 6, 1, 7, 2, 3]) },
+            Entry::Received { time: Duration::from_secs(1000), packet: packet(&[12, 6, 3]) },
+        ].into_iter().collect();
+
+        for speed in [0.0, -1.0, f64::NAN, 1e-300] {
+            assert!(matches!(SimConnect::replay(recording.clone(), speed), Err(Error::InvalidArgument { .. })));
+        }
+
+        let sc = SimConnect::replay(recording.clone(), 1.0).unwrap();
+        let Some(Recv::Exception(e)) = sc.recv_timeout(Duration::from_secs(5)).unwrap() else { panic!() };
+        assert_eq!(e.call(), Some("SimConnect_Test(1)"));
+        assert_eq!(sc.last_send_id().unwrap(), 2);
+        assert!(sc.try_recv().unwrap().is_none());
+
+        // as fast as possible, ending once everything was received
+        let sc = SimConnect::replay(recording, f64::INFINITY).unwrap();
+        let received: Vec<_> = sc.iter().collect();
+        assert_eq!(received.len(), 2);
+        assert!(matches!(received[1], Ok(Recv::Quit)));
+        assert!(matches!(sc.try_recv(), Err(Error::Closed)));
+    }
+}
@@ -0,0 +1,725 @@
+use simconnect_sys::*;
+
+use crate::error::Exception;
+use crate::facilities::{
+    Airport, ControllerItem, FacilityMinimal, InputEventDescriptor, JetwayData, Ndb, Vor, Waypoint,
+};
+use crate::flags::DataRequestFlags;
+use crate::ids::{ClientEventId, DefineId, GroupId, RequestId};
+use crate::types::{Guid, LatLonAlt, Pbh, SimObjectType, Xyz};
+
+// size of SIMCONNECT_RECV, every packet starts with it
+pub(crate) const HEADER_SIZE: usize = 12;
+
+/// Errors returned when a received packet can't be decoded.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum DecodeError {
+
+    /// The packet is shorter than its message type requires.
+    #[error("packet {id} is too short, needs {needed} bytes but has {len}")]
+    TooShort {
+        id: u32,
+        needed: usize,
+        len: usize,
+    },
+
+    /// `dwSize` doesn't fit the buffer the packet was received in.
+    #[error("packet {id} claims {size} bytes but only {len} were received")]
+    SizeMismatch {
+        id: u32,
+        size: usize,
+        len: usize,
+    },
+
+    /// A field holds a value that isn't valid for its type.
+    #[error("packet {id} has an invalid {field} ({value})")]
+    InvalidValue {
+        id: u32,
+        field: &'static str,
+        value: u32,
+    },
+
+    /// A string field of a data definition isn't valid UTF-8.
+    #[error("packet {id} has invalid UTF-8 in the string at byte {offset}: {error}")]
+    InvalidUtf8 {
+        id: u32,
+        offset: usize,
+        error: std::str::Utf8Error,
+    },
+}
+
+/// A message received from SimConnect.
+///
+/// Decoded from the raw `SIMCONNECT_RECV_*` structures into owned values,
+/// with every size and array length checked against the received packet.
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum Recv {
+    Null,
+    Exception(Exception),
+    Open(Open),
+    Quit,
+    Event(Event),
+    EventObjectAddRemove {
+        event: Event,
+        object_type: SimObjectType,
+    },
+    EventFilename {
+        event: Event,
+        file_name: String,
+        flags: u32,
+    },
+    EventFrame {
+        event: Event,
+        frame_rate: f32,
+        sim_speed: f32,
+    },
+    SimObjectData(SimObjectData),
+    SimObjectDataByType(SimObjectData),
+    WeatherObservation {
+        request_id: RequestId,
+        metar: String,
+    },
+    CloudState {
+        request_id: RequestId,
+        data: Vec<u8>,
+    },
+    AssignedObjectId {
+        request_id: RequestId,
+        object_id: u32,
+    },
+    ReservedKey {
+        choice_reserved: String,
+        reserved_key: String,
+    },
+    CustomAction {
+        event: Event,
+        instance_id: Guid,
+        wait_for_completion: bool,
+        payload: String,
+    },
+    SystemState(SystemState),
+    ClientData(SimObjectData),
+    EventWeatherMode(Event),
+    AirportList(List<Airport>),
+    VorList(List<Vor>),
+    NdbList(List<Ndb>),
+    WaypointList(List<Waypoint>),
+    EventMultiplayerServerStarted(Event),
+    EventMultiplayerClientStarted(Event),
+    EventMultiplayerSessionEnded(Event),
+    EventRaceEnd {
+        event: Event,
+        racer_number: u32,
+        result: RaceResult,
+    },
+    EventRaceLap {
+        event: Event,
+        lap_index: u32,
+        result: RaceResult,
+    },
+    EventEx1 {
+        group_id: Option<GroupId>,
+        event_id: ClientEventId,
+        data: [u32; 5],
+    },
+    FacilityData(FacilityData),
+    FacilityDataEnd {
+        request_id: RequestId,
+    },
+    FacilityMinimalList(List<FacilityMinimal>),
+    JetwayData(List<JetwayData>),
+    ControllersList(List<ControllerItem>),
+    ActionCallback {
+        action_id: String,
+        request_id: RequestId,
+    },
+    EnumerateInputEvents(List<InputEventDescriptor>),
+    GetInputEvent {
+        request_id: RequestId,
+        value: InputEventValue,
+    },
+    SubscribeInputEvent {
+        hash: u64,
+        value: InputEventValue,
+    },
+    EnumerateInputEventParams {
+        hash: u64,
+        value: String,
+    },
+
+    /// A message id this crate doesn't know about, from a newer simulator.
+    Unknown {
+        id: u32,
+        data: Vec<u8>,
+    },
+}
+
+/// The simulator and SimConnect versions (`SIMCONNECT_RECV_OPEN`).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Open {
+    pub application_name: String,
+    pub application_version: Version,
+    pub simconnect_version: Version,
+}
+
+/// A version number as reported by `SIMCONNECT_RECV_OPEN`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Version {
+    pub major: u32,
+    pub minor: u32,
+    pub build_major: u32,
+    pub build_minor: u32,
+}
+
+/// A client event (`SIMCONNECT_RECV_EVENT`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Event {
+
+    /// The notification group, `None` for events outside of any group.
+    pub group_id: Option<GroupId>,
+    pub event_id: ClientEventId,
+    pub data: u32,
+}
+
+/// Data for a data definition (`SIMCONNECT_RECV_SIMOBJECT_DATA`), also used
+/// for data by type and client data.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SimObjectData {
+    pub request_id: RequestId,
+    pub object_id: u32,
+    pub define_id: DefineId,
+    pub flags: DataRequestFlags,
+    pub entry_number: u32,
+    pub out_of: u32,
+    pub define_count: u32,
+
+    /// The raw data, laid out as described by the data definition.
+    pub data: Vec<u8>,
+}
+
+/// The result of a system state request (`SIMCONNECT_RECV_SYSTEM_STATE`).
+#[derive(Debug, Clone, PartialEq)]
+pub struct SystemState {
+    pub request_id: RequestId,
+    pub integer: u32,
+    pub float: f32,
+    pub string: String,
+}
+
+/// One part of a list response, large lists are split across several
+/// messages numbered `entry_number` out of `out_of`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct List<T> {
+    pub request_id: RequestId,
+    pub entry_number: u32,
+    pub out_of: u32,
+    pub items: Vec<T>,
+}
+
+/// Facility data for a facility data definition (`SIMCONNECT_RECV_FACILITY_DATA`).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FacilityData {
+    pub user_request_id: RequestId,
+    pub unique_request_id: u32,
+    pub parent_unique_request_id: u32,
+    pub data_type: u32,
+    pub is_list_item: bool,
+    pub item_index: u32,
+    pub list_size: u32,
+    pub data: Vec<u8>,
+}
+
+/// The result of a race or lap (`SIMCONNECT_DATA_RACE_RESULT`).
+#[derive(Debug, Clone, PartialEq)]
+pub struct RaceResult {
+    pub number_of_racers: u32,
+    pub mission_guid: Guid,
+    pub player_name: String,
+    pub session_type: String,
+    pub aircraft: String,
+    pub player_role: String,
+    pub total_time: f64,
+    pub penalty_time: f64,
+    pub is_disqualified: bool,
+}
+
+/// The value of an input event.
+#[derive(Debug, Clone, PartialEq)]
+pub enum InputEventValue {
+    Double(f64),
+    String(String),
+}
+
+impl Recv {
+
+    /// Decodes a received packet.
+    ///
+    /// `data` must start with the `SIMCONNECT_RECV` header, it may be longer
+    /// than the packet's `dwSize` but never shorter.
+    pub fn decode(data: &[u8]) -> Result<Self, DecodeError> {
+        let mut r = Reader::new(0, data);
+        let size = r.u32()? as usize;
+        let _version = r.u32()?;
+        let id = r.u32()?;
+        if size < HEADER_SIZE || size > data.len() {
+            return Err(DecodeError::SizeMismatch { id, size, len: data.len() });
+        }
+        let mut r = Reader { id, buf: &data[..size], pos: HEADER_SIZE };
+
+        let recv = match id as SIMCONNECT_RECV_ID {
+            SIMCONNECT_RECV_ID_NULL => Recv::Null,
+            SIMCONNECT_RECV_ID_EXCEPTION => {
+                let code = r.u32()?;
+                let send_id = r.u32()?;
+                let index = r.u32()?;
+                Recv::Exception(Exception::new(code, send_id, index))
+            },
+            SIMCONNECT_RECV_ID_OPEN => {
+                let application_name = r.string(256)?;
+                let application_version = r.decode()?;
+                let simconnect_version = r.decode()?;
+                let _reserved = r.take(8)?;
+                Recv::Open(Open { application_name, application_version, simconnect_version })
+            },
+            SIMCONNECT_RECV_ID_QUIT => Recv::Quit,
+            SIMCONNECT_RECV_ID_EVENT => Recv::Event(r.decode()?),
+            SIMCONNECT_RECV_ID_EVENT_OBJECT_ADDREMOVE => {
+                let event = r.decode()?;
+                let raw = r.u32()?;
+                let object_type = SimObjectType::try_from(raw)
+                    .map_err(|_| DecodeError::InvalidValue { id, field: "eObjType", value: raw })?;
+                Recv::EventObjectAddRemove { event, object_type }
+            },
+            SIMCONNECT_RECV_ID_EVENT_FILENAME => Recv::EventFilename {
+                event: r.decode()?,
+                file_name: r.string(MAX_PATH as usize)?,
+                flags: r.u32()?,
+            },
+            SIMCONNECT_RECV_ID_EVENT_FRAME => Recv::EventFrame {
+                event: r.decode()?,
+                frame_rate: r.f32()?,
+                sim_speed: r.f32()?,
+            },
+            SIMCONNECT_RECV_ID_SIMOBJECT_DATA => Recv::SimObjectData(r.decode()?),
+            SIMCONNECT_RECV_ID_SIMOBJECT_DATA_BYTYPE => Recv::SimObjectDataByType(r.decode()?),
+            SIMCONNECT_RECV_ID_WEATHER_OBSERVATION => Recv::WeatherObservation {
+                request_id: r.request_id()?,
+                metar: r.string_v(),
+            },
+            SIMCONNECT_RECV_ID_CLOUD_STATE => {
+                let request_id = r.request_id()?;
+                let len = r.u32()? as usize;
+                let data = r.take(len)?.to_vec();
+                Recv::CloudState { request_id, data }
+            },
+            SIMCONNECT_RECV_ID_ASSIGNED_OBJECT_ID => Recv::AssignedObjectId {
+                request_id: r.request_id()?,
+                object_id: r.u32()?,
+            },
+            SIMCONNECT_RECV_ID_RESERVED_KEY => Recv::ReservedKey {
+                choice_reserved: r.string(30)?,
+                reserved_key: r.string(50)?,
+            },
+            SIMCONNECT_RECV_ID_CUSTOM_ACTION => Recv::CustomAction {
+                event: r.decode()?,
+                instance_id: r.decode()?,
+                wait_for_completion: r.u32()? != 0,
+                payload: r.string_v(),
+            },
+            SIMCONNECT_RECV_ID_SYSTEM_STATE => Recv::SystemState(SystemState {
+                request_id: r.request_id()?,
+                integer: r.u32()?,
+                float: r.f32()?,
+                string: r.string(MAX_PATH as usize)?,
+            }),
+            SIMCONNECT_RECV_ID_CLIENT_DATA => Recv::ClientData(r.decode()?),
+            SIMCONNECT_RECV_ID_EVENT_WEATHER_MODE => Recv::EventWeatherMode(r.decode()?),
+            SIMCONNECT_RECV_ID_AIRPORT_LIST => Recv::AirportList(r.list()?),
+            SIMCONNECT_RECV_ID_VOR_LIST => Recv::VorList(r.list()?),
+            SIMCONNECT_RECV_ID_NDB_LIST => Recv::NdbList(r.list()?),
+            SIMCONNECT_RECV_ID_WAYPOINT_LIST => Recv::WaypointList(r.list()?),
+            SIMCONNECT_RECV_ID_EVENT_MULTIPLAYER_SERVER_STARTED => Recv::EventMultiplayerServerStarted(r.decode()?),
+            SIMCONNECT_RECV_ID_EVENT_MULTIPLAYER_CLIENT_STARTED => Recv::EventMultiplayerClientStarted(r.decode()?),
+            SIMCONNECT_RECV_ID_EVENT_MULTIPLAYER_SESSION_ENDED => Recv::EventMultiplayerSessionEnded(r.decode()?),
+            SIMCONNECT_RECV_ID_EVENT_RACE_END => Recv::EventRaceEnd {
+                event: r.decode()?,
+                racer_number: r.u32()?,
+                result: r.decode()?,
+            },
+            SIMCONNECT_RECV_ID_EVENT_RACE_LAP => Recv::EventRaceLap {
+                event: r.decode()?,
+                lap_index: r.u32()?,
+                result: r.decode()?,
+            },
+            #[cfg(sdk_at_least_0_24_3)]
+            SIMCONNECT_RECV_ID_EVENT_EX1 => Recv::EventEx1 {
+                group_id: group_id(r.u32()?),
+                event_id: ClientEventId::new(r.u32()?),
+                data: [r.u32()?, r.u32()?, r.u32()?, r.u32()?, r.u32()?],
+            },
+            SIMCONNECT_RECV_ID_FACILITY_DATA => Recv::FacilityData(FacilityData {
+                user_request_id: r.request_id()?,
+                unique_request_id: r.u32()?,
+                parent_unique_request_id: r.u32()?,
+                data_type: r.u32()?,
+                is_list_item: r.u32()? != 0,
+                item_index: r.u32()?,
+                list_size: r.u32()?,
+                data: r.rest().to_vec(),
+            }),
+            SIMCONNECT_RECV_ID_FACILITY_DATA_END => Recv::FacilityDataEnd {
+                request_id: r.request_id()?,
+            },
+            SIMCONNECT_RECV_ID_FACILITY_MINIMAL_LIST => Recv::FacilityMinimalList(r.list()?),
+            SIMCONNECT_RECV_ID_JETWAY_DATA => Recv::JetwayData(r.list()?),
+            SIMCONNECT_RECV_ID_CONTROLLERS_LIST => Recv::ControllersList(r.list()?),
+            SIMCONNECT_RECV_ID_ACTION_CALLBACK => Recv::ActionCallback {
+                action_id: r.string(MAX_PATH as usize)?,
+                request_id: r.request_id()?,
+            },
+            SIMCONNECT_RECV_ID_ENUMERATE_INPUT_EVENTS => Recv::EnumerateInputEvents(r.list()?),
+            SIMCONNECT_RECV_ID_GET_INPUT_EVENT => Recv::GetInputEvent {
+                request_id: r.request_id()?,
+                value: r.input_event_value()?,
+            },
+            SIMCONNECT_RECV_ID_SUBSCRIBE_INPUT_EVENT => Recv::SubscribeInputEvent {
+                hash: r.u64()?,
+                value: r.input_event_value()?,
+            },
+            SIMCONNECT_RECV_ID_ENUMERATE_INPUT_EVENT_PARAMS => Recv::EnumerateInputEventParams {
+                hash: r.u64()?,
+                value: r.string(MAX_PATH as usize)?,
+            },
+            _ => Recv::Unknown { id, data: data[..size].to_vec() },
+        };
+        Ok(recv)
+    }
+
+    /// Decodes a packet received from `SimConnect_GetNextDispatch` or a
+    /// dispatch callback.
+    ///
+    /// # Safety
+    ///
+    /// `data` must be valid for reads of `cb_data` bytes.
+    pub unsafe fn from_raw(data: *const SIMCONNECT_RECV, cb_data: u32) -> Result<Self, DecodeError> {
+        if data.is_null() {
+            return Err(DecodeError::TooShort { id: 0, needed: HEADER_SIZE, len: 0 });
+        }
+        Self::decode(std::slice::from_raw_parts(data as *const u8, cb_data as usize))
+    }
+}
+
+fn group_id(raw: u32) -> Option<GroupId> {
+    match raw {
+        SIMCONNECT_RECV_EVENT_UNKNOWN_GROUP => None,
+        id => Some(GroupId::new(id)),
+    }
+}
+
+/// A little endian cursor over a received packet, used to implement
+/// [`DataValue`](crate::DataValue) for custom types.
+#[derive(Debug)]
+pub struct Reader<'a> {
+    id: u32,
+    buf: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Reader<'a> {
+
+    /// Creates a reader over `buf`, errors are reported against message `id`.
+    pub fn new(id: u32, buf: &'a [u8]) -> Self {
+        Self { id, buf, pos: 0 }
+    }
+
+    /// Takes the next `n` bytes.
+    pub fn take(&mut self, n: usize) -> Result<&'a [u8], DecodeError> {
+        let needed = self.pos.saturating_add(n);
+        if needed > self.buf.len() {
+            return Err(DecodeError::TooShort { id: self.id, needed, len: self.buf.len() });
+        }
+        let bytes = &self.buf[self.pos..needed];
+        self.pos = needed;
+        Ok(bytes)
+    }
+
+    fn array<const N: usize>(&mut self) -> Result<[u8; N], DecodeError> {
+        // take() returned exactly N bytes
+        Ok(self.take(N)?.try_into().unwrap())
+    }
+
+    pub fn u8(&mut self) -> Result<u8, DecodeError> {
+        Ok(self.array::<1>()?[0])
+    }
+
+    pub fn u16(&mut self) -> Result<u16, DecodeError> {
+        self.array().map(u16::from_le_bytes)
+    }
+
+    pub fn u32(&mut self) -> Result<u32, DecodeError> {
+        self.array().map(u32::from_le_bytes)
+    }
+
+    pub(crate) fn request_id(&mut self) -> Result<RequestId, DecodeError> {
+        self.u32().map(RequestId::new)
+    }
+
+    pub fn i32(&mut self) -> Result<i32, DecodeError> {
+        self.array().map(i32::from_le_bytes)
+    }
+
+    pub fn i64(&mut self) -> Result<i64, DecodeError> {
+        self.array().map(i64::from_le_bytes)
+    }
+
+    pub fn u64(&mut self) -> Result<u64, DecodeError> {
+        self.array().map(u64::from_le_bytes)
+    }
+
+    pub fn f32(&mut self) -> Result<f32, DecodeError> {
+        self.array().map(f32::from_le_bytes)
+    }
+
+    pub fn f64(&mut self) -> Result<f64, DecodeError> {
+        self.array().map(f64::from_le_bytes)
+    }
+
+    /// Reads a fixed size, nul padded string.
+    pub fn string(&mut self, len: usize) -> Result<String, DecodeError> {
+        self.take(len).map(c_str)
+    }
+
+    /// Reads a variable length string running to the end of the packet.
+    pub fn string_v(&mut self) -> String {
+        c_str(self.rest())
+    }
+
+    /// Reads a string from a fixed size field of `len` bytes, failing on
+    /// invalid UTF-8 rather than replacing it.
+    pub fn string_utf8(&mut self, len: usize) -> Result<String, DecodeError> {
+        let offset = self.pos;
+        let bytes = self.take(len)?;
+        let end = bytes.iter().position(|b| *b == 0).unwrap_or(bytes.len());
+        utf8(self.id, offset, &bytes[..end])
+    }
+
+    /// Reads a nul terminated string, consuming the terminator, as
+    /// `SIMCONNECT_DATATYPE_STRINGV` fields are stored.
+    pub fn string_nul(&mut self) -> Result<String, DecodeError> {
+        let offset = self.pos;
+        let len = self.nul_position()?;
+        let s = utf8(self.id, offset, self.take(len)?)?;
+        self.pos += 1;
+        Ok(s)
+    }
+
+    // the length of the nul terminated string at the current position
+    pub(crate) fn nul_position(&self) -> Result<usize, DecodeError> {
+        self.buf[self.pos..].iter().position(|b| *b == 0)
+            .ok_or(DecodeError::TooShort { id: self.id, needed: self.buf.len() + 1, len: self.buf.len() })
+    }
+
+    /// Returns the id of the message being read.
+    pub fn id(&self) -> u32 {
+        self.id
+    }
+
+    /// Returns the number of bytes read so far.
+    pub fn position(&self) -> usize {
+        self.pos
+    }
+
+    /// Returns everything left in the packet.
+    pub fn rest(&mut self) -> &'a [u8] {
+        let bytes = &self.buf[self.pos..];
+        self.pos = self.buf.len();
+        bytes
+    }
+
+    pub(crate) fn decode<T: Decode>(&mut self) -> Result<T, DecodeError> {
+        T::decode(self)
+    }
+
+    // the list header shared by SIMCONNECT_RECV_FACILITIES_LIST and
+    // SIMCONNECT_RECV_LIST_TEMPLATE, followed by dwArraySize items
+    fn list<T: Decode>(&mut self) -> Result<List<T>, DecodeError> {
+        let request_id = self.request_id()?;
+        let count = self.u32()? as usize;
+        let entry_number = self.u32()?;
+        let out_of = self.u32()?;
+
+        // check the whole array fits before allocating anything for it
+        let needed = count.checked_mul(T::SIZE)
+            .and_then(|n| n.checked_add(self.pos))
+            .unwrap_or(usize::MAX);
+        if needed > self.buf.len() {
+            return Err(DecodeError::TooShort { id: self.id, needed, len: self.buf.len() });
+        }
+        let items = (0..count).map(|_| self.decode()).collect::<Result<_, _>>()?;
+        Ok(List { request_id, entry_number, out_of, items })
+    }
+
+    fn input_event_value(&mut self) -> Result<InputEventValue, DecodeError> {
+        let kind = self.u32()?;
+        match kind {
+            SIMCONNECT_INPUT_EVENT_TYPE_DOUBLE => Ok(InputEventValue::Double(self.f64()?)),
+            SIMCONNECT_INPUT_EVENT_TYPE_STRING => Ok(InputEventValue::String(self.string_v())),
+            _ => Err(DecodeError::InvalidValue { id: self.id, field: "eType", value: kind }),
+        }
+    }
+
+    /// Returns an error for an invalid value of `field`.
+    pub fn invalid(&self, field: &'static str, value: u32) -> DecodeError {
+        DecodeError::InvalidValue { id: self.id, field, value }
+    }
+}
+
+fn utf8(id: u32, offset: usize, bytes: &[u8]) -> Result<String, DecodeError> {
+    match std::str::from_utf8(bytes) {
+        Ok(s) => Ok(s.to_owned()),
+        Err(error) => Err(DecodeError::InvalidUtf8 { id, offset, error }),
+    }
+}
+
+// strings are nul terminated within their fields and expected to be ASCII
+fn c_str(bytes: &[u8]) -> String {
+    let end = bytes.iter().position(|b| *b == 0).unwrap_or(bytes.len());
+    String::from_utf8_lossy(&bytes[..end]).into_owned()
+}
+
+/// A fixed size structure that can be read from a packet.
+pub(crate) trait Decode: Sized {
+
+    /// Size of the packed structure in bytes.
+    const SIZE: usize;
+
+    fn decode(r: &mut Reader<'_>) -> Result<Self, DecodeError>;
+}
+
+impl Decode for Version {
+    const SIZE: usize = 16;
+
+    fn decode(r: &mut Reader<'_>) -> Result<Self, DecodeError> {
+        Ok(Self { major: r.u32()?, minor: r.u32()?, build_major: r.u32()?, build_minor: r.u32()? })
+    }
+}
+
+impl Decode for Event {
+    const SIZE: usize = 12;
+
+    fn decode(r: &mut Reader<'_>) -> Result<Self, DecodeError> {
+        Ok(Self { group_id: group_id(r.u32()?), event_id: ClientEventId::new(r.u32()?), data: r.u32()? })
+    }
+}
+
+impl Decode for SimObjectData {
+    const SIZE: usize = 32;
+
+    fn decode(r: &mut Reader<'_>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            request_id: r.request_id()?,
+            object_id: r.u32()?,
+            define_id: DefineId::new(r.u32()?),
+            flags: DataRequestFlags::from_bits_retain(r.u32()?),
+            entry_number: r.u32()?,
+            out_of: r.u32()?,
+            define_count: r.u32()?,
+            data: r.rest().to_vec(),
+        })
+    }
+}
+
+impl Decode for RaceResult {
+    const SIZE: usize = 4 + 16 + 4 * 260 + 8 + 8 + 4;
+
+    fn decode(r: &mut Reader<'_>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            number_of_racers: r.u32()?,
+            mission_guid: r.decode()?,
+            player_name: r.string(MAX_PATH as usize)?,
+            session_type: r.string(MAX_PATH as usize)?,
+            aircraft: r.string(MAX_PATH as usize)?,
+            player_role: r.string(MAX_PATH as usize)?,
+            total_time: r.f64()?,
+            penalty_time: r.f64()?,
+            is_disqualified: r.u32()? != 0,
+        })
+    }
+}
+
+impl Decode for Guid {
+    const SIZE: usize = 16;
+
+    fn decode(r: &mut Reader<'_>) -> Result<Self, DecodeError> {
+        r.array().map(Guid)
+    }
+}
+
+impl Decode for LatLonAlt {
+    const SIZE: usize = 24;
+
+    fn decode(r: &mut Reader<'_>) -> Result<Self, DecodeError> {
+        Ok(Self { latitude: r.f64()?, longitude: r.f64()?, altitude: r.f64()? })
+    }
+}
+
+impl Decode for Xyz {
+    const SIZE: usize = 24;
+
+    fn decode(r: &mut Reader<'_>) -> Result<Self, DecodeError> {
+        Ok(Self { x: r.f64()?, y: r.f64()?, z: r.f64()? })
+    }
+}
+
+impl Decode for Pbh {
+    const SIZE: usize = 12;
+
+    fn decode(r: &mut Reader<'_>) -> Result<Self, DecodeError> {
+        Ok(Self { pitch: r.f32()?, bank: r.f32()?, heading: r.f32()? })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn packet(id: SIMCONNECT_RECV_ID, body: &[u32]) -> Vec<u8> {
+        let size = (HEADER_SIZE + body.len() * 4) as u32;
+        [size, 6, id as u32].iter()
+            .chain(body)
+            .flat_map(|v| v.to_le_bytes())
+            .collect()
+    }
+
+    #[test]
+    fn decode_event() {
+        let data = packet(SIMCONNECT_RECV_ID_EVENT, &[SIMCONNECT_RECV_EVENT_UNKNOWN_GROUP, 7, 42]);
+        assert_eq!(Recv::decode(&data), Ok(Recv::Event(Event { group_id: None, event_id: ClientEventId::new(7), data: 42 })));
+    }
+
+    #[test]
+    fn decode_sim_object_data() {
+        let data = packet(SIMCONNECT_RECV_ID_SIMOBJECT_DATA, &[1, 0, 2, 0, 1, 1, 1, 0xdeadbeef]);
+        let Ok(Recv::SimObjectData(data)) = Recv::decode(&data) else { panic!() };
+        assert_eq!(data.request_id, RequestId::new(1));
+        assert_eq!(data.define_id, DefineId::new(2));
+        assert_eq!(data.data, 0xdeadbeef_u32.to_le_bytes());
+    }
+
+    #[test]
+    fn decode_malformed() {
+        // too short for the exception fields
+        let data = packet(SIMCONNECT_RECV_ID_EXCEPTION, &[1, 2]);
+        assert_eq!(Recv::decode(&data), Err(DecodeError::TooShort { id: 1, needed: 24, len: 20 }));
+
+        // dwSize larger than the buffer
+        let mut data = packet(SIMCONNECT_RECV_ID_QUIT, &[]);
+        data[0] = 64;
+        assert!(matches!(Recv::decode(&data), Err(DecodeError::SizeMismatch { size: 64, .. })));
+
+        // an array size that can't possibly fit
+        let data = packet(SIMCONNECT_RECV_ID_AIRPORT_LIST, &[1, u32::MAX, 0, 1]);
+        assert!(matches!(Recv::decode(&data), Err(DecodeError::TooShort { .. })));
+    }
+}
@@ -0,0 +1,262 @@
+use std::ffi::CStr;
+use std::fmt;
+
+use crate::data::RequestOptions;
+use crate::flags::{DataSetFlags, EventFlags};
+use crate::ids::{ClientEventId, DefineId, GroupId, InputGroupId, RequestId};
+use crate::types::{DataType, InitPosition, Period, SimObjectType};
+
+/// A request to SimConnect, either passed to SimConnect.dll or encoded as a
+/// packet of the network protocol depending on how the connection was made.
+///
+/// Displays as the arguments of the call, for the send record.
+// variants are named after the SimConnect functions
+#[allow(clippy::enum_variant_names)]
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum Request<'a> {
+    MapClientEventToSimEvent {
+        event_id: ClientEventId,
+        name: &'a CStr,
+    },
+    TransmitClientEvent {
+        object_id: u32,
+        event_id: ClientEventId,
+        data: u32,
+
+        // a group id, or a priority with EventFlags::GROUPID_IS_PRIORITY
+        group_id: u32,
+        flags: EventFlags,
+    },
+    #[cfg(sdk_at_least_0_24_3)]
+    TransmitClientEventEx1 {
+        object_id: u32,
+        event_id: ClientEventId,
+        group_id: u32,
+        flags: EventFlags,
+        data: [u32; 5],
+    },
+    SetSystemEventState {
+        event_id: ClientEventId,
+        on: bool,
+    },
+    AddClientEventToNotificationGroup {
+        group_id: GroupId,
+        event_id: ClientEventId,
+        maskable: bool,
+    },
+    RemoveClientEvent {
+        group_id: GroupId,
+        event_id: ClientEventId,
+    },
+    SetNotificationGroupPriority {
+        group_id: GroupId,
+        priority: u32,
+    },
+    ClearNotificationGroup {
+        group_id: GroupId,
+    },
+    AddToDataDefinition {
+        define_id: DefineId,
+        name: &'a CStr,
+
+        // sent as null when empty
+        units: &'a CStr,
+        data_type: DataType,
+        epsilon: f32,
+        datum_id: u32,
+    },
+    ClearDataDefinition {
+        define_id: DefineId,
+    },
+    RequestDataOnSimObject {
+        request_id: RequestId,
+        define_id: DefineId,
+        object_id: u32,
+        period: Period,
+        options: RequestOptions,
+    },
+    RequestDataOnSimObjectType {
+        request_id: RequestId,
+        define_id: DefineId,
+        radius_meters: u32,
+        object_type: SimObjectType,
+    },
+    SetDataOnSimObject {
+        define_id: DefineId,
+        object_id: u32,
+        flags: DataSetFlags,
+        data: &'a [u8],
+    },
+    MapInputEventToClientEvent {
+        group_id: InputGroupId,
+        definition: &'a CStr,
+
+        // client event ids, or SIMCONNECT_UNUSED for none
+        down_event_id: u32,
+        down_value: u32,
+        up_event_id: u32,
+        up_value: u32,
+        maskable: bool,
+    },
+    #[cfg(sdk_at_least_0_24_3)]
+    MapInputEventToClientEventEx1 {
+        group_id: InputGroupId,
+        definition: &'a CStr,
+        down_event_id: u32,
+        down_value: u32,
+        up_event_id: u32,
+        up_value: u32,
+        maskable: bool,
+    },
+    SetInputGroupPriority {
+        group_id: InputGroupId,
+        priority: u32,
+    },
+    RemoveInputEvent {
+        group_id: InputGroupId,
+        definition: &'a CStr,
+    },
+    ClearInputGroup {
+        group_id: InputGroupId,
+    },
+    SetInputGroupState {
+        group_id: InputGroupId,
+        on: bool,
+    },
+    RequestReservedKey {
+        event_id: ClientEventId,
+
+        // the second and third choices are optional
+        choices: [Option<&'a CStr>; 3],
+    },
+    SubscribeToSystemEvent {
+        event_id: ClientEventId,
+        name: &'a CStr,
+    },
+    UnsubscribeFromSystemEvent {
+        event_id: ClientEventId,
+    },
+    RequestSystemState {
+        request_id: RequestId,
+        state: &'a CStr,
+    },
+    AiCreateNonAtcAircraft {
+        container_title: &'a CStr,
+        tail_number: &'a CStr,
+        position: &'a InitPosition,
+        request_id: RequestId,
+    },
+    AiCreateSimulatedObject {
+        container_title: &'a CStr,
+        position: &'a InitPosition,
+        request_id: RequestId,
+    },
+    AiRemoveObject {
+        object_id: u32,
+        request_id: RequestId,
+    },
+}
+
+impl Request<'_> {
+
+    /// Returns the name of the SimConnect function making the request.
+    pub(crate) fn name(&self) -> &'static str {
+        match self {
+            Request::MapClientEventToSimEvent { .. } => "SimConnect_MapClientEventToSimEvent",
+            Request::TransmitClientEvent { .. } => "SimConnect_TransmitClientEvent",
+            #[cfg(sdk_at_least_0_24_3)]
+            Request::TransmitClientEventEx1 { .. } => "SimConnect_TransmitClientEvent_EX1",
+            Request::SetSystemEventState { .. } => "SimConnect_SetSystemEventState",
+            Request::AddClientEventToNotificationGroup { .. } => "SimConnect_AddClientEventToNotificationGroup",
+            Request::RemoveClientEvent { .. } => "SimConnect_RemoveClientEvent",
+            Request::SetNotificationGroupPriority { .. } => "SimConnect_SetNotificationGroupPriority",
+            Request::ClearNotificationGroup { .. } => "SimConnect_ClearNotificationGroup",
+            Request::AddToDataDefinition { .. } => "SimConnect_AddToDataDefinition",
+            Request::ClearDataDefinition { .. } => "SimConnect_ClearDataDefinition",
+            Request::RequestDataOnSimObject { .. } => "SimConnect_RequestDataOnSimObject",
+            Request::RequestDataOnSimObjectType { .. } => "SimConnect_RequestDataOnSimObjectType",
+            Request::SetDataOnSimObject { .. } => "SimConnect_SetDataOnSimObject",
+            Request::MapInputEventToClientEvent { .. } => "SimConnect_MapInputEventToClientEvent",
+            #[cfg(sdk_at_least_0_24_3)]
+            Request::MapInputEventToClientEventEx1 { .. } => "SimConnect_MapInputEventToClientEvent_EX1",
+            Request::SetInputGroupPriority { .. } => "SimConnect_SetInputGroupPriority",
+            Request::RemoveInputEvent { .. } => "SimConnect_RemoveInputEvent",
+            Request::ClearInputGroup { .. } => "SimConnect_ClearInputGroup",
+            Request::SetInputGroupState { .. } => "SimConnect_SetInputGroupState",
+            Request::RequestReservedKey { .. } => "SimConnect_RequestReservedKey",
+            Request::SubscribeToSystemEvent { .. } => "SimConnect_SubscribeToSystemEvent",
+            Request::UnsubscribeFromSystemEvent { .. } => "SimConnect_UnsubscribeFromSystemEvent",
+            Request::RequestSystemState { .. } => "SimConnect_RequestSystemState",
+            Request::AiCreateNonAtcAircraft { .. } => "SimConnect_AICreateNonATCAircraft",
+            Request::AiCreateSimulatedObject { .. } => "SimConnect_AICreateSimulatedObject",
+            Request::AiRemoveObject { .. } => "SimConnect_AIRemoveObject",
+        }
+    }
+}
+
+impl fmt::Display for Request<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Request::MapClientEventToSimEvent { event_id, name } => write!(f, "{}, {:?}", event_id, name),
+            Request::TransmitClientEvent { object_id, event_id, data, group_id, flags } => {
+                write!(f, "{}, {}, {}, {}, {:#x}", object_id, event_id, data, group_id, flags.bits())
+            }
+            #[cfg(sdk_at_least_0_24_3)]
+            Request::TransmitClientEventEx1 { object_id, event_id, group_id, flags, data: [d0, d1, d2, d3, d4] } => {
+                write!(f, "{}, {}, {}, {:#x}, {}, {}, {}, {}, {}", object_id, event_id, group_id, flags.bits(), d0, d1, d2, d3, d4)
+            }
+            Request::SetSystemEventState { event_id, on } => write!(f, "{}, {}", event_id, on),
+            Request::AddClientEventToNotificationGroup { group_id, event_id, maskable } => {
+                write!(f, "{}, {}, {}", group_id, event_id, maskable)
+            }
+            Request::RemoveClientEvent { group_id, event_id } => write!(f, "{}, {}", group_id, event_id),
+            Request::SetNotificationGroupPriority { group_id, priority } => write!(f, "{}, {}", group_id, priority),
+            Request::ClearNotificationGroup { group_id } => write!(f, "{}", group_id),
+            Request::AddToDataDefinition { define_id, name, units, data_type, epsilon, datum_id } => {
+                write!(f, "{}, {:?}, {:?}, {:?}, {}, {}", define_id, name, units, data_type, epsilon, datum_id)
+            }
+            Request::ClearDataDefinition { define_id } => write!(f, "{}", define_id),
+            Request::RequestDataOnSimObject { request_id, define_id, object_id, period, options } => {
+                let RequestOptions { flags, origin, interval, limit } = options;
+                write!(f, "{}, {}, {}, {:?}, {:#x}, {}, {}, {}",
+                    request_id, define_id, object_id, period, flags.bits(), origin, interval, limit)
+            }
+            Request::RequestDataOnSimObjectType { request_id, define_id, radius_meters, object_type } => {
+                write!(f, "{}, {}, {}, {:?}", request_id, define_id, radius_meters, object_type)
+            }
+            Request::SetDataOnSimObject { define_id, object_id, flags, data } => {
+                write!(f, "{}, {}, {:#x}, <{} bytes>", define_id, object_id, flags.bits(), data.len())
+            }
+            Request::MapInputEventToClientEvent {
+                group_id, definition, down_event_id, down_value, up_event_id, up_value, maskable,
+            } => {
+                write!(f, "{}, {:?}, {}, {}, {}, {}, {}", group_id, definition, down_event_id, down_value, up_event_id,
+                    up_value, maskable)
+            }
+            #[cfg(sdk_at_least_0_24_3)]
+            Request::MapInputEventToClientEventEx1 {
+                group_id, definition, down_event_id, down_value, up_event_id, up_value, maskable,
+            } => {
+                write!(f, "{}, {:?}, {}, {}, {}, {}, {}", group_id, definition, down_event_id, down_value, up_event_id,
+                    up_value, maskable)
+            }
+            Request::SetInputGroupPriority { group_id, priority } => write!(f, "{}, {}", group_id, priority),
+            Request::RemoveInputEvent { group_id, definition } => write!(f, "{}, {:?}", group_id, definition),
+            Request::ClearInputGroup { group_id } => write!(f, "{}", group_id),
+            Request::SetInputGroupState { group_id, on } => write!(f, "{}, {}", group_id, on),
+            Request::RequestReservedKey { event_id, choices: [choice1, choice2, choice3] } => {
+                write!(f, "{}, {:?}, {:?}, {:?}", event_id, choice1, choice2, choice3)
+            }
+            Request::SubscribeToSystemEvent { event_id, name } => write!(f, "{}, {:?}", event_id, name),
+            Request::UnsubscribeFromSystemEvent { event_id } => write!(f, "{}", event_id),
+            Request::RequestSystemState { request_id, state } => write!(f, "{}, {:?}", request_id, state),
+            Request::AiCreateNonAtcAircraft { container_title, tail_number, position, request_id } => {
+                write!(f, "{:?}, {:?}, {:?}, {}", container_title, tail_number, position, request_id)
+            }
+            Request::AiCreateSimulatedObject { container_title, position, request_id } => {
+                write!(f, "{:?}, {:?}, {}", container_title, position, request_id)
+            }
+            Request::AiRemoveObject { object_id, request_id } => write!(f, "{}, {}", object_id, request_id),
+        }
+    }
+}
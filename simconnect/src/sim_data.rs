@@ -0,0 +1,447 @@
+use std::collections::BTreeMap;
+
+use simconnect_sys::*;
+
+use crate::connection::SimConnect;
+use crate::error::Result;
+use crate::flags::{DataRequestFlags, DataSetFlags};
+use crate::ids::DefineId;
+use crate::recv::{DecodeError, Reader, SimObjectData};
+use crate::types::{DataType, InitPosition, LatLonAlt, Xyz};
+
+/// One entry of a data definition, as passed to `SimConnect_AddToDataDefinition`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DataField {
+    pub name: &'static str,
+    pub units: &'static str,
+    pub data_type: DataType,
+    pub epsilon: f32,
+}
+
+/// A struct that can be registered as a data definition, usually implemented
+/// with `#[derive(SimData)]`.
+///
+/// Fields are laid out one after the other with no padding, the same layout
+/// SimConnect uses and a `#[repr(C, packed)]` struct would have. Each field
+/// is added with its index as the datum id. `#[derive(SimData)]` generates
+/// that packed layout and fails to compile if its size doesn't match
+/// [`SimData::SIZE`].
+///
+#[cfg_attr(feature = "derive", doc = "```no_run")]
+#[cfg_attr(not(feature = "derive"), doc = "```ignore")]
+/// use simconnect::{Period, SimConnect, SimData, OBJECT_ID_USER};
+///
+/// #[derive(Debug, SimData)]
+/// struct Position {
+///     #[simvar(name = "PLANE LATITUDE", units = "degrees")]
+///     latitude: f64,
+///     #[simvar(name = "PLANE LONGITUDE", units = "degrees")]
+///     longitude: f64,
+///     #[simvar(name = "PLANE ALTITUDE", units = "feet", epsilon = 1.0)]
+///     altitude: f64,
+/// }
+///
+/// let sc = SimConnect::open("Example")?;
+/// let define_id = sc.define::<Position>()?;
+/// let request_id = sc.new_request_id();
+/// sc.request_data_on_sim_object(request_id, define_id, OBJECT_ID_USER, Period::Second)?;
+/// for recv in sc.iter() {
+///     if let simconnect::Recv::SimObjectData(data) = recv? {
+///         println!("{:?}", Position::from_recv(&data)?);
+///     }
+/// }
+/// # Ok::<(), simconnect::Error>(())
+/// ```
+pub trait SimData: Sized {
+
+    /// The entries of the data definition, in order.
+    const FIELDS: &'static [DataField];
+
+    /// The size of the untagged data in bytes, `None` if a field is a
+    /// variable length string.
+    const SIZE: Option<usize> = data_size(Self::FIELDS);
+
+    /// Reads the struct from untagged data.
+    fn read(r: &mut Reader<'_>) -> Result<Self, DecodeError>;
+
+    /// Writes the struct as untagged data.
+    fn write(&self, out: &mut Vec<u8>);
+
+    /// Adds every field to the data definition `define_id`.
+    fn register(sc: &SimConnect, define_id: DefineId) -> Result<()> {
+        for (datum_id, field) in Self::FIELDS.iter().enumerate() {
+            sc.add_to_data_definition(define_id, field.name, field.units, field.data_type,
+                field.epsilon, datum_id as u32)?;
+        }
+        Ok(())
+    }
+
+    /// Decodes the struct from the raw data of a data definition.
+    fn from_bytes(data: &[u8]) -> Result<Self, DecodeError> {
+        Self::read(&mut Reader::new(SIMCONNECT_RECV_ID_SIMOBJECT_DATA as u32, data))
+    }
+
+    /// Decodes the struct from a received `SIMCONNECT_RECV_SIMOBJECT_DATA`.
+    fn from_recv(data: &SimObjectData) -> Result<Self, DecodeError> {
+        let reader = Reader::new(SIMCONNECT_RECV_ID_SIMOBJECT_DATA as u32, &data.data);
+        if data.flags.contains(DataRequestFlags::TAGGED) {
+            return Err(reader.invalid("dwFlags", data.flags.bits()));
+        }
+        if data.define_count as usize != Self::FIELDS.len() {
+            return Err(reader.invalid("dwDefineCount", data.define_count));
+        }
+        Self::from_bytes(&data.data)
+    }
+
+    /// Encodes the struct for `SimConnect_SetDataOnSimObject`.
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut out = Vec::new();
+        self.write(&mut out);
+        out
+    }
+
+    /// Reads the value of the field registered with `datum_id` into `self`.
+    ///
+    /// Generated by `#[derive(SimData)]`, manual implementations that don't
+    /// override it can't be patched.
+    fn read_field(&mut self, datum_id: u32, r: &mut Reader<'_>) -> Result<(), DecodeError> {
+        let _ = self;
+        Err(r.invalid("datum id", datum_id))
+    }
+
+    /// Updates `self` from a received `SIMCONNECT_RECV_SIMOBJECT_DATA`.
+    ///
+    /// Tagged data, requested with `SIMCONNECT_DATA_REQUEST_FLAG_TAGGED`,
+    /// only holds the fields that changed and only those are updated,
+    /// untagged data updates every field of the definition. Fields that
+    /// aren't part of the definition keep their value either way.
+    fn patch(&mut self, data: &SimObjectData) -> Result<(), DecodeError> {
+        let tagged = data.flags.contains(DataRequestFlags::TAGGED);
+        let mut r = Reader::new(SIMCONNECT_RECV_ID_SIMOBJECT_DATA as u32, &data.data);
+        if !tagged && data.define_count as usize != Self::FIELDS.len() {
+            return Err(r.invalid("dwDefineCount", data.define_count));
+        }
+
+        // check the whole packet first, so a malformed one leaves `self` as it
+        // was. untagged data holds every field in order of their datum ids
+        let mut fields = Vec::with_capacity(Self::FIELDS.len());
+        for i in 0..data.define_count {
+            let datum_id = if tagged { r.u32()? } else { i };
+            let start = r.position();
+            let field = Self::FIELDS.get(datum_id as usize)
+                .ok_or_else(|| r.invalid("datum id", datum_id))?;
+            r.datum(field.data_type)?;
+            fields.push((datum_id, start));
+        }
+        for (datum_id, start) in fields {
+            let mut r = Reader::new(SIMCONNECT_RECV_ID_SIMOBJECT_DATA as u32, &data.data[start..]);
+            self.read_field(datum_id, &mut r)?;
+        }
+        Ok(())
+    }
+}
+
+// sums the sizes of the fields of a definition, usable in constants
+const fn data_size(fields: &[DataField]) -> Option<usize> {
+    let mut size = 0;
+    let mut i = 0;
+    while i < fields.len() {
+        match fields[i].data_type.size() {
+            Some(field_size) => size += field_size,
+            None => return None,
+        }
+        i += 1;
+    }
+    Some(size)
+}
+
+/// A single value of any [`DataType`], as found in tagged data.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Datum {
+    Int32(i32),
+    Int64(i64),
+    Float32(f32),
+    Float64(f64),
+    String(String),
+    LatLonAlt(LatLonAlt),
+    Xyz(Xyz),
+    InitPosition(InitPosition),
+
+    /// Structure types without a Rust equivalent, as their raw bytes.
+    Bytes(Vec<u8>),
+}
+
+/// Decodes tagged or untagged data into a map of datum id to value.
+///
+/// `fields` are the entries of the data definition the data was requested
+/// for, registered with their index as the datum id as [`SimData::register`]
+/// does. Tagged data only holds the fields that changed, so the map is sparse.
+pub fn decode_tagged(data: &SimObjectData, fields: &[DataField]) -> Result<BTreeMap<u32, Datum>, DecodeError> {
+    let mut r = Reader::new(SIMCONNECT_RECV_ID_SIMOBJECT_DATA as u32, &data.data);
+    let tagged = data.flags.contains(DataRequestFlags::TAGGED);
+    let mut values = BTreeMap::new();
+    for index in 0..data.define_count {
+        let datum_id = if tagged { r.u32()? } else { index };
+        let field = fields.get(datum_id as usize)
+            .ok_or_else(|| r.invalid("datum id", datum_id))?;
+        values.insert(datum_id, r.datum(field.data_type)?);
+    }
+    Ok(values)
+}
+
+/// A value that can be used as a field of a [`SimData`] struct.
+pub trait DataValue: Sized {
+
+    /// The SimConnect type the value is registered as.
+    const DATA_TYPE: DataType;
+
+    fn read(r: &mut Reader<'_>) -> Result<Self, DecodeError>;
+
+    fn write(&self, out: &mut Vec<u8>);
+}
+
+macro_rules! data_values {
+    ($($ty:ty => $data_type:ident, $read:ident;)*) => {
+        $(
+            impl DataValue for $ty {
+                const DATA_TYPE: DataType = DataType::$data_type;
+
+                fn read(r: &mut Reader<'_>) -> Result<Self, DecodeError> {
+                    r.$read()
+                }
+
+                fn write(&self, out: &mut Vec<u8>) {
+                    out.extend_from_slice(&self.to_le_bytes());
+                }
+            }
+        )*
+    };
+}
+
+data_values! {
+    i32 => Int32, i32;
+    u32 => Int32, u32;
+    i64 => Int64, i64;
+    u64 => Int64, u64;
+    f32 => Float32, f32;
+    f64 => Float64, f64;
+}
+
+impl DataValue for bool {
+    const DATA_TYPE: DataType = DataType::Int32;
+
+    fn read(r: &mut Reader<'_>) -> Result<Self, DecodeError> {
+        r.u32().map(|v| v != 0)
+    }
+
+    fn write(&self, out: &mut Vec<u8>) {
+        (*self as u32).write(out)
+    }
+}
+
+impl DataValue for LatLonAlt {
+    const DATA_TYPE: DataType = DataType::LatLonAlt;
+
+    fn read(r: &mut Reader<'_>) -> Result<Self, DecodeError> {
+        r.decode()
+    }
+
+    fn write(&self, out: &mut Vec<u8>) {
+        for v in [self.latitude, self.longitude, self.altitude] {
+            v.write(out);
+        }
+    }
+}
+
+impl DataValue for Xyz {
+    const DATA_TYPE: DataType = DataType::Xyz;
+
+    fn read(r: &mut Reader<'_>) -> Result<Self, DecodeError> {
+        r.decode()
+    }
+
+    fn write(&self, out: &mut Vec<u8>) {
+        for v in [self.x, self.y, self.z] {
+            v.write(out);
+        }
+    }
+}
+
+impl DataValue for InitPosition {
+    const DATA_TYPE: DataType = DataType::InitPosition;
+
+    fn read(r: &mut Reader<'_>) -> Result<Self, DecodeError> {
+        Ok(InitPosition {
+            latitude: r.f64()?,
+            longitude: r.f64()?,
+            altitude: r.f64()?,
+            pitch: r.f64()?,
+            bank: r.f64()?,
+            heading: r.f64()?,
+            on_ground: r.u32()? != 0,
+            airspeed: r.u32()?,
+        })
+    }
+
+    fn write(&self, out: &mut Vec<u8>) {
+        for v in [self.latitude, self.longitude, self.altitude, self.pitch, self.bank, self.heading] {
+            v.write(out);
+        }
+        self.on_ground.write(out);
+        self.airspeed.write(out);
+    }
+}
+
+impl Reader<'_> {
+
+    /// Reads a single value of `data_type`.
+    pub fn datum(&mut self, data_type: DataType) -> Result<Datum, DecodeError> {
+        Ok(match data_type {
+            DataType::Int32 => Datum::Int32(self.i32()?),
+            DataType::Int64 => Datum::Int64(self.i64()?),
+            DataType::Float32 => Datum::Float32(self.f32()?),
+            DataType::Float64 => Datum::Float64(self.f64()?),
+            DataType::String8 | DataType::String32 | DataType::String64
+            | DataType::String128 | DataType::String256 | DataType::String260 => {
+                // size() is only None for StringV
+                Datum::String(self.string_utf8(data_type.size().unwrap())?)
+            }
+            DataType::StringV => Datum::String(self.string_nul()?),
+            DataType::LatLonAlt => Datum::LatLonAlt(self.decode()?),
+            DataType::Xyz => Datum::Xyz(self.decode()?),
+            DataType::InitPosition => Datum::InitPosition(InitPosition::read(self)?),
+            DataType::MarkerState | DataType::Waypoint => {
+                Datum::Bytes(self.take(data_type.size().unwrap())?.to_vec())
+            }
+        })
+    }
+}
+
+impl SimConnect {
+
+    /// Allocates a [`DefineId`] and registers `T` as its data definition.
+    pub fn define<T: SimData>(&self) -> Result<DefineId> {
+        let define_id = self.new_define_id();
+        if let Err(e) = T::register(self, define_id) {
+            self.release_define_id(define_id);
+            return Err(e);
+        }
+        Ok(define_id)
+    }
+
+    /// Sets the data definition `define_id`, registered from `T`, on `object_id`.
+    pub fn set_data<T: SimData>(&self, define_id: DefineId, object_id: u32, value: &T) -> Result<()> {
+        self.set_data_on_sim_object(define_id, object_id, DataSetFlags::empty(), &value.to_bytes())
+    }
+}
+
+#[cfg(all(test, feature = "derive"))]
+mod tests {
+    use super::*;
+    use crate::{RequestId, SimData};
+
+    #[derive(Debug, PartialEq, SimData)]
+    struct Aircraft {
+        #[simvar(name = "PLANE ALTITUDE", units = "feet", epsilon = 0.5)]
+        altitude: f64,
+        #[simvar(name = "SIM ON GROUND", units = "bool")]
+        on_ground: bool,
+        #[simvar(name = "FUEL TOTAL QUANTITY", units = "gallons")]
+        fuel: f32,
+    }
+
+    #[test]
+    fn derive_fields() {
+        assert_eq!(Aircraft::FIELDS, &[
+            DataField { name: "PLANE ALTITUDE", units: "feet", data_type: DataType::Float64, epsilon: 0.5 },
+            DataField { name: "SIM ON GROUND", units: "bool", data_type: DataType::Int32, epsilon: 0.0 },
+            DataField { name: "FUEL TOTAL QUANTITY", units: "gallons", data_type: DataType::Float32, epsilon: 0.0 },
+        ]);
+    }
+
+    #[test]
+    fn derive_round_trip() {
+        let aircraft = Aircraft { altitude: 1500.0, on_ground: true, fuel: 42.5 };
+        let bytes = aircraft.to_bytes();
+        assert_eq!(bytes.len(), 8 + 4 + 4);
+        assert_eq!(Aircraft::SIZE, Some(bytes.len()));
+
+        let data = SimObjectData {
+            request_id: RequestId::new(1),
+            object_id: 0,
+            define_id: DefineId::new(1),
+            flags: DataRequestFlags::empty(),
+            entry_number: 1,
+            out_of: 1,
+            define_count: 3,
+            data: bytes,
+        };
+        assert_eq!(Aircraft::from_recv(&data), Ok(aircraft));
+        assert!(Aircraft::from_bytes(&data.data[..8]).is_err());
+    }
+
+    #[test]
+    fn patch_tagged() {
+        let mut aircraft = Aircraft { altitude: 1500.0, on_ground: true, fuel: 42.5 };
+
+        // only the fuel (datum 2) and altitude (datum 0) changed
+        let mut bytes = Vec::new();
+        2u32.write(&mut bytes);
+        40.0f32.write(&mut bytes);
+        0u32.write(&mut bytes);
+        1600.0f64.write(&mut bytes);
+        let mut data = SimObjectData {
+            request_id: RequestId::new(1),
+            object_id: 0,
+            define_id: DefineId::new(1),
+            flags: DataRequestFlags::CHANGED | DataRequestFlags::TAGGED,
+            entry_number: 1,
+            out_of: 1,
+            define_count: 2,
+            data: bytes,
+        };
+
+        let values = decode_tagged(&data, Aircraft::FIELDS).unwrap();
+        assert_eq!(values.into_iter().collect::<Vec<_>>(),
+            [(0, Datum::Float64(1600.0)), (2, Datum::Float32(40.0))]);
+
+        aircraft.patch(&data).unwrap();
+        assert_eq!(aircraft, Aircraft { altitude: 1600.0, on_ground: true, fuel: 40.0 });
+
+        // an unknown datum id fails without touching the struct
+        data.data[0] = 7;
+        assert!(aircraft.patch(&data).is_err());
+        assert_eq!(aircraft.fuel, 40.0);
+    }
+
+    #[test]
+    fn patch_untagged() {
+        #[derive(Debug, PartialEq, SimData)]
+        struct Tracked {
+            #[simvar(name = "PLANE ALTITUDE", units = "feet")]
+            altitude: f64,
+            #[simvar(skip)]
+            updates: u32,
+        }
+
+        let mut tracked = Tracked { altitude: 1500.0, updates: 3 };
+        let mut data = SimObjectData {
+            request_id: RequestId::new(1),
+            object_id: 0,
+            define_id: DefineId::new(1),
+            flags: DataRequestFlags::empty(),
+            entry_number: 1,
+            out_of: 1,
+            define_count: 1,
+            data: Tracked { altitude: 1600.0, updates: 0 }.to_bytes(),
+        };
+
+        // skipped fields keep their value
+        tracked.patch(&data).unwrap();
+        assert_eq!(tracked, Tracked { altitude: 1600.0, updates: 3 });
+
+        data.data.truncate(4);
+        assert!(tracked.patch(&data).is_err());
+        assert_eq!(tracked.altitude, 1600.0);
+    }
+}
@@ -0,0 +1,182 @@
+use std::marker::PhantomData;
+
+use simconnect_sys::SIMCONNECT_GROUP_PRIORITY_HIGHEST;
+
+use crate::connection::SimConnect;
+use crate::error::Result;
+use crate::flags::EventFlags;
+use crate::ids::ClientEventId;
+
+/// A set of simulation events that can be mapped to client events in one go,
+/// usually implemented with `#[derive(SimEvents)]`.
+///
+/// Each variant of a fieldless enum maps to the key event named after it in
+/// SCREAMING_SNAKE_CASE, or the name given with `#[event(name = "...")]`.
+/// Names are checked against the [`key_events`](crate::key_events) catalog at
+/// compile time, an event missing from it is a warning that
+/// `#[event(unchecked)]` silences.
+///
+#[cfg_attr(feature = "derive", doc = "```no_run")]
+#[cfg_attr(not(feature = "derive"), doc = "```ignore")]
+/// use simconnect::{SimConnect, SimEvents, OBJECT_ID_USER};
+///
+/// #[derive(Debug, Clone, Copy, PartialEq, SimEvents)]
+/// enum Controls {
+///     GearToggle,
+///     ThrottleSet,
+///     #[event(name = "AP_MASTER")]
+///     Autopilot,
+///     #[event(name = "MOBIFLIGHT.CUSTOM_EVENT", unchecked)]
+///     Custom,
+/// }
+///
+/// let sc = SimConnect::open("Example")?;
+/// let events = sc.map_events::<Controls>()?;
+/// sc.transmit_event(&events, Controls::ThrottleSet, OBJECT_ID_USER, 8192)?;
+/// # Ok::<(), simconnect::Error>(())
+/// ```
+pub trait SimEvents: Copy + 'static {
+
+    /// Every event, in order.
+    const EVENTS: &'static [Self];
+
+    /// The name of the simulation event this maps to.
+    fn name(self) -> &'static str;
+
+    /// The position of this event in [`SimEvents::EVENTS`].
+    fn index(self) -> usize;
+}
+
+/// The client event ids a set of [`SimEvents`] was mapped to, see
+/// [`SimConnect::map_events`].
+#[derive(Debug, Clone)]
+pub struct EventMap<T> {
+    event_ids: Vec<ClientEventId>,
+    events: PhantomData<T>,
+}
+
+impl<T: SimEvents> EventMap<T> {
+
+    /// The client event id `event` was mapped to.
+    pub fn id(&self, event: T) -> ClientEventId {
+        self.event_ids[event.index()]
+    }
+
+    /// The event mapped to the client event `event_id`, to decode received events.
+    pub fn event(&self, event_id: ClientEventId) -> Option<T> {
+        self.event_ids.iter().position(|id| *id == event_id).map(|i| T::EVENTS[i])
+    }
+
+    /// Every event with its client event id.
+    pub fn iter(&self) -> impl Iterator<Item = (T, ClientEventId)> + '_ {
+        T::EVENTS.iter().copied().zip(self.event_ids.iter().copied())
+    }
+}
+
+impl SimConnect {
+
+    /// Maps every event of `T` to a newly allocated client event id.
+    pub fn map_events<T: SimEvents>(&self) -> Result<EventMap<T>> {
+        let event_ids: Vec<_> = T::EVENTS.iter().map(|_| self.new_client_event_id()).collect();
+        for (event, event_id) in T::EVENTS.iter().zip(&event_ids) {
+            if let Err(e) = self.map_client_event_to_sim_event(*event_id, event.name()) {
+                for event_id in event_ids {
+                    self.release_client_event_id(event_id);
+                }
+                return Err(e);
+            }
+        }
+        Ok(EventMap { event_ids, events: PhantomData })
+    }
+
+    /// Transmits `event` with `value` to `object_id` at the highest priority.
+    ///
+    /// Negative values, e.g. of the `AXIS_*` events, are sent as their two's
+    /// complement as SimConnect expects.
+    pub fn transmit_event<T: SimEvents>(&self, events: &EventMap<T>, event: T, object_id: u32, value: i32) -> Result<()> {
+        self.transmit_client_event_with_priority(object_id, events.id(event), value as u32,
+            SIMCONNECT_GROUP_PRIORITY_HIGHEST, EventFlags::empty())
+    }
+
+    /// Transmits `event` with up to five parameters to `object_id` at the
+    /// highest priority, with `SimConnect_TransmitClientEvent_EX1`.
+    #[cfg(sdk_at_least_0_24_3)]
+    pub fn transmit_event_ex1<T: SimEvents>(
+        &self,
+        events: &EventMap<T>,
+        event: T,
+        object_id: u32,
+        values: [u32; 5],
+    ) -> Result<()> {
+        self.transmit_client_event_ex1_with_priority(object_id, events.id(event),
+            SIMCONNECT_GROUP_PRIORITY_HIGHEST, EventFlags::empty(), values)
+    }
+}
+
+#[cfg(all(test, feature = "derive"))]
+mod tests {
+    use super::*;
+    use crate::mock::{MockServer, Request, TIMEOUT};
+    use crate::{SimEvents, OBJECT_ID_USER};
+
+    #[derive(Debug, Clone, Copy, PartialEq, SimEvents)]
+    enum Controls {
+        GearToggle,
+        AxisElevatorSet,
+        Throttle1Set,
+        #[event(name = "AP_MASTER")]
+        Autopilot,
+        #[event(name = "Custom.Event", unchecked)]
+        Custom,
+    }
+
+    #[test]
+    fn derive() {
+        let names: Vec<_> = Controls::EVENTS.iter().map(|e| e.name()).collect();
+        assert_eq!(names, ["GEAR_TOGGLE", "AXIS_ELEVATOR_SET", "THROTTLE1_SET", "AP_MASTER", "Custom.Event"]);
+        assert_eq!(Controls::Custom.index(), 4);
+    }
+
+    #[test]
+    fn map_and_transmit() {
+        let server = MockServer::start().unwrap();
+        let sc = server.connect("Test").unwrap();
+
+        let events = sc.map_events::<Controls>().unwrap();
+        let event_id = events.id(Controls::AxisElevatorSet);
+        assert_eq!(events.event(event_id), Some(Controls::AxisElevatorSet));
+        assert_eq!(events.iter().count(), 5);
+
+        sc.transmit_event(&events, Controls::AxisElevatorSet, OBJECT_ID_USER, -100).unwrap();
+        let received = server.wait_for(TIMEOUT, |r| matches!(r.request, Request::TransmitClientEvent { .. })).unwrap();
+        assert_eq!(received.request, Request::TransmitClientEvent {
+            object_id: OBJECT_ID_USER,
+            event_id,
+            data: -100i32 as u32,
+            group_id: SIMCONNECT_GROUP_PRIORITY_HIGHEST,
+            flags: EventFlags::GROUPID_IS_PRIORITY,
+        });
+
+        // the five parameters come back through a notification group
+        #[cfg(sdk_at_least_0_24_3)]
+        {
+            use crate::mock::next_matching;
+            use crate::Recv;
+
+            let group_id = sc.new_group_id();
+            sc.add_client_event_to_notification_group(group_id, events.id(Controls::Custom), false).unwrap();
+            sc.set_notification_group_priority(group_id, simconnect_sys::SIMCONNECT_GROUP_PRIORITY_STANDARD).unwrap();
+            sc.transmit_event_ex1(&events, Controls::Custom, OBJECT_ID_USER, [1, 2, 3, 4, 5]).unwrap();
+            let received = server.wait_for(TIMEOUT, |r| matches!(r.request, Request::TransmitClientEventEx1 { .. }));
+            assert_eq!(received.unwrap().request, Request::TransmitClientEventEx1 {
+                object_id: OBJECT_ID_USER,
+                event_id: events.id(Controls::Custom),
+                group_id: SIMCONNECT_GROUP_PRIORITY_HIGHEST,
+                flags: EventFlags::GROUPID_IS_PRIORITY,
+                data: [1, 2, 3, 4, 5],
+            });
+            let recv = next_matching(&sc, |r| matches!(r, Recv::EventEx1 { .. }));
+            assert!(matches!(recv, Recv::EventEx1 { data: [1, 2, 3, 4, 5], .. }));
+        }
+    }
+}
@@ -0,0 +1,692 @@
+use std::collections::{HashMap, VecDeque};
+use std::future::Future;
+use std::marker::PhantomData;
+use std::pin::Pin;
+use std::net::ToSocketAddrs;
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+use std::task::{Context, Poll, Waker};
+
+use futures_core::Stream;
+use simconnect_sys::SIMCONNECT_GROUP_PRIORITY_STANDARD;
+
+use crate::connection::{Notifier, SimConnect};
+use crate::error::{Error, Result};
+use crate::ids::{ClientEventId, DefineId, InputGroupId, RequestId};
+use crate::input::InputEvents;
+use crate::recv::{Recv, SystemState};
+use crate::sim_data::SimData;
+use crate::types::{InitPosition, Period};
+
+/// A [`SimConnect`] connection for async code, usable with any runtime.
+///
+/// Messages are read as a [`Stream`] from [`AsyncSimConnect::messages`], and
+/// requests answered by a single message return a [`Response`] future that
+/// resolves when it arrives. Responses are taken out of the message stream,
+/// everything else is passed on to it.
+///
+/// Tasks are woken by a background thread as messages arrive, so nothing is
+/// polled on a timer. Connections opened through SimConnect.dll with a window
+/// or event handle of their own have nothing to wait on and fall back to
+/// checking every few milliseconds.
+///
+/// ```no_run
+/// use simconnect::{AsyncSimConnect, SimData, OBJECT_ID_USER};
+///
+/// #[derive(Debug, SimData)]
+/// struct Position {
+///     #[simvar(name = "PLANE ALTITUDE", units = "feet")]
+///     altitude: f64,
+/// }
+///
+/// # async fn example() -> simconnect::Result<()> {
+/// let sc = AsyncSimConnect::open("Example")?;
+/// let define_id = sc.define::<Position>()?;
+/// let position: Position = sc.request_data_once(define_id, OBJECT_ID_USER).await?;
+/// let sim = sc.request_system_state("Sim").await?;
+/// println!("{:?}, sim running: {}", position, sim.integer != 0);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct AsyncSimConnect {
+
+    // declared first so the callback is gone before the connection closes
+    _notifier: Notifier,
+    shared: Arc<Shared>,
+}
+
+#[derive(Debug)]
+struct Shared {
+    inner: Mutex<Inner>,
+}
+
+#[derive(Debug)]
+struct Inner {
+    sc: SimConnect,
+    router: Router,
+}
+
+impl AsyncSimConnect {
+
+    /// Opens a connection to SimConnect using the default options.
+    pub fn open(name: &str) -> Result<Self> {
+        Self::new(SimConnect::open(name)?)
+    }
+
+    /// Connects to a SimConnect server at `addr` over TCP, see
+    /// [`SimConnect::connect`].
+    pub fn connect(addr: impl ToSocketAddrs, name: &str) -> Result<Self> {
+        Self::new(SimConnect::connect(addr, name)?)
+    }
+
+    /// Wraps an open connection.
+    ///
+    /// Messages already queued on `sc` are delivered through the new
+    /// connection.
+    pub fn new(sc: SimConnect) -> Result<Self> {
+        let shared = Arc::new(Shared {
+            inner: Mutex::new(Inner { sc, router: Router::default() }),
+        });
+        // the callback is owned by the connection, a strong reference would
+        // keep it alive forever
+        let weak = Arc::downgrade(&shared);
+        let wake = Arc::new(move || {
+            let Some(shared) = weak.upgrade() else { return };
+            // wake outside the lock to keep holding it short
+            let wakers = shared.lock().router.take_wakers();
+            for waker in wakers {
+                waker.wake();
+            }
+        });
+        let notifier = shared.lock().sc.notify(wake)?;
+        Ok(Self { _notifier: notifier, shared })
+    }
+
+    /// Runs `f` with the underlying connection, for calls without an async
+    /// equivalent.
+    ///
+    /// Messages must not be received through it, they would be missed by the
+    /// stream and responses.
+    pub fn with_connection<R>(&self, f: impl FnOnce(&SimConnect) -> R) -> R {
+        f(&self.shared.lock().sc)
+    }
+
+    /// Registers `T` as a new data definition, see [`SimConnect::define`].
+    pub fn define<T: SimData>(&self) -> Result<DefineId> {
+        self.with_connection(|sc| sc.define::<T>())
+    }
+
+    /// Returns a stream of every message that isn't a response to a pending
+    /// [`Response`].
+    ///
+    /// Like [`SimConnect::iter`], the stream ends after `Recv::Quit` or a
+    /// failing `SimConnect_GetNextDispatch`. Messages are only kept while a
+    /// stream exists, if several exist each message goes to one of them.
+    pub fn messages(&self) -> Messages<'_> {
+        self.shared.lock().router.streams += 1;
+        Messages { sc: self, done: false }
+    }
+
+    /// Requests the data definition `define_id` for `object_id` once,
+    /// resolving to the decoded data.
+    pub fn request_data_once<T: SimData>(&self, define_id: DefineId, object_id: u32) -> Response<'_, T> {
+        self.request(ResponseKind::Data, decode_data::<T>, |sc, request_id| {
+            sc.request_data_on_sim_object(request_id, define_id, object_id, Period::Once)
+        })
+    }
+
+    /// Requests the system state `state`.
+    pub fn request_system_state(&self, state: &str) -> Response<'_, SystemState> {
+        self.request(ResponseKind::SystemState, decode_system_state, |sc, request_id| {
+            sc.request_system_state(request_id, state)
+        })
+    }
+
+    /// Creates an aircraft that isn't controlled by ATC, resolving to the
+    /// object id it was assigned.
+    pub fn ai_create_non_atc_aircraft(
+        &self,
+        container_title: &str,
+        tail_number: &str,
+        position: &InitPosition,
+    ) -> Response<'_, u32> {
+        self.request(ResponseKind::ObjectId, decode_object_id, |sc, request_id| {
+            sc.ai_create_non_atc_aircraft(container_title, tail_number, position, request_id)
+        })
+    }
+
+    /// Creates a simulation object other than an aircraft, resolving to the
+    /// object id it was assigned.
+    pub fn ai_create_simulated_object(&self, container_title: &str, position: &InitPosition) -> Response<'_, u32> {
+        self.request(ResponseKind::ObjectId, decode_object_id, |sc, request_id| {
+            sc.ai_create_simulated_object(container_title, position, request_id)
+        })
+    }
+
+    /// Reserves the first free key combination of up to three `choices`,
+    /// resolving once the key the simulator reserved is mapped to `event_id`
+    /// in an input group of its own. The key is unmapped when the
+    /// [`ReservedKey`] is dropped.
+    ///
+    /// ```no_run
+    /// # async fn example(sc: &simconnect::AsyncSimConnect) -> simconnect::Result<()> {
+    /// let event_id = sc.with_connection(|sc| sc.new_client_event_id());
+    /// let key = sc.reserve_key(event_id, &["q", "shift+q", "ctrl+q"]).await?;
+    /// println!("press {} to open the menu", key.key());
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Invalid `choices` fail as with [`SimConnect::request_reserved_key`].
+    pub fn reserve_key<'a>(
+        &'a self,
+        event_id: ClientEventId,
+        choices: &[&str],
+    ) -> impl Future<Output = Result<ReservedKey<'a>>> + 'a {
+        // sent right away like the other requests, not on the first poll
+        let response = self.request(ResponseKind::ReservedKey, decode_reserved_key, |sc, _| {
+            sc.request_reserved_key(event_id, choices)
+        });
+        async move {
+            let (choice, key) = response.await?;
+            let group_id = self.with_connection(|sc| {
+                let group_id = sc.new_input_group_id();
+                let mapped = sc.map_input(group_id, &key, InputEvents::down(event_id))
+                    .and_then(|()| sc.set_input_group_priority(group_id, SIMCONNECT_GROUP_PRIORITY_STANDARD))
+                    .and_then(|()| sc.set_input_group_state(group_id, true));
+                if let Err(e) = mapped {
+                    let _ = sc.clear_input_group(group_id);
+                    sc.release_input_group_id(group_id);
+                    return Err(e);
+                }
+                Ok(group_id)
+            })?;
+            Ok(ReservedKey { sc: self, choice, key, event_id, group_id })
+        }
+    }
+
+    // sends a request under a new request id and waits for the message
+    // answering it, or an exception caused by it
+    fn request<T>(
+        &self,
+        kind: ResponseKind,
+        decode: fn(Recv) -> Result<T>,
+        send: impl FnOnce(&SimConnect, RequestId) -> Result<()>,
+    ) -> Response<'_, T> {
+        let mut inner = self.shared.lock();
+        let request_id = inner.sc.new_request_id();
+        let sent = send(&inner.sc, request_id).and_then(|()| inner.sc.last_send_id());
+        let state = match sent {
+            Ok(send_id) => {
+                inner.router.pending.insert(request_id, Pending::new(kind, send_id));
+                Ok(request_id)
+            }
+            Err(e) => {
+                inner.sc.release_request_id(request_id);
+                Err(Some(e))
+            }
+        };
+        Response { sc: self, state, decode, _marker: PhantomData }
+    }
+}
+
+impl Shared {
+    fn lock(&self) -> MutexGuard<'_, Inner> {
+        // the state stays consistent even if a task panicked holding the lock
+        self.inner.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl Inner {
+
+    // moves every queued message into the router
+    fn pump(&mut self) {
+        while !self.router.closed {
+            match self.sc.try_recv() {
+                Ok(Some(recv)) => self.router.route(recv),
+                Ok(None) => break,
+                Err(e) => self.router.fail(e),
+            }
+        }
+    }
+}
+
+/// Stream of messages returned by [`AsyncSimConnect::messages`].
+#[derive(Debug)]
+pub struct Messages<'a> {
+    sc: &'a AsyncSimConnect,
+    done: bool,
+}
+
+impl Stream for Messages<'_> {
+    type Item = Result<Recv>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        if self.done {
+            return Poll::Ready(None);
+        }
+        let mut inner = self.sc.shared.lock();
+
+        // register before checking, so a message arriving in between still
+        // wakes us up
+        inner.router.stream_wakers.push(cx.waker().clone());
+        inner.pump();
+        let next = inner.router.messages.pop_front();
+        let closed = inner.router.closed && inner.router.messages.is_empty();
+        drop(inner);
+
+        match next {
+            Some(recv) => {
+                self.done = closed;
+                Poll::Ready(Some(recv))
+            }
+            None if closed => {
+                self.done = true;
+                Poll::Ready(None)
+            }
+            None => Poll::Pending,
+        }
+    }
+}
+
+impl Drop for Messages<'_> {
+    fn drop(&mut self) {
+        let mut inner = self.sc.shared.lock();
+        inner.router.streams -= 1;
+        if inner.router.streams == 0 {
+            inner.router.messages.clear();
+        }
+    }
+}
+
+/// Future resolving to the response to a single request, see
+/// [`AsyncSimConnect`].
+///
+/// Exceptions caused by the request resolve it to [`Error::Exception`].
+/// Dropping the future cancels waiting, a response arriving later is passed
+/// on to the message stream.
+#[must_use = "futures do nothing unless polled"]
+pub struct Response<'a, T> {
+    sc: &'a AsyncSimConnect,
+
+    // the error is taken when returned
+    state: std::result::Result<RequestId, Option<Error>>,
+    decode: fn(Recv) -> Result<T>,
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<T> Response<'_, T> {
+
+    /// Returns the request id the response is matched on, `None` if sending
+    /// the request failed.
+    pub fn request_id(&self) -> Option<RequestId> {
+        self.state.as_ref().ok().copied()
+    }
+}
+
+impl<T> Future for Response<'_, T> {
+    type Output = Result<T>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<T>> {
+        let request_id = match &mut self.state {
+            Ok(request_id) => *request_id,
+            Err(e) => return Poll::Ready(Err(e.take().expect("Response polled after completion"))),
+        };
+        let mut inner = self.sc.shared.lock();
+        let pending = inner.router.pending.get_mut(&request_id).expect("Response polled after completion");
+        pending.waker = Some(cx.waker().clone());
+        inner.pump();
+
+        let pending = &inner.router.pending[&request_id];
+        if pending.response.is_none() && !inner.router.closed {
+            return Poll::Pending;
+        }
+        let pending = inner.router.pending.remove(&request_id).unwrap();
+        inner.sc.release_request_id(request_id);
+        drop(inner);
+
+        self.state = Err(None);
+        Poll::Ready(match pending.response {
+            Some(response) => response.and_then(self.decode),
+            None => Err(Error::Closed),
+        })
+    }
+}
+
+impl<T> Drop for Response<'_, T> {
+    fn drop(&mut self) {
+        if let Ok(request_id) = self.state {
+            let mut inner = self.sc.shared.lock();
+            // a late response could still arrive, so the id isn't reused
+            inner.router.pending.remove(&request_id);
+        }
+    }
+}
+
+impl<T> std::fmt::Debug for Response<'_, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Response").field("state", &self.state).finish()
+    }
+}
+
+fn decode_data<T: SimData>(recv: Recv) -> Result<T> {
+    match recv {
+        Recv::SimObjectData(data) => Ok(T::from_recv(&data)?),
+        _ => unreachable!("routed by ResponseKind"),
+    }
+}
+
+fn decode_system_state(recv: Recv) -> Result<SystemState> {
+    match recv {
+        Recv::SystemState(state) => Ok(state),
+        _ => unreachable!("routed by ResponseKind"),
+    }
+}
+
+fn decode_object_id(recv: Recv) -> Result<u32> {
+    match recv {
+        Recv::AssignedObjectId { object_id, .. } => Ok(object_id),
+        _ => unreachable!("routed by ResponseKind"),
+    }
+}
+
+fn decode_reserved_key(recv: Recv) -> Result<(String, String)> {
+    match recv {
+        Recv::ReservedKey { choice_reserved, reserved_key } => Ok((choice_reserved, reserved_key)),
+        _ => unreachable!("routed by ResponseKind"),
+    }
+}
+
+/// A key combination reserved with [`AsyncSimConnect::reserve_key`], mapped
+/// to its client event until dropped.
+#[derive(Debug)]
+#[must_use = "the key is unmapped when dropped"]
+pub struct ReservedKey<'a> {
+    sc: &'a AsyncSimConnect,
+    choice: String,
+    key: String,
+    event_id: ClientEventId,
+    group_id: InputGroupId,
+}
+
+impl ReservedKey<'_> {
+
+    /// The choice that was reserved, as it was requested.
+    pub fn choice(&self) -> &str {
+        &self.choice
+    }
+
+    /// The reserved key combination as the simulator names it, which may add
+    /// modifiers to the choice.
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    /// The client event sent when the key is pressed.
+    pub fn event_id(&self) -> ClientEventId {
+        self.event_id
+    }
+
+    /// The input group the key is mapped in.
+    pub fn group_id(&self) -> InputGroupId {
+        self.group_id
+    }
+}
+
+impl Drop for ReservedKey<'_> {
+    fn drop(&mut self) {
+        self.sc.with_connection(|sc| {
+            let _ = sc.clear_input_group(self.group_id);
+            sc.release_input_group_id(self.group_id);
+        });
+    }
+}
+
+/// The message a pending request is answered with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ResponseKind {
+    Data,
+    SystemState,
+    ObjectId,
+    ReservedKey,
+}
+
+impl ResponseKind {
+    fn of(recv: &Recv) -> Option<(Self, RequestId)> {
+        match recv {
+            Recv::SimObjectData(data) => Some((ResponseKind::Data, data.request_id)),
+            Recv::SystemState(state) => Some((ResponseKind::SystemState, state.request_id)),
+            Recv::AssignedObjectId { request_id, .. } => Some((ResponseKind::ObjectId, *request_id)),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Pending {
+    kind: ResponseKind,
+    send_id: u32,
+    response: Option<Result<Recv>>,
+    waker: Option<Waker>,
+}
+
+impl Pending {
+    fn new(kind: ResponseKind, send_id: u32) -> Self {
+        Self { kind, send_id, response: None, waker: None }
+    }
+}
+
+/// Decides where each received message goes, kept apart from the connection
+/// so it can be tested without one.
+#[derive(Debug, Default)]
+struct Router {
+    messages: VecDeque<Result<Recv>>,
+    streams: usize,
+    stream_wakers: Vec<Waker>,
+    pending: HashMap<RequestId, Pending>,
+    closed: bool,
+}
+
+impl Router {
+
+    fn route(&mut self, recv: Recv) {
+        let pending = match &recv {
+            Recv::Exception(e) => self.pending.values_mut()
+                .find(|p| p.send_id == e.send_id() && p.response.is_none()),
+            // answered without a request id, so in the order they were requested
+            Recv::ReservedKey { .. } => self.pending.values_mut()
+                .filter(|p| p.kind == ResponseKind::ReservedKey && p.response.is_none())
+                .min_by_key(|p| p.send_id),
+            recv => ResponseKind::of(recv)
+                .and_then(|(kind, request_id)| self.pending.get_mut(&request_id).filter(|p| p.kind == kind)),
+        };
+        if let Some(pending) = pending {
+            pending.response = Some(match recv {
+                Recv::Exception(e) => Err(e.into()),
+                recv => Ok(recv),
+            });
+            if let Some(waker) = pending.waker.take() {
+                waker.wake();
+            }
+            return;
+        }
+
+        if matches!(recv, Recv::Quit) {
+            self.close();
+        }
+        self.push(Ok(recv));
+    }
+
+    // a failing dispatch ends the connection like `SimConnect::iter`, errors
+    // decoding a single message don't
+    fn fail(&mut self, e: Error) {
+        if e.ends_connection() {
+            self.close();
+        }
+        self.push(Err(e));
+    }
+
+    fn push(&mut self, recv: Result<Recv>) {
+        if self.streams > 0 {
+            self.messages.push_back(recv);
+        }
+        for waker in self.stream_wakers.drain(..) {
+            waker.wake();
+        }
+    }
+
+    fn close(&mut self) {
+        self.closed = true;
+        for waker in self.pending.values_mut().filter_map(|p| p.waker.take()) {
+            waker.wake();
+        }
+    }
+
+    fn take_wakers(&mut self) -> Vec<Waker> {
+        let pending = self.pending.values_mut().filter_map(|p| p.waker.take());
+        self.stream_wakers.drain(..).chain(pending).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::task::Wake;
+
+    use super::*;
+    use crate::error::Exception;
+    use crate::flags::DataRequestFlags;
+    use crate::ids::DefineId;
+    use crate::recv::SimObjectData;
+
+    struct CountWaker(AtomicUsize);
+
+    impl Wake for CountWaker {
+        fn wake(self: Arc<Self>) {
+            self.0.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    fn data(request_id: u32) -> Recv {
+        Recv::SimObjectData(SimObjectData {
+            request_id: RequestId::new(request_id),
+            object_id: 0,
+            define_id: DefineId::new(1),
+            flags: DataRequestFlags::empty(),
+            entry_number: 1,
+            out_of: 1,
+            define_count: 0,
+            data: Vec::new(),
+        })
+    }
+
+    #[test]
+    fn route_responses() {
+        let count = Arc::new(CountWaker(AtomicUsize::new(0)));
+        let mut router = Router { streams: 1, ..Default::default() };
+        let mut pending = Pending::new(ResponseKind::Data, 7);
+        pending.waker = Some(count.clone().into());
+        router.pending.insert(RequestId::new(1), pending);
+        router.pending.insert(RequestId::new(2), Pending::new(ResponseKind::SystemState, 8));
+
+        // responses go to their request and wake it, anything else to the stream
+        router.route(data(1));
+        router.route(data(2));
+        router.route(Recv::Exception(Exception::new(1, 8, 1)));
+        router.route(Recv::Exception(Exception::new(1, 9, 1)));
+        assert_eq!(count.0.load(Ordering::SeqCst), 1);
+        assert!(matches!(router.pending[&RequestId::new(1)].response, Some(Ok(Recv::SimObjectData(_)))));
+        assert!(matches!(router.pending[&RequestId::new(2)].response, Some(Err(Error::Exception(_)))));
+        assert_eq!(router.messages.len(), 2);
+
+        router.route(Recv::Quit);
+        assert!(router.closed);
+        assert!(matches!(router.messages.back(), Some(Ok(Recv::Quit))));
+
+        // nothing is kept without a stream to read it
+        let mut router = Router::default();
+        router.route(data(3));
+        assert!(router.messages.is_empty());
+    }
+
+    #[test]
+    fn route_reserved_keys() {
+        let mut router = Router::default();
+        router.pending.insert(RequestId::new(1), Pending::new(ResponseKind::ReservedKey, 8));
+        router.pending.insert(RequestId::new(2), Pending::new(ResponseKind::ReservedKey, 7));
+
+        // answered in the order they were sent
+        let reserved = |choice: &str| Recv::ReservedKey { choice_reserved: choice.into(), reserved_key: choice.into() };
+        router.route(reserved("A"));
+        router.route(reserved("B"));
+        assert!(matches!(&router.pending[&RequestId::new(2)].response,
+            Some(Ok(Recv::ReservedKey { choice_reserved, .. })) if choice_reserved == "A"));
+        assert!(matches!(&router.pending[&RequestId::new(1)].response,
+            Some(Ok(Recv::ReservedKey { choice_reserved, .. })) if choice_reserved == "B"));
+    }
+
+    // polls on this thread, parking it until woken
+    #[cfg(feature = "mock")]
+    fn block_on<F: Future>(future: F) -> F::Output {
+        struct ThreadWaker(std::thread::Thread);
+
+        impl Wake for ThreadWaker {
+            fn wake(self: Arc<Self>) {
+                self.0.unpark();
+            }
+        }
+
+        let waker = Arc::new(ThreadWaker(std::thread::current())).into();
+        let mut cx = Context::from_waker(&waker);
+        let mut future = std::pin::pin!(future);
+        loop {
+            if let Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+                return output;
+            }
+            std::thread::park_timeout(std::time::Duration::from_millis(100));
+        }
+    }
+
+    #[cfg(feature = "mock")]
+    #[test]
+    fn reserve_key() {
+        use crate::error::ExceptionKind;
+        use crate::mock::{self, MockServer, TIMEOUT};
+
+        let server = MockServer::start().unwrap();
+        server.bind_key("Q");
+        let sc = AsyncSimConnect::new(server.connect("Test").unwrap()).unwrap();
+        let mut messages = sc.messages();
+
+        let event_id = sc.with_connection(|sc| sc.new_client_event_id());
+        let key = block_on(sc.reserve_key(event_id, &["q", "shift+q"])).unwrap();
+        assert_eq!((key.choice(), key.key()), ("shift+q", "Shift+Q"));
+        server.wait_for(TIMEOUT, |r| matches!(r.request, mock::Request::SetInputGroupState { on: true, .. })).unwrap();
+
+        // pressing the key sends the event
+        server.send_input("shift+q", true);
+        let event = loop {
+            match block_on(std::future::poll_fn(|cx| Pin::new(&mut messages).poll_next(cx))) {
+                Some(Ok(Recv::Event(event))) => break event,
+                Some(recv) => drop(recv.unwrap()),
+                None => panic!("stream ended"),
+            }
+        };
+        assert_eq!((event.event_id, event.group_id.map(|id| id.get())), (event_id, Some(key.group_id().get())));
+
+        // every choice is taken now
+        let e = block_on(sc.reserve_key(event_id, &["q", "SHIFT+Q"])).unwrap_err();
+        assert!(matches!(e, Error::Exception(e) if e.kind() == Some(ExceptionKind::Error)));
+
+        // invalid choices fail without leaving a request pending
+        let e = block_on(sc.reserve_key(event_id, &[])).unwrap_err();
+        assert!(matches!(e, Error::InvalidArgument { name: "choices", .. }));
+        let e = block_on(sc.reserve_key(event_id, &[&"q".repeat(30)])).unwrap_err();
+        assert!(matches!(e, Error::StringTooLong { len: 30, max: 29 }));
+        assert!(sc.shared.lock().router.pending.is_empty());
+
+        let group_id = key.group_id();
+        drop(key);
+        server.wait_for(TIMEOUT, |r| r.request == mock::Request::ClearInputGroup { group_id }).unwrap();
+    }
+}
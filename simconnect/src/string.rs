@@ -0,0 +1,231 @@
+use std::ffi::CString;
+use std::fmt;
+use std::ops::Deref;
+
+use simconnect_sys::*;
+
+use crate::error::{Error, Result};
+use crate::recv::{DecodeError, Reader};
+use crate::sim_data::DataValue;
+use crate::types::DataType;
+
+/// A string stored in a fixed size, nul padded field of `N` bytes
+/// (`SIMCONNECT_DATATYPE_STRING8` to `SIMCONNECT_DATATYPE_STRING260`).
+///
+/// The string always leaves room for the nul terminator, so it holds at most
+/// `N - 1` bytes of UTF-8.
+#[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct FixedString<const N: usize>(String);
+
+pub type String8 = FixedString<8>;
+pub type String32 = FixedString<32>;
+pub type String64 = FixedString<64>;
+pub type String128 = FixedString<128>;
+pub type String256 = FixedString<256>;
+pub type String260 = FixedString<260>;
+
+impl<const N: usize> FixedString<N> {
+
+    /// Creates a fixed string, failing if it doesn't fit `N` bytes including
+    /// the nul terminator or contains a nul byte.
+    pub fn new(s: impl Into<String>) -> Result<Self> {
+        let s = s.into();
+        if s.len() >= N {
+            return Err(Error::StringTooLong { len: s.len(), max: N - 1 });
+        }
+        let s = CString::new(s)?;
+        // valid UTF-8 going in, so it is coming out
+        Ok(Self(s.into_string().unwrap()))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    pub fn into_string(self) -> String {
+        self.0
+    }
+}
+
+impl<const N: usize> Deref for FixedString<N> {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl<const N: usize> AsRef<str> for FixedString<N> {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl<const N: usize> fmt::Display for FixedString<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl<const N: usize> TryFrom<&str> for FixedString<N> {
+    type Error = Error;
+
+    fn try_from(s: &str) -> Result<Self> {
+        Self::new(s)
+    }
+}
+
+impl<const N: usize> TryFrom<String> for FixedString<N> {
+    type Error = Error;
+
+    fn try_from(s: String) -> Result<Self> {
+        Self::new(s)
+    }
+}
+
+impl<const N: usize> From<FixedString<N>> for String {
+    fn from(s: FixedString<N>) -> String {
+        s.0
+    }
+}
+
+macro_rules! fixed_strings {
+    ($($n:literal => $data_type:ident;)*) => {
+        $(
+            impl DataValue for FixedString<$n> {
+                const DATA_TYPE: DataType = DataType::$data_type;
+
+                fn read(r: &mut Reader<'_>) -> Result<Self, DecodeError> {
+                    r.string_utf8($n).map(Self)
+                }
+
+                fn write(&self, out: &mut Vec<u8>) {
+                    out.extend_from_slice(self.0.as_bytes());
+                    out.resize(out.len() + $n - self.0.len(), 0);
+                }
+            }
+        )*
+    };
+}
+
+fixed_strings! {
+    8 => String8;
+    32 => String32;
+    64 => String64;
+    128 => String128;
+    256 => String256;
+    260 => String260;
+}
+
+/// `String` fields are variable length (`SIMCONNECT_DATATYPE_STRINGV`),
+/// moving every field after them.
+impl DataValue for String {
+    const DATA_TYPE: DataType = DataType::StringV;
+
+    fn read(r: &mut Reader<'_>) -> Result<Self, DecodeError> {
+        r.string_nul()
+    }
+
+    fn write(&self, out: &mut Vec<u8>) {
+        // an interior nul would end the string early, cut it there as the
+        // simulator would
+        let end = self.find('\0').unwrap_or(self.len());
+        out.extend_from_slice(&self.as_bytes()[..end]);
+        out.push(0);
+    }
+}
+
+/// Reads a variable length string from the start of `data`, the safe
+/// equivalent of `SimConnect_RetrieveString`.
+///
+/// Returns the string and the number of bytes it takes up including the nul
+/// terminator, which is where the next field starts.
+pub fn retrieve_string(data: &[u8]) -> Result<(&str, usize), DecodeError> {
+    let r = Reader::new(SIMCONNECT_RECV_ID_SIMOBJECT_DATA as u32, data);
+    let len = r.nul_position()?;
+    let s = std::str::from_utf8(&data[..len])
+        .map_err(|error| DecodeError::InvalidUtf8 { id: r.id(), offset: 0, error })?;
+    Ok((s, len + 1))
+}
+
+/// Appends `s` as a variable length string, the safe equivalent of
+/// `SimConnect_InsertString`.
+///
+/// Returns the number of bytes written including the nul terminator.
+pub fn insert_string(out: &mut Vec<u8>, s: &str) -> Result<usize> {
+    let s = CString::new(s)?;
+    out.extend_from_slice(s.as_bytes_with_nul());
+    Ok(s.as_bytes_with_nul().len())
+}
+
+#[cfg(all(test, feature = "derive"))]
+mod tests {
+    use super::*;
+    use crate::{DataRequestFlags, DefineId, RequestId, SimData, SimObjectData};
+
+    #[derive(Debug, PartialEq, SimData)]
+    struct Strings {
+        #[simvar(name = "ATC ID")]
+        atc_id: String32,
+        #[simvar(name = "TITLE")]
+        title: String,
+        #[simvar(name = "ATC AIRLINE")]
+        airline: String,
+        #[simvar(name = "PLANE ALTITUDE", units = "feet")]
+        altitude: f64,
+        #[simvar(name = "ATC TYPE")]
+        atc_type: String8,
+    }
+
+    #[test]
+    fn string_offsets() {
+        let strings = Strings {
+            atc_id: "N1234".try_into().unwrap(),
+            title: "Cessna 152".into(),
+            airline: String::new(),
+            altitude: 1500.0,
+            atc_type: "C152".try_into().unwrap(),
+        };
+        let bytes = strings.to_bytes();
+        assert_eq!(bytes.len(), 32 + 11 + 1 + 8 + 8);
+        assert_eq!(Strings::from_bytes(&bytes), Ok(strings));
+        assert_eq!(retrieve_string(&bytes[32..]), Ok(("Cessna 152", 11)));
+
+        let mut data = SimObjectData {
+            request_id: RequestId::new(1),
+            object_id: 0,
+            define_id: DefineId::new(1),
+            flags: DataRequestFlags::TAGGED,
+            entry_number: 1,
+            out_of: 1,
+            define_count: 2,
+            data: Vec::new(),
+        };
+        1u32.write(&mut data.data);
+        insert_string(&mut data.data, "Cessna 172").unwrap();
+        4u32.write(&mut data.data);
+        data.data.extend_from_slice(b"C172\0\0\0\0");
+        let mut strings = Strings::from_bytes(&bytes).unwrap();
+        strings.patch(&data).unwrap();
+        assert_eq!(strings.title, "Cessna 172");
+        assert_eq!(strings.atc_type.as_str(), "C172");
+    }
+
+    #[test]
+    fn string_errors() {
+        assert!(String8::new("1234567").is_ok());
+        assert!(matches!(String8::new("12345678"), Err(Error::StringTooLong { len: 8, max: 7 })));
+        assert!(matches!(String8::new("a\0b"), Err(Error::Nul(_))));
+
+        let mut bytes = vec![0; 32];
+        bytes[0] = 0xff;
+        bytes.extend_from_slice(b"title\0\0");
+        assert!(matches!(Strings::from_bytes(&bytes), Err(DecodeError::InvalidUtf8 { offset: 0, .. })));
+        bytes[0] = b'N';
+        bytes[33] = 0xc3;
+        assert!(matches!(Strings::from_bytes(&bytes), Err(DecodeError::InvalidUtf8 { offset: 32, .. })));
+
+        // no terminator before the end of the packet
+        assert!(matches!(retrieve_string(b"title"), Err(DecodeError::TooShort { .. })));
+    }
+}
@@ -0,0 +1,281 @@
+use crate::connection::SimConnect;
+use crate::error::Result;
+use crate::ids::ClientEventId;
+use crate::recv::Recv;
+use crate::types::SimObjectType;
+
+// the message a system event is received as
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Payload {
+    Event,
+    Filename,
+    Frame,
+    Object,
+}
+
+macro_rules! system_events {
+    ($($(#[$doc:meta])* $variant:ident = $name:literal, $payload:ident;)*) => {
+
+        /// The system events documented for `SimConnect_SubscribeToSystemEvent`.
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        #[non_exhaustive]
+        pub enum SystemEvent {
+            $($(#[$doc])* $variant,)*
+        }
+
+        impl SystemEvent {
+
+            /// Every system event, in the order of the SDK documentation.
+            pub const ALL: &'static [SystemEvent] = &[$(Self::$variant,)*];
+
+            /// The name passed to `SimConnect_SubscribeToSystemEvent`.
+            pub fn name(self) -> &'static str {
+                match self {
+                    $(Self::$variant => $name,)*
+                }
+            }
+
+            /// Returns the event named `name`, ignoring case as SimConnect does.
+            pub fn from_name(name: &str) -> Option<Self> {
+                Self::ALL.iter().copied().find(|event| event.name().eq_ignore_ascii_case(name))
+            }
+
+            fn payload(self) -> Payload {
+                match self {
+                    $(Self::$variant => Payload::$payload,)*
+                }
+            }
+        }
+    };
+}
+
+system_events! {
+    /// Every second, while the simulation is running.
+    OneSec = "1sec", Event;
+
+    /// Every four seconds, while the simulation is running.
+    FourSec = "4sec", Event;
+
+    /// Six times per second, while the simulation is running.
+    SixHz = "6Hz", Event;
+
+    /// An aircraft was loaded, with the path of its `.AIR` file.
+    AircraftLoaded = "AircraftLoaded", Filename;
+
+    /// The user's aircraft crashed.
+    Crashed = "Crashed", Event;
+
+    /// The crash cut-scene ended.
+    CrashReset = "CrashReset", Event;
+
+    /// A flight was loaded, with the path of its `.FLT` file.
+    FlightLoaded = "FlightLoaded", Filename;
+
+    /// A flight was saved, with the path of its `.FLT` file.
+    FlightSaved = "FlightSaved", Filename;
+
+    /// A flight plan was activated, with the path of its `.PLN` file.
+    FlightPlanActivated = "FlightPlanActivated", Filename;
+
+    /// The active flight plan was deactivated.
+    FlightPlanDeactivated = "FlightPlanDeactivated", Event;
+
+    /// Every visual frame, with the frame rate and simulation speed.
+    Frame = "Frame", Frame;
+
+    /// An AI object was added to the simulation.
+    ObjectAdded = "ObjectAdded", Object;
+
+    /// An AI object was removed from the simulation.
+    ObjectRemoved = "ObjectRemoved", Object;
+
+    /// The simulation was paused (1) or unpaused (0).
+    Pause = "Pause", Event;
+
+    /// The pause state changed, as `SIMCONNECT_PAUSE_STATE_*` flags.
+    PauseEx1 = "Pause_EX1", Event;
+
+    /// The simulation was paused.
+    Paused = "Paused", Event;
+
+    /// Every visual frame while the simulation is paused.
+    PauseFrame = "PauseFrame", Frame;
+
+    /// The user changed the position of their aircraft through a dialog.
+    PositionChanged = "PositionChanged", Event;
+
+    /// The simulation started (1) or stopped (0).
+    Sim = "Sim", Event;
+
+    /// The simulation started.
+    SimStart = "SimStart", Event;
+
+    /// The simulation stopped.
+    SimStop = "SimStop", Event;
+
+    /// The master sound switch was turned on (1) or off (0).
+    Sound = "Sound", Event;
+
+    /// The simulation was unpaused.
+    Unpaused = "Unpaused", Event;
+
+    /// The user's aircraft view changed, as `SIMCONNECT_VIEW_SYSTEM_EVENT_DATA_*`.
+    View = "View", Event;
+}
+
+/// The payload of a received system event, depending on the event.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SystemEventData {
+
+    /// The `dwData` of a `SIMCONNECT_RECV_EVENT`, e.g. 1 for [`SystemEvent::Pause`]
+    /// when the simulation was paused.
+    Event(u32),
+
+    /// A `SIMCONNECT_RECV_EVENT_FILENAME`.
+    Filename {
+        file_name: String,
+        flags: u32,
+    },
+
+    /// A `SIMCONNECT_RECV_EVENT_FRAME`.
+    Frame {
+        frame_rate: f32,
+        sim_speed: f32,
+    },
+
+    /// A `SIMCONNECT_RECV_EVENT_OBJECT_ADDREMOVE`.
+    Object {
+        object_id: u32,
+        object_type: SimObjectType,
+    },
+}
+
+/// A subscription to a [`SystemEvent`], unsubscribed when dropped.
+///
+/// Created with [`SimConnect::subscribe_system_event`], it picks its events
+/// out of the received messages with [`SystemEventSubscription::decode`].
+#[derive(Debug)]
+#[must_use = "the system event is unsubscribed when the subscription is dropped"]
+pub struct SystemEventSubscription<'a> {
+    sc: &'a SimConnect,
+    event: SystemEvent,
+    event_id: ClientEventId,
+}
+
+impl SimConnect {
+
+    /// Subscribes to `event` with a newly allocated client event id, until
+    /// the returned subscription is dropped.
+    ///
+    /// ```no_run
+    /// use simconnect::{SimConnect, SystemEvent, SystemEventData};
+    ///
+    /// let sc = SimConnect::open("Example")?;
+    /// let pause = sc.subscribe_system_event(SystemEvent::Pause)?;
+    /// let loaded = sc.subscribe_system_event(SystemEvent::FlightLoaded)?;
+    /// for recv in sc.iter() {
+    ///     let recv = recv?;
+    ///     if let Some(SystemEventData::Event(paused)) = pause.decode(&recv) {
+    ///         println!("paused: {}", paused == 1);
+    ///     }
+    ///     if let Some(SystemEventData::Filename { file_name, .. }) = loaded.decode(&recv) {
+    ///         println!("loaded {file_name}");
+    ///     }
+    /// }
+    /// # Ok::<(), simconnect::Error>(())
+    /// ```
+    pub fn subscribe_system_event(&self, event: SystemEvent) -> Result<SystemEventSubscription<'_>> {
+        let event_id = self.new_client_event_id();
+        if let Err(e) = self.subscribe_to_system_event(event_id, event.name()) {
+            self.release_client_event_id(event_id);
+            return Err(e);
+        }
+        Ok(SystemEventSubscription { sc: self, event, event_id })
+    }
+}
+
+impl SystemEventSubscription<'_> {
+
+    /// The event subscribed to.
+    pub fn event(&self) -> SystemEvent {
+        self.event
+    }
+
+    /// The client event id the event is received as.
+    pub fn event_id(&self) -> ClientEventId {
+        self.event_id
+    }
+
+    /// Turns reporting of the event on or off, without unsubscribing.
+    pub fn set_state(&self, on: bool) -> Result<()> {
+        self.sc.set_system_event_state(self.event_id, on)
+    }
+
+    /// Returns the payload of `recv` if it is this subscription's event.
+    pub fn decode(&self, recv: &Recv) -> Option<SystemEventData> {
+        let (event, data) = match (self.event.payload(), recv) {
+            (Payload::Event, Recv::Event(event)) => (event, SystemEventData::Event(event.data)),
+            (Payload::Filename, Recv::EventFilename { event, file_name, flags }) => {
+                (event, SystemEventData::Filename { file_name: file_name.clone(), flags: *flags })
+            }
+            (Payload::Frame, Recv::EventFrame { event, frame_rate, sim_speed }) => {
+                (event, SystemEventData::Frame { frame_rate: *frame_rate, sim_speed: *sim_speed })
+            }
+            (Payload::Object, Recv::EventObjectAddRemove { event, object_type }) => {
+                (event, SystemEventData::Object { object_id: event.data, object_type: *object_type })
+            }
+            _ => return None,
+        };
+        (event.event_id == self.event_id).then_some(data)
+    }
+}
+
+impl Drop for SystemEventSubscription<'_> {
+
+    fn drop(&mut self) {
+        // a closed connection has nothing left to unsubscribe from
+        let _ = self.sc.unsubscribe_from_system_event(self.event_id);
+        self.sc.release_client_event_id(self.event_id);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::{next_matching, MockServer, Request, TIMEOUT};
+
+    #[test]
+    fn names() {
+        assert_eq!(SystemEvent::PauseEx1.name(), "Pause_EX1");
+        assert_eq!(SystemEvent::from_name("simstart"), Some(SystemEvent::SimStart));
+        assert_eq!(SystemEvent::from_name("4sec"), Some(SystemEvent::FourSec));
+        assert_eq!(SystemEvent::from_name("Brakes"), None);
+    }
+
+    #[test]
+    fn subscription() {
+        let server = MockServer::start().unwrap();
+        let sc = server.connect("Test").unwrap();
+
+        let sim_start = sc.subscribe_system_event(SystemEvent::SimStart).unwrap();
+        let added = sc.subscribe_system_event(SystemEvent::ObjectAdded).unwrap();
+        server.wait_for(TIMEOUT, |r| matches!(&r.request,
+            Request::SubscribeToSystemEvent { name, .. } if name == "ObjectAdded")).unwrap();
+
+        server.send_system_event("SimStart", 1);
+        let recv = next_matching(&sc, |r| matches!(r, Recv::Event(_)));
+        assert_eq!(sim_start.decode(&recv), Some(SystemEventData::Event(1)));
+        assert_eq!(added.decode(&recv), None);
+
+        let object_id = server.add_object(SimObjectType::Aircraft);
+        let recv = next_matching(&sc, |r| matches!(r, Recv::EventObjectAddRemove { .. }));
+        assert_eq!(added.decode(&recv), Some(SystemEventData::Object { object_id, object_type: SimObjectType::Aircraft }));
+        assert_eq!(sim_start.decode(&recv), None);
+
+        // dropping unsubscribes and hands the id back
+        let event_id = sim_start.event_id();
+        drop(sim_start);
+        server.wait_for(TIMEOUT, |r| r.request == Request::UnsubscribeFromSystemEvent { event_id }).unwrap();
+        assert_eq!(sc.new_client_event_id(), event_id);
+    }
+}
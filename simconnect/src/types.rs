@@ -0,0 +1,276 @@
+use simconnect_sys::*;
+
+use crate::error::UnknownValue;
+
+/// The object id of the user's aircraft.
+pub const OBJECT_ID_USER: u32 = SIMCONNECT_OBJECT_ID_USER;
+
+// defines a rust enum for a SimConnect.h enum, with conversions from the raw
+// value as found in function arguments (`c_int`) and packets (`DWORD`)
+macro_rules! raw_enum {
+    (
+        $(#[$meta:meta])*
+        pub enum $name:ident: $raw:ident {
+            $($(#[$variant_meta:meta])* $variant:ident = $value:ident,)*
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        #[repr(i32)]
+        pub enum $name {
+            $($(#[$variant_meta])* $variant = $value,)*
+        }
+
+        impl $name {
+            pub(crate) fn from_raw(raw: $raw) -> Option<Self> {
+                match raw {
+                    $($(#[$variant_meta])* $value => Some(Self::$variant),)*
+                    _ => None,
+                }
+            }
+
+            pub(crate) fn raw(self) -> $raw {
+                self as $raw
+            }
+        }
+
+        impl From<$name> for $raw {
+            fn from(value: $name) -> Self {
+                value.raw()
+            }
+        }
+
+        impl TryFrom<$raw> for $name {
+            type Error = UnknownValue;
+
+            fn try_from(raw: $raw) -> Result<Self, UnknownValue> {
+                Self::from_raw(raw).ok_or(UnknownValue { name: stringify!($raw), value: raw as i64 })
+            }
+        }
+
+        impl TryFrom<u32> for $name {
+            type Error = UnknownValue;
+
+            fn try_from(raw: u32) -> Result<Self, UnknownValue> {
+                $raw::try_from(raw).ok()
+                    .and_then(Self::from_raw)
+                    .ok_or(UnknownValue { name: stringify!($raw), value: raw as i64 })
+            }
+        }
+    };
+}
+
+raw_enum! {
+    /// How often data is sent for a request (`SIMCONNECT_PERIOD`).
+    pub enum Period: SIMCONNECT_PERIOD {
+        Never = SIMCONNECT_PERIOD_NEVER,
+        Once = SIMCONNECT_PERIOD_ONCE,
+        VisualFrame = SIMCONNECT_PERIOD_VISUAL_FRAME,
+        SimFrame = SIMCONNECT_PERIOD_SIM_FRAME,
+        Second = SIMCONNECT_PERIOD_SECOND,
+    }
+}
+
+raw_enum! {
+    /// How often client data is sent for a request
+    /// (`SIMCONNECT_CLIENT_DATA_PERIOD`).
+    pub enum ClientDataPeriod: SIMCONNECT_CLIENT_DATA_PERIOD {
+        Never = SIMCONNECT_CLIENT_DATA_PERIOD_NEVER,
+        Once = SIMCONNECT_CLIENT_DATA_PERIOD_ONCE,
+        VisualFrame = SIMCONNECT_CLIENT_DATA_PERIOD_VISUAL_FRAME,
+        OnSet = SIMCONNECT_CLIENT_DATA_PERIOD_ON_SET,
+        Second = SIMCONNECT_CLIENT_DATA_PERIOD_SECOND,
+    }
+}
+
+raw_enum! {
+    /// The type of a simulation object (`SIMCONNECT_SIMOBJECT_TYPE`).
+    pub enum SimObjectType: SIMCONNECT_SIMOBJECT_TYPE {
+        User = SIMCONNECT_SIMOBJECT_TYPE_USER,
+        All = SIMCONNECT_SIMOBJECT_TYPE_ALL,
+        Aircraft = SIMCONNECT_SIMOBJECT_TYPE_AIRCRAFT,
+        Helicopter = SIMCONNECT_SIMOBJECT_TYPE_HELICOPTER,
+        Boat = SIMCONNECT_SIMOBJECT_TYPE_BOAT,
+        Ground = SIMCONNECT_SIMOBJECT_TYPE_GROUND,
+    }
+}
+
+raw_enum! {
+    /// The type of a facility list (`SIMCONNECT_FACILITY_LIST_TYPE`).
+    pub enum FacilityListType: SIMCONNECT_FACILITY_LIST_TYPE {
+        Airport = SIMCONNECT_FACILITY_LIST_TYPE_AIRPORT,
+        Waypoint = SIMCONNECT_FACILITY_LIST_TYPE_WAYPOINT,
+        Ndb = SIMCONNECT_FACILITY_LIST_TYPE_NDB,
+        Vor = SIMCONNECT_FACILITY_LIST_TYPE_VOR,
+    }
+}
+
+raw_enum! {
+    /// The type of a data definition entry (`SIMCONNECT_DATATYPE`).
+    pub enum DataType: SIMCONNECT_DATATYPE {
+        Int32 = SIMCONNECT_DATATYPE_INT32,
+        Int64 = SIMCONNECT_DATATYPE_INT64,
+        Float32 = SIMCONNECT_DATATYPE_FLOAT32,
+        Float64 = SIMCONNECT_DATATYPE_FLOAT64,
+        String8 = SIMCONNECT_DATATYPE_STRING8,
+        String32 = SIMCONNECT_DATATYPE_STRING32,
+        String64 = SIMCONNECT_DATATYPE_STRING64,
+        String128 = SIMCONNECT_DATATYPE_STRING128,
+        String256 = SIMCONNECT_DATATYPE_STRING256,
+        String260 = SIMCONNECT_DATATYPE_STRING260,
+        StringV = SIMCONNECT_DATATYPE_STRINGV,
+        InitPosition = SIMCONNECT_DATATYPE_INITPOSITION,
+        MarkerState = SIMCONNECT_DATATYPE_MARKERSTATE,
+        Waypoint = SIMCONNECT_DATATYPE_WAYPOINT,
+        LatLonAlt = SIMCONNECT_DATATYPE_LATLONALT,
+        Xyz = SIMCONNECT_DATATYPE_XYZ,
+    }
+}
+
+impl DataType {
+
+    /// Returns the size of a value in bytes, `None` for the variable length
+    /// `StringV`.
+    pub const fn size(self) -> Option<usize> {
+        Some(match self {
+            DataType::Int32 | DataType::Float32 => 4,
+            DataType::Int64 | DataType::Float64 => 8,
+            DataType::String8 => 8,
+            DataType::String32 => 32,
+            DataType::String64 => 64,
+            DataType::String128 => 128,
+            DataType::String256 => 256,
+            DataType::String260 => 260,
+            DataType::StringV => return None,
+            DataType::InitPosition => 6 * 8 + 2 * 4,
+            DataType::MarkerState => 64 + 4,
+            DataType::Waypoint => 3 * 8 + 4 + 2 * 8,
+            DataType::LatLonAlt | DataType::Xyz => 3 * 8,
+        })
+    }
+}
+
+raw_enum! {
+    /// The type of a received message, the `dwID` of every packet
+    /// (`SIMCONNECT_RECV_ID`).
+    #[non_exhaustive]
+    pub enum RecvId: SIMCONNECT_RECV_ID {
+        Null = SIMCONNECT_RECV_ID_NULL,
+        Exception = SIMCONNECT_RECV_ID_EXCEPTION,
+        Open = SIMCONNECT_RECV_ID_OPEN,
+        Quit = SIMCONNECT_RECV_ID_QUIT,
+        Event = SIMCONNECT_RECV_ID_EVENT,
+        EventObjectAddRemove = SIMCONNECT_RECV_ID_EVENT_OBJECT_ADDREMOVE,
+        EventFilename = SIMCONNECT_RECV_ID_EVENT_FILENAME,
+        EventFrame = SIMCONNECT_RECV_ID_EVENT_FRAME,
+        SimObjectData = SIMCONNECT_RECV_ID_SIMOBJECT_DATA,
+        SimObjectDataByType = SIMCONNECT_RECV_ID_SIMOBJECT_DATA_BYTYPE,
+        WeatherObservation = SIMCONNECT_RECV_ID_WEATHER_OBSERVATION,
+        CloudState = SIMCONNECT_RECV_ID_CLOUD_STATE,
+        AssignedObjectId = SIMCONNECT_RECV_ID_ASSIGNED_OBJECT_ID,
+        ReservedKey = SIMCONNECT_RECV_ID_RESERVED_KEY,
+        CustomAction = SIMCONNECT_RECV_ID_CUSTOM_ACTION,
+        SystemState = SIMCONNECT_RECV_ID_SYSTEM_STATE,
+        ClientData = SIMCONNECT_RECV_ID_CLIENT_DATA,
+        EventWeatherMode = SIMCONNECT_RECV_ID_EVENT_WEATHER_MODE,
+        AirportList = SIMCONNECT_RECV_ID_AIRPORT_LIST,
+        VorList = SIMCONNECT_RECV_ID_VOR_LIST,
+        NdbList = SIMCONNECT_RECV_ID_NDB_LIST,
+        WaypointList = SIMCONNECT_RECV_ID_WAYPOINT_LIST,
+        EventMultiplayerServerStarted = SIMCONNECT_RECV_ID_EVENT_MULTIPLAYER_SERVER_STARTED,
+        EventMultiplayerClientStarted = SIMCONNECT_RECV_ID_EVENT_MULTIPLAYER_CLIENT_STARTED,
+        EventMultiplayerSessionEnded = SIMCONNECT_RECV_ID_EVENT_MULTIPLAYER_SESSION_ENDED,
+        EventRaceEnd = SIMCONNECT_RECV_ID_EVENT_RACE_END,
+        EventRaceLap = SIMCONNECT_RECV_ID_EVENT_RACE_LAP,
+        #[cfg(sdk_at_least_0_24_3)]
+        EventEx1 = SIMCONNECT_RECV_ID_EVENT_EX1,
+        FacilityData = SIMCONNECT_RECV_ID_FACILITY_DATA,
+        FacilityDataEnd = SIMCONNECT_RECV_ID_FACILITY_DATA_END,
+        FacilityMinimalList = SIMCONNECT_RECV_ID_FACILITY_MINIMAL_LIST,
+        JetwayData = SIMCONNECT_RECV_ID_JETWAY_DATA,
+        ControllersList = SIMCONNECT_RECV_ID_CONTROLLERS_LIST,
+        ActionCallback = SIMCONNECT_RECV_ID_ACTION_CALLBACK,
+        EnumerateInputEvents = SIMCONNECT_RECV_ID_ENUMERATE_INPUT_EVENTS,
+        GetInputEvent = SIMCONNECT_RECV_ID_GET_INPUT_EVENT,
+        SubscribeInputEvent = SIMCONNECT_RECV_ID_SUBSCRIBE_INPUT_EVENT,
+        EnumerateInputEventParams = SIMCONNECT_RECV_ID_ENUMERATE_INPUT_EVENT_PARAMS,
+    }
+}
+
+/// A position in degrees and feet (`SIMCONNECT_DATA_LATLONALT`).
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct LatLonAlt {
+    pub latitude: f64,
+    pub longitude: f64,
+    pub altitude: f64,
+}
+
+/// A cartesian position or vector (`SIMCONNECT_DATA_XYZ`).
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Xyz {
+    pub x: f64,
+    pub y: f64,
+    pub z: f64,
+}
+
+/// Where and how an object is placed (`SIMCONNECT_DATA_INITPOSITION`).
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct InitPosition {
+    pub latitude: f64,
+    pub longitude: f64,
+    pub altitude: f64,
+    pub pitch: f64,
+    pub bank: f64,
+    pub heading: f64,
+    pub on_ground: bool,
+
+    /// Airspeed in knots, or one of `INITPOSITION_AIRSPEED_CRUISE` and
+    /// `INITPOSITION_AIRSPEED_KEEP`.
+    pub airspeed: u32,
+}
+
+impl InitPosition {
+    #[cfg_attr(not(windows), allow(dead_code))]
+    pub(crate) fn raw(&self) -> SIMCONNECT_DATA_INITPOSITION {
+        SIMCONNECT_DATA_INITPOSITION {
+            Latitude: self.latitude,
+            Longitude: self.longitude,
+            Altitude: self.altitude,
+            Pitch: self.pitch,
+            Bank: self.bank,
+            Heading: self.heading,
+            OnGround: self.on_ground as DWORD,
+            Airspeed: self.airspeed,
+        }
+    }
+}
+
+/// An orientation in degrees (`SIMCONNECT_DATA_PBH`).
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Pbh {
+    pub pitch: f32,
+    pub bank: f32,
+    pub heading: f32,
+}
+
+/// A Windows `GUID`, as the 16 bytes found in the packet.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Guid(pub [u8; 16]);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn raw_conversions() {
+        assert_eq!(Period::try_from(SIMCONNECT_PERIOD_SECOND), Ok(Period::Second));
+        assert_eq!(SIMCONNECT_PERIOD::from(Period::VisualFrame), SIMCONNECT_PERIOD_VISUAL_FRAME);
+        assert_eq!(RecvId::try_from(SIMCONNECT_RECV_ID_EVENT as u32), Ok(RecvId::Event));
+        assert_eq!(DataType::try_from(SIMCONNECT_DATATYPE_XYZ as u32), Ok(DataType::Xyz));
+
+        let e = SimObjectType::try_from(u32::MAX).unwrap_err();
+        assert_eq!(e, UnknownValue { name: "SIMCONNECT_SIMOBJECT_TYPE", value: u32::MAX as i64 });
+        assert_eq!(e.to_string(), "4294967295 is not a valid SIMCONNECT_SIMOBJECT_TYPE");
+        assert!(FacilityListType::try_from(SIMCONNECT_FACILITY_LIST_TYPE_COUNT).is_err());
+    }
+}